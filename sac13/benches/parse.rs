@@ -0,0 +1,53 @@
+//! Parsing throughput for log ingestion, where almost every date is in a canonical layout.
+//!
+//! ```sh
+//! cargo bench --bench parse
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use sac13::{CalendarDate, Date, GregorianDate, parse_date_str};
+
+/// Every 97th day of the SAC13 range in the given format.
+fn inputs(format: fn(Date) -> String) -> Vec<String> {
+    (Date::MIN.as_julian()..=Date::MAX.as_julian())
+        .step_by(97)
+        .map(|julian| format(Date::from_julian(julian).unwrap()))
+        .collect()
+}
+
+fn parse(c: &mut Criterion) {
+    let cases = [
+        ("canonical sac13", inputs(|date| date.to_string())),
+        (
+            "iso gregorian",
+            inputs(|date| format!("{}", date.convert::<GregorianDate>().to_iso8601())),
+        ),
+        (
+            "dotted gregorian",
+            inputs(|date| {
+                let date: GregorianDate = date.convert();
+                format!("{:02}.{:02}.{:04}", date.day(), date.month(), date.year())
+            }),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("parse_date_str");
+
+    for (name, inputs) in &cases {
+        group.throughput(Throughput::Elements(inputs.len() as u64));
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                for input in inputs {
+                    black_box(parse_date_str(black_box(input)));
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sac13::prelude::*;
+
+// Differential test of the arithmetic in `from_julian()` against the
+// simple day-by-day logic of `tomorrow()`.
+fuzz_target!(|jdn: i32| {
+    let Some(date) = Date::from_julian(jdn) else {
+        assert!(jdn < Date::MIN.as_julian() || jdn > Date::MAX.as_julian());
+        return;
+    };
+
+    assert_eq!(date.as_julian(), jdn);
+
+    let next = jdn.checked_add(1).and_then(Date::from_julian);
+    assert_eq!(date.tomorrow(), next);
+
+    if let Some(next) = next {
+        assert_eq!(next.yesterday(), Some(date));
+    }
+
+    let gregorian = GregorianDate::from_julian(jdn).unwrap();
+    assert_eq!(gregorian.tomorrow(), next.map(CalendarDate::convert));
+});
@@ -0,0 +1,50 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sac13::{
+    CalendarDate, ParseError, ParseOptions, parse_date_str_with, try_parse_date_str_with,
+    try_parse_datetime_str_with,
+};
+
+fuzz_target!(|input: &str| {
+    for options in [
+        ParseOptions::new(),
+        ParseOptions::new().plain_sac13_years(true),
+    ] {
+        let result = try_parse_date_str_with(input, options);
+
+        // The `Option` API is a thin wrapper around the `Result` API.
+        assert_eq!(
+            parse_date_str_with(input, options).map(|x| x.as_julian()),
+            result.as_ref().ok().map(|x| x.as_julian())
+        );
+
+        match result {
+            Ok(parsed) => {
+                // Conversions never fail for parsed dates.
+                assert_eq!(parsed.to_sac13().as_julian(), parsed.as_julian());
+                assert_eq!(parsed.to_gregorian().as_julian(), parsed.as_julian());
+
+                // Writing the date in its own format must parse to the same day.
+                let written = parsed.reformat(&parsed.format).to_string();
+                let reparsed = try_parse_date_str_with(&written, options)
+                    .unwrap_or_else(|e| panic!("{input:?} reformatted as {written:?}: {e}"));
+
+                assert_eq!(reparsed.as_julian(), parsed.as_julian());
+            }
+            Err(error) => check_span(input, &error),
+        }
+
+        if let Err(error) = try_parse_datetime_str_with(input, options) {
+            check_span(input, &error);
+        }
+    }
+});
+
+/// The span must be usable to slice the input.
+fn check_span(input: &str, error: &ParseError) {
+    let span = error.span();
+
+    assert!(span.start <= span.end && span.end <= input.len());
+    assert!(input.is_char_boundary(span.start) && input.is_char_boundary(span.end));
+}
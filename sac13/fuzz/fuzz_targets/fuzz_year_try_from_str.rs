@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sac13::Year;
+
+fuzz_target!(|input: &str| {
+    // Both formats are canonical: a parsed year displays exactly like the input.
+    if let Some(year) = Year::try_from_str(input) {
+        assert_eq!(year.to_string(), input);
+    }
+
+    if let Some(year) = Year::try_from_plain_str(input) {
+        assert_eq!(year.display_plain().to_string(), input);
+    }
+});
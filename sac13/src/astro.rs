@@ -0,0 +1,273 @@
+//! Approximate astronomical events, based on the mean equinox and solstice formulas
+//! of Jean Meeus, _Astronomical Algorithms_ (2nd ed., chapter 27).
+
+use crate::{Date, Year, traits::CalendarDate};
+
+/// Polynomial coefficients (in Julian Ephemeris Days) of the March equinox, June solstice,
+/// September equinox and December solstice for the years -1000 to 1000 (table 27.A).
+const BEFORE_1000: [[f64; 5]; 4] = [
+    [
+        1_721_139.291_89,
+        365_242.137_40,
+        0.061_34,
+        0.001_11,
+        -0.000_71,
+    ],
+    [
+        1_721_233.254_01,
+        365_241.725_62,
+        -0.053_23,
+        0.009_07,
+        0.000_25,
+    ],
+    [
+        1_721_325.704_55,
+        365_242.495_58,
+        -0.116_77,
+        -0.002_97,
+        0.000_74,
+    ],
+    [
+        1_721_414.399_87,
+        365_242.882_57,
+        -0.007_69,
+        -0.009_33,
+        -0.000_06,
+    ],
+];
+
+/// Same as [`BEFORE_1000`] for the years 1000 to 3000 (table 27.B), relative to the year 2000.
+const FROM_1000: [[f64; 5]; 4] = [
+    [
+        2_451_623.809_84,
+        365_242.374_04,
+        0.051_69,
+        -0.004_11,
+        -0.000_57,
+    ],
+    [
+        2_451_716.567_67,
+        365_241.626_03,
+        0.003_25,
+        0.008_88,
+        -0.000_30,
+    ],
+    [
+        2_451_810.217_15,
+        365_242.017_67,
+        -0.115_75,
+        0.003_37,
+        0.000_78,
+    ],
+    [
+        2_451_900.059_52,
+        365_242.740_49,
+        -0.062_23,
+        -0.008_23,
+        0.000_32,
+    ],
+];
+
+/// Mean tropical year in days, used outside the range of the formulas.
+const TROPICAL_YEAR: f64 = 365.242_19;
+
+impl Year {
+    /// Approximate dates of the March equinox, June solstice, September equinox and
+    /// December solstice (in this order) of the Gregorian year in which this year starts.
+    ///
+    /// The dates are derived from the mean instants of Meeus' formulas (in UTC, without
+    /// the difference between terrestrial and universal time), which are within a day
+    /// between J000 and N999 (the Gregorian years -1000 to 3000). Outside that range
+    /// they are extrapolated with the mean tropical year and can be off by several days.
+    /// Results before [`Date::MIN`](CalendarDate::MIN) or after [`Date::MAX`](CalendarDate::MAX)
+    /// are clamped.
+    ///
+    /// Requires the `astro` feature.
+    ///
+    /// ```
+    /// use sac13::{date, year};
+    ///
+    /// assert_eq!(
+    ///     year!(M024).approx_solstices_and_equinoxes(),
+    ///     [
+    ///         date!(M024 - 01 - 01),
+    ///         date!(M024 - 04 - 09),
+    ///         date!(M024 - 07 - 19),
+    ///         date!(M024 - 10 - 25),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn approx_solstices_and_equinoxes(&self) -> [Date; 4] {
+        events(f64::from(self.value()) - 10_000.0).map(date_of_jde)
+    }
+}
+
+impl Date {
+    /// Elapsed fraction of the tropical year (from 0 inclusive to 1 exclusive) at noon of the date,
+    /// counted from the preceding March equinox.
+    ///
+    /// Uses the same approximation as [`Year::approx_solstices_and_equinoxes()`].
+    ///
+    /// Requires the `astro` feature.
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// // the September equinox is a bit more than half a year after the March equinox
+    /// let fraction = date!(M024 - 07 - 19).fraction_of_cycle();
+    /// assert!((0.5..0.52).contains(&fraction));
+    /// ```
+    #[must_use]
+    pub fn fraction_of_cycle(&self) -> f64 {
+        let (jd, points) = self.cycle();
+        (jd - points[0]) / (points[4] - points[0])
+    }
+
+    /// Estimated apparent solar longitude in degrees (from 0 inclusive to 360 exclusive) at noon of the date.
+    ///
+    /// It's 0° at the March equinox, 90° at the June solstice, 180° at the September equinox and
+    /// 270° at the December solstice, and interpolated linearly between these events, which
+    /// accounts for the different lengths of the seasons. The error is below one degree
+    /// where the approximation of the events is accurate (J000 to N999).
+    ///
+    /// Requires the `astro` feature.
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert!(date!(M024 - 01 - 01).solar_longitude_estimate() < 1.0);
+    ///
+    /// let longitude = date!(M024 - 04 - 09).solar_longitude_estimate();
+    /// assert!((89.0..91.0).contains(&longitude));
+    /// ```
+    #[must_use]
+    pub fn solar_longitude_estimate(&self) -> f64 {
+        let (jd, points) = self.cycle();
+
+        // the last event that has passed
+        let season: u8 = (1..4)
+            .rev()
+            .find(|&i| jd >= points[usize::from(i)])
+            .unwrap_or(0);
+
+        let [start, end] = [points[usize::from(season)], points[usize::from(season) + 1]];
+        90.0 * (f64::from(season) + (jd - start) / (end - start))
+    }
+
+    /// The Julian Day (at noon) and the events of the tropical year that contains it,
+    /// followed by the next March equinox.
+    fn cycle(self) -> (f64, [f64; 5]) {
+        let jd = f64::from(self.as_julian());
+        let mut year = f64::from(self.year().value()) - 10_000.0;
+
+        loop {
+            let [march, june, september, december] = events(year);
+            let next_march = events(year + 1.0)[0];
+
+            if jd < march {
+                year -= 1.0;
+            } else if jd >= next_march {
+                year += 1.0;
+            } else {
+                return (jd, [march, june, september, december, next_march]);
+            }
+        }
+    }
+}
+
+/// Julian Ephemeris Days of the March equinox, June solstice, September equinox and
+/// December solstice of the Gregorian year.
+fn events(year: f64) -> [f64; 4] {
+    core::array::from_fn(|event| {
+        if year < -1000.0 {
+            mean_event(&BEFORE_1000[event], -1.0) + (year + 1000.0) * TROPICAL_YEAR
+        } else if year < 1000.0 {
+            mean_event(&BEFORE_1000[event], year / 1000.0)
+        } else if year <= 3000.0 {
+            mean_event(&FROM_1000[event], (year - 2000.0) / 1000.0)
+        } else {
+            mean_event(&FROM_1000[event], 1.0) + (year - 3000.0) * TROPICAL_YEAR
+        }
+    })
+}
+
+/// Evaluates the polynomial of an event for `y` millennia.
+fn mean_event(coefficients: &[f64; 5], y: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * y + c)
+}
+
+/// The date of the Julian (Ephemeris) Day, clamped to the SAC13 range.
+#[allow(clippy::cast_possible_truncation)] // the input is within a few days of the SAC13 range
+fn date_of_jde(jde: f64) -> Date {
+    // `floor()` isn't available in `core`
+    let shifted = jde + 0.5;
+    let truncated = shifted as i32;
+    let day = if f64::from(truncated) > shifted {
+        truncated - 1
+    } else {
+        truncated
+    };
+
+    Date::from_julian(day).unwrap_or_else(|| {
+        if day < Date::MIN.as_julian() {
+            Date::MIN
+        } else {
+            Date::MAX
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_in_order_and_near_the_year_start() {
+        for year in (0..=Year::MAX.value()).step_by(97).filter_map(Year::new) {
+            let [march, june, september, december] = year.approx_solstices_and_equinoxes();
+
+            assert!(
+                march < june && june < september && september < december,
+                "{year}"
+            );
+
+            // SAC13 years start close to the March equinox
+            let start = Date::from_ymd(year, crate::Month::March, 1).unwrap();
+            assert!(
+                (march.as_julian() - start.as_julian()).abs() <= 3,
+                "{year}: {march}"
+            );
+        }
+    }
+
+    #[test]
+    fn longitude_increases_over_the_year() {
+        for year in [year!(A000), year!(M024), year!(Z999)] {
+            let first = Date::from_ymd(year, crate::Month::March, 1).unwrap();
+            let mut previous = first.solar_longitude_estimate();
+
+            for date in (1..year.days()).filter_map(|days| first.add_days(days.into())) {
+                let longitude = date.solar_longitude_estimate();
+
+                assert!((0.0..360.0).contains(&longitude), "{date}");
+                assert!((0.0..360.0).contains(&(date.fraction_of_cycle() * 360.0)));
+
+                // only wraps around at the March equinox
+                assert!(longitude > previous || previous > 358.0, "{date}");
+                previous = longitude;
+            }
+        }
+    }
+
+    #[test]
+    fn known_events() {
+        // 2000-03-20, 2000-06-21, 2000-09-22 and 2000-12-21
+        assert_eq!(
+            year!(M000)
+                .approx_solstices_and_equinoxes()
+                .map(|d| d.as_julian()),
+            [2_451_624, 2_451_717, 2_451_810, 2_451_900]
+        );
+    }
+}
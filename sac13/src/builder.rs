@@ -0,0 +1,349 @@
+use core::fmt::Display;
+
+use crate::{
+    Date, Month, Year,
+    date_sac13::raw_date::{YearOrdinal, yo_to_date},
+};
+
+/// Builder for [`Date`] from partially specified, unvalidated components.
+///
+/// Created with [`Date::builder()`]. Besides the year, exactly one of the following
+/// combinations has to be set:
+///
+/// - [`month`](Self::month()) and [`day`](Self::day())
+/// - [`ordinal`](Self::ordinal()) (day of the year, starting with 1)
+/// - [`week`](Self::week()) (1-52) and [`weekday`](Self::weekday()) (SAC13 weekday ordinal, 1-8)
+///
+/// [`build()`](Self::build()) reports exactly which constraint failed, which is useful
+/// for validating user input like form fields.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, Date, DateBuilderError};
+///
+/// assert_eq!(
+///     Date::builder().year(12020).month(5).day(21).build(),
+///     Ok(date!(M020 - 05 - 21))
+/// );
+///
+/// assert_eq!(
+///     Date::builder().year(12020).ordinal(365).build(),
+///     Ok(date!(M020 - 13 - 29))
+/// );
+///
+/// assert_eq!(
+///     Date::builder().year(12020).week(20).weekday(7).build(),
+///     Ok(date!(M020 - 05 - 28))
+/// );
+///
+/// assert_eq!(
+///     Date::builder().year(12020).month(5).day(29).build(),
+///     Err(DateBuilderError::DayOutOfRange { max: 28 })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[must_use]
+pub struct DateBuilder {
+    year: Option<u16>,
+    month: Option<u8>,
+    day: Option<u8>,
+    ordinal: Option<u16>,
+    week: Option<u8>,
+    weekday: Option<u8>,
+}
+
+/// The constraint that was violated when building a [`Date`] with a [`DateBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateBuilderError {
+    /// No year was given.
+    MissingYear,
+
+    /// The year is outside the SAC13 range (0 - 25'999).
+    YearOutOfRange,
+
+    /// The month is not between 1 and 13.
+    MonthOutOfRange,
+
+    /// The day is zero or larger than the month is long.
+    DayOutOfRange { max: u8 },
+
+    /// The ordinal is zero or larger than the year is long.
+    OrdinalOutOfRange { max: u16 },
+
+    /// The week is not between 1 and 52.
+    WeekOutOfRange,
+
+    /// The weekday is not between 1 and 7, or 8 on a week without synchronization day.
+    WeekdayOutOfRange,
+
+    /// Neither month and day, ordinal nor week and weekday were given.
+    Incomplete,
+
+    /// More than one way to specify the day within the year was used
+    /// (e.g. month and ordinal), or parts of different ones were mixed.
+    Conflicting,
+}
+
+impl DateBuilder {
+    /// Empty builder. Same as [`Date::builder()`].
+    pub const fn new() -> Self {
+        Self {
+            year: None,
+            month: None,
+            day: None,
+            ordinal: None,
+            week: None,
+            weekday: None,
+        }
+    }
+
+    /// Sets the year (e.g. 12'020 for M020).
+    pub const fn year(mut self, year: u16) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month ordinal (1-13).
+    pub const fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the day of the month.
+    pub const fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Sets the day of the year (starting with 1).
+    pub const fn ordinal(mut self, ordinal: u16) -> Self {
+        self.ordinal = Some(ordinal);
+        self
+    }
+
+    /// Sets the week of the year (1-52).
+    pub const fn week(mut self, week: u8) -> Self {
+        self.week = Some(week);
+        self
+    }
+
+    /// Sets the SAC13 weekday ordinal (1-7, or 8 for synchronization days).
+    ///
+    /// See [`Date::weekday_ordinal()`].
+    pub const fn weekday(mut self, weekday: u8) -> Self {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    /// Validates the components and builds the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first constraint that was violated. The year is checked first,
+    /// then whether the combination of the components is valid, then their ranges.
+    pub const fn build(self) -> Result<Date, DateBuilderError> {
+        let Some(year) = self.year else {
+            return Err(DateBuilderError::MissingYear);
+        };
+
+        let Some(year) = Year::new(year) else {
+            return Err(DateBuilderError::YearOutOfRange);
+        };
+
+        let uses_month = self.month.is_some() || self.day.is_some();
+        let uses_ordinal = self.ordinal.is_some();
+        let uses_week = self.week.is_some() || self.weekday.is_some();
+
+        match uses_month as u8 + uses_ordinal as u8 + uses_week as u8 {
+            0 => return Err(DateBuilderError::Incomplete),
+            1 => {}
+            _ => return Err(DateBuilderError::Conflicting),
+        }
+
+        match self {
+            Self {
+                month: Some(month),
+                day: Some(day),
+                ..
+            } => {
+                let Some(month) = Month::new(month) else {
+                    return Err(DateBuilderError::MonthOutOfRange);
+                };
+
+                match Date::from_ymd(year, month, day) {
+                    Some(date) => Ok(date),
+                    None => Err(DateBuilderError::DayOutOfRange {
+                        max: Date::month_len(year, month),
+                    }),
+                }
+            }
+            Self {
+                ordinal: Some(ordinal),
+                ..
+            } => {
+                let yo = if ordinal == 0 {
+                    None
+                } else {
+                    YearOrdinal::new(year, ordinal - 1)
+                };
+
+                match yo {
+                    Some(yo) => Ok(yo_to_date(yo)),
+                    None => Err(DateBuilderError::OrdinalOutOfRange { max: year.days() }),
+                }
+            }
+            Self {
+                week: Some(week),
+                weekday: Some(weekday),
+                ..
+            } => {
+                if week == 0 || week > 52 {
+                    return Err(DateBuilderError::WeekOutOfRange);
+                }
+
+                let day = match weekday {
+                    1..=7 => (week - 1) % 4 * 7 + weekday,
+                    8 if week % 4 == 0 => 29,
+                    _ => return Err(DateBuilderError::WeekdayOutOfRange),
+                };
+
+                let Some(month) = Month::new((week - 1) / 4 + 1) else {
+                    return Err(DateBuilderError::WeekOutOfRange);
+                };
+
+                match Date::from_ymd(year, month, day) {
+                    Some(date) => Ok(date),
+                    // only possible for the leap day on common years
+                    None => Err(DateBuilderError::WeekdayOutOfRange),
+                }
+            }
+            _ => Err(DateBuilderError::Incomplete),
+        }
+    }
+}
+
+impl Display for DateBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingYear => write!(f, "the year is missing"),
+            Self::YearOutOfRange => write!(f, "the year must be between 0 and 25999"),
+            Self::MonthOutOfRange => write!(f, "the month must be between 1 and 13"),
+            Self::DayOutOfRange { max } => write!(f, "the day must be between 1 and {max}"),
+            Self::OrdinalOutOfRange { max } => {
+                write!(f, "the day of the year must be between 1 and {max}")
+            }
+            Self::WeekOutOfRange => write!(f, "the week must be between 1 and 52"),
+            Self::WeekdayOutOfRange => write!(
+                f,
+                "the weekday must be between 1 and 7 (8 only on synchronization days)"
+            ),
+            Self::Incomplete => write!(
+                f,
+                "either month and day, the day of the year, or week and weekday are required"
+            ),
+            Self::Conflicting => write!(
+                f,
+                "only one of month and day, the day of the year, or week and weekday may be given"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DateBuilderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year_errors() {
+        assert_eq!(
+            DateBuilder::new().month(1).day(1).build(),
+            Err(DateBuilderError::MissingYear)
+        );
+        assert_eq!(
+            DateBuilder::new().year(26_000).month(1).day(1).build(),
+            Err(DateBuilderError::YearOutOfRange)
+        );
+    }
+
+    #[test]
+    fn incomplete_and_conflicting() {
+        let b = DateBuilder::new().year(12020);
+
+        assert_eq!(b.build(), Err(DateBuilderError::Incomplete));
+        assert_eq!(b.month(1).build(), Err(DateBuilderError::Incomplete));
+        assert_eq!(b.weekday(1).build(), Err(DateBuilderError::Incomplete));
+        assert_eq!(
+            b.month(1).day(1).ordinal(1).build(),
+            Err(DateBuilderError::Conflicting)
+        );
+        assert_eq!(
+            b.month(1).weekday(1).build(),
+            Err(DateBuilderError::Conflicting)
+        );
+    }
+
+    #[test]
+    fn ordinal_limits() {
+        let b = DateBuilder::new().year(12020);
+
+        assert_eq!(
+            b.ordinal(0).build(),
+            Err(DateBuilderError::OrdinalOutOfRange { max: 365 })
+        );
+        assert_eq!(
+            b.ordinal(366).build(),
+            Err(DateBuilderError::OrdinalOutOfRange { max: 365 })
+        );
+        assert_eq!(
+            b.year(12021).ordinal(366).build(),
+            Ok(date!(M021 - 13 - 29))
+        );
+    }
+
+    #[test]
+    fn week_and_weekday() {
+        let b = DateBuilder::new().year(12021);
+
+        assert_eq!(b.week(1).weekday(1).build(), Ok(date!(M021 - 01 - 01)));
+        assert_eq!(b.week(24).weekday(8).build(), Ok(date!(M021 - 06 - 29)));
+        assert_eq!(b.week(52).weekday(8).build(), Ok(date!(M021 - 13 - 29)));
+
+        assert_eq!(
+            b.year(12020).week(24).weekday(8).build(),
+            Err(DateBuilderError::WeekdayOutOfRange)
+        );
+        assert_eq!(
+            b.week(23).weekday(8).build(),
+            Err(DateBuilderError::WeekdayOutOfRange)
+        );
+        assert_eq!(
+            b.week(53).weekday(1).build(),
+            Err(DateBuilderError::WeekOutOfRange)
+        );
+        assert_eq!(
+            b.week(1).weekday(0).build(),
+            Err(DateBuilderError::WeekdayOutOfRange)
+        );
+    }
+
+    #[test]
+    fn month_and_day() {
+        let b = DateBuilder::new().year(12021);
+
+        assert_eq!(
+            b.month(14).day(1).build(),
+            Err(DateBuilderError::MonthOutOfRange)
+        );
+        assert_eq!(
+            b.month(6).day(30).build(),
+            Err(DateBuilderError::DayOutOfRange { max: 29 })
+        );
+        assert_eq!(
+            b.month(1).day(0).build(),
+            Err(DateBuilderError::DayOutOfRange { max: 28 })
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! Helpers that ease the migration from other date libraries.
+
+pub mod chrono_like;
+pub mod clocks;
+pub mod ticks;
+
+use crate::{DateTime, day_counts::JulianDay};
+
+/// Julian Day Number of 1970-01-01 (Gregorian), the Unix epoch.
+#[cfg(any(feature = "tzdb", feature = "wasm"))]
+pub(crate) const UNIX_EPOCH_JULIAN: i64 = 2_440_588;
+
+/// Date and time of a count of units (e.g. seconds or ticks) since the epoch, negative
+/// before it, with `units_per_second` units per second. Fractions of a second are truncated.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // range checked
+pub(crate) const fn from_units(
+    units: i64,
+    units_per_second: i64,
+    epoch_julian: i64,
+) -> Option<DateTime> {
+    let units_per_day = units_per_second * DateTime::SECONDS_PER_DAY as i64;
+    let julian = units.div_euclid(units_per_day) + epoch_julian;
+
+    if julian > i32::MAX as i64 || julian < i32::MIN as i64 {
+        return None;
+    }
+
+    let day = ok!(JulianDay::from_julian_day_number(julian as i32));
+    let seconds = units.rem_euclid(units_per_day) / units_per_second;
+
+    DateTime::new(day.to_date(), seconds as u32)
+}
+
+/// Units since the epoch, the inverse of [`from_units()`].
+///
+/// Can't overflow for dates in the SAC13 range with up to 10^7 units per second.
+pub(crate) const fn to_units(datetime: DateTime, units_per_second: i64, epoch_julian: i64) -> i64 {
+    let days = datetime.date().to_julian_day().value() as i64 - epoch_julian;
+    let seconds = days * DateTime::SECONDS_PER_DAY as i64 + datetime.seconds_of_day() as i64;
+
+    seconds * units_per_second
+}
@@ -0,0 +1,163 @@
+//! Method names familiar from `chrono::NaiveDate`.
+//!
+//! This module doesn't depend on `chrono`. It only provides an extension trait
+//! for [`Date`] with the same method names and semantics (where applicable), so
+//! porting chrono-based code to SAC13 is mostly a matter of changing the types.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::prelude::*;
+//! use sac13::compat::chrono_like::ChronoLike;
+//!
+//! let date = date!(M020 - 13 - 29);
+//!
+//! assert_eq!(date.succ_opt(), Some(date!(M021 - 01 - 01)));
+//! assert_eq!(date.with_ordinal(1), Some(date!(M020 - 01 - 01)));
+//! ```
+
+use crate::{
+    Date, LeapPolicy, Month, Year,
+    date_sac13::raw_date::{YearOrdinal, date_to_yo, yo_to_date},
+    traits::CalendarDate,
+};
+
+/// Julian Day Number of the day before 0001-01-01 (Gregorian), which is day 1 of the Common Era.
+const CE_JULIAN_OFFSET: i32 = 1_721_425;
+
+/// Extension trait with `chrono::NaiveDate`-style methods for [`Date`].
+pub trait ChronoLike: Sized {
+    /// The next day or `None` if the result would be out of range.
+    #[must_use]
+    fn succ_opt(&self) -> Option<Self>;
+
+    /// The previous day or `None` if the result would be out of range.
+    #[must_use]
+    fn pred_opt(&self) -> Option<Self>;
+
+    /// Number of days since 0001-12-31 (Gregorian), so 0001-01-01 (Gregorian) is day 1.
+    #[must_use]
+    fn num_days_from_ce(&self) -> i32;
+
+    /// Inverse of [`num_days_from_ce()`](Self::num_days_from_ce()).
+    ///
+    /// Returns `None` if the day is outside the SAC13 range.
+    #[must_use]
+    fn from_num_days_from_ce_opt(days: i32) -> Option<Self>;
+
+    /// Day of the year, starting with 1.
+    #[must_use]
+    fn ordinal(&self) -> u32;
+
+    /// Day of the year, starting with 0.
+    #[must_use]
+    fn ordinal0(&self) -> u32;
+
+    /// Same year, but with the given day of the year (starting with 1).
+    ///
+    /// Returns `None` if the ordinal doesn't exist in that year.
+    #[must_use]
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self>;
+
+    /// Same year, but with the given day of the year (starting with 0).
+    ///
+    /// Returns `None` if the ordinal doesn't exist in that year.
+    #[must_use]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<Self>;
+
+    /// Same year and month, but with the given day of the month.
+    ///
+    /// Returns `None` if the day doesn't exist in that month.
+    #[must_use]
+    fn with_day(&self, day: u32) -> Option<Self>;
+
+    /// Same year and day, but with the given month ordinal (1-13).
+    ///
+    /// Returns `None` if the resulting date doesn't exist.
+    #[must_use]
+    fn with_month(&self, month: u32) -> Option<Self>;
+
+    /// Same month and day, but with the given year.
+    ///
+    /// Returns `None` if the resulting date doesn't exist (e.g. August 29th on a common year).
+    #[must_use]
+    fn with_year(&self, year: i32) -> Option<Self>;
+}
+
+impl ChronoLike for Date {
+    fn succ_opt(&self) -> Option<Self> {
+        self.tomorrow()
+    }
+
+    fn pred_opt(&self) -> Option<Self> {
+        self.yesterday()
+    }
+
+    fn num_days_from_ce(&self) -> i32 {
+        self.as_julian() - CE_JULIAN_OFFSET
+    }
+
+    fn from_num_days_from_ce_opt(days: i32) -> Option<Self> {
+        Self::from_julian(days.checked_add(CE_JULIAN_OFFSET)?)
+    }
+
+    fn ordinal(&self) -> u32 {
+        self.ordinal0() + 1
+    }
+
+    fn ordinal0(&self) -> u32 {
+        date_to_yo(*self).day().into()
+    }
+
+    fn with_ordinal(&self, ordinal: u32) -> Option<Self> {
+        self.with_ordinal0(ordinal.checked_sub(1)?)
+    }
+
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<Self> {
+        let day = u16::try_from(ordinal0).ok()?;
+        Some(yo_to_date(YearOrdinal::new(self.year(), day)?))
+    }
+
+    fn with_day(&self, day: u32) -> Option<Self> {
+        Self::from_ymd(self.year(), self.month(), u8::try_from(day).ok()?)
+    }
+
+    fn with_month(&self, month: u32) -> Option<Self> {
+        let month = Month::new(u8::try_from(month).ok()?)?;
+        Self::from_ymd(self.year(), month, self.day())
+    }
+
+    fn with_year(&self, year: i32) -> Option<Self> {
+        let year = Year::new(u16::try_from(year).ok()?)?;
+        self.anniversary(year, LeapPolicy::Reject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn num_days_from_ce_round_trip() {
+        // 2000-03-20 (Gregorian) is day 730'199 of the Common Era
+        let date = date!(M000 - 01 - 01);
+
+        assert_eq!(date.num_days_from_ce(), 730_199);
+        assert_eq!(Date::from_num_days_from_ce_opt(730_199), Some(date));
+        assert_eq!(Date::from_num_days_from_ce_opt(i32::MAX), None);
+    }
+
+    #[test]
+    fn with_methods_validate() {
+        let date = date!(M021 - 06 - 29);
+
+        assert_eq!(date.ordinal(), 169);
+        assert_eq!(date.with_ordinal(366), Some(date!(M021 - 13 - 29)));
+        assert_eq!(date.with_ordinal(367), None);
+        assert_eq!(date.with_ordinal(0), None);
+
+        assert_eq!(date.with_year(12020), None);
+        assert_eq!(date.with_month(7), None);
+        assert_eq!(date.with_day(1), Some(date!(M021 - 06 - 01)));
+    }
+}
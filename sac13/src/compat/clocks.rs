@@ -0,0 +1,238 @@
+//! Time stamps of clock synchronization sources: NTP and GPS.
+//!
+//! - NTP counts seconds since 1900-01-01 00:00 UTC in 32 bits, so the count wraps every
+//!   136 years. The wraps are numbered as eras, era 0 ends in 2036.
+//! - GPS counts weeks since 1980-01-06 00:00 and seconds within the week (time of week).
+//!   GPS time has no leap seconds, so the results are in [GPS time](crate::TimeScale::Gps)
+//!   and need [`DateTime::convert_time_scale()`] to get UTC.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::compat::clocks::{from_gps_week, from_ntp_near};
+//! use sac13::date;
+//!
+//! // 2024-05-21 13:30:00
+//! let dt = from_ntp_near(3_925_287_000, date!(M020 - 01 - 01)).unwrap();
+//!
+//! assert_eq!(dt.date(), date!(M024 - 03 - 07));
+//! assert_eq!((dt.hour(), dt.minute(), dt.second()), (13, 30, 0));
+//!
+//! // the same instant in GPS time (18 leap seconds later)
+//! let gps = from_gps_week(2315, 221_418).unwrap();
+//! assert_eq!((gps.date(), gps.second()), (dt.date(), 18));
+//! ```
+
+use super::{from_units, to_units};
+use crate::{Date, DateTime};
+
+/// Number of seconds per week, the range of the GPS time of week.
+pub const SECONDS_PER_WEEK: u32 = 7 * DateTime::SECONDS_PER_DAY;
+
+/// Number of seconds per NTP era.
+const NTP_ERA_SECONDS: i64 = 1 << 32;
+
+/// Julian Day Number of 1900-01-01 (Gregorian).
+const NTP_EPOCH_JULIAN: i64 = 2_415_021;
+
+/// Julian Day Number of 1980-01-06 (Gregorian).
+const GPS_EPOCH_JULIAN: i64 = 2_444_245;
+
+/// Date and time (UTC) of the seconds field of an NTP time stamp in the given era.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub const fn from_ntp(era: i32, seconds: u32) -> Option<DateTime> {
+    from_units(
+        era as i64 * NTP_ERA_SECONDS + seconds as i64,
+        1,
+        NTP_EPOCH_JULIAN,
+    )
+}
+
+/// Date and time (UTC) of the seconds field of an NTP time stamp, in the era that puts it
+/// closest to the reference date (at most 68 years away), e.g. the build date of the firmware.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub const fn from_ntp_near(seconds: u32, reference: Date) -> Option<DateTime> {
+    let reference = to_units(start_of_day(reference), 1, NTP_EPOCH_JULIAN);
+    let era = (reference - seconds as i64 + NTP_ERA_SECONDS / 2).div_euclid(NTP_ERA_SECONDS);
+
+    from_units(era * NTP_ERA_SECONDS + seconds as i64, 1, NTP_EPOCH_JULIAN)
+}
+
+/// The NTP era and seconds field of the date and time (UTC).
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // eras of the SAC13 range fit
+pub const fn to_ntp(datetime: DateTime) -> (i32, u32) {
+    let seconds = to_units(datetime, 1, NTP_EPOCH_JULIAN);
+
+    (
+        seconds.div_euclid(NTP_ERA_SECONDS) as i32,
+        seconds.rem_euclid(NTP_ERA_SECONDS) as u32,
+    )
+}
+
+/// Date and time (GPS time) of a full GPS week number and the time of week in seconds.
+///
+/// Returns `None` if the time of week isn't less than [`SECONDS_PER_WEEK`] or the date
+/// is outside the SAC13 range.
+#[must_use]
+pub const fn from_gps_week(week: u32, seconds_of_week: u32) -> Option<DateTime> {
+    if seconds_of_week >= SECONDS_PER_WEEK {
+        return None;
+    }
+
+    from_units(
+        week as i64 * SECONDS_PER_WEEK as i64 + seconds_of_week as i64,
+        1,
+        GPS_EPOCH_JULIAN,
+    )
+}
+
+/// Like [`from_gps_week()`], but with the 10-bit week number of the legacy navigation
+/// message, which rolls over every 1024 weeks (about 19.6 years).
+///
+/// The first week on or after `not_before` with the given week number is used,
+/// so the result is correct for 1024 weeks after that date.
+///
+/// ```
+/// use sac13::compat::clocks::{from_gps_week, from_gps_week_10bit};
+/// use sac13::date;
+///
+/// assert_eq!(
+///     from_gps_week_10bit(2315 % 1024, 0, date!(M020 - 01 - 01)),
+///     from_gps_week(2315, 0)
+/// );
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // non-negative and small
+pub const fn from_gps_week_10bit(
+    week: u16,
+    seconds_of_week: u32,
+    not_before: Date,
+) -> Option<DateTime> {
+    if week >= 1024 {
+        return None;
+    }
+
+    let first_week =
+        to_units(start_of_day(not_before), 1, GPS_EPOCH_JULIAN).div_euclid(SECONDS_PER_WEEK as i64);
+
+    if first_week < 0 {
+        return from_gps_week(week as u32, seconds_of_week);
+    }
+
+    let rollovers = (first_week - week as i64 + 1023) / 1024;
+
+    from_gps_week((rollovers * 1024) as u32 + week as u32, seconds_of_week)
+}
+
+/// The full GPS week number and the time of week of the date and time (GPS time).
+///
+/// Returns `None` for dates before 1980-01-06 (Gregorian).
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // weeks of the SAC13 range fit
+pub const fn to_gps_week(datetime: DateTime) -> Option<(u32, u32)> {
+    let seconds = to_units(datetime, 1, GPS_EPOCH_JULIAN);
+
+    if seconds < 0 {
+        return None;
+    }
+
+    Some((
+        (seconds / SECONDS_PER_WEEK as i64) as u32,
+        (seconds % SECONDS_PER_WEEK as i64) as u32,
+    ))
+}
+
+/// Midnight at the start of the date.
+const fn start_of_day(date: Date) -> DateTime {
+    let Some(datetime) = DateTime::new(date, 0) else {
+        unreachable!()
+    };
+
+    datetime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CalendarDate, LeapSeconds, TimeScale};
+
+    #[test]
+    fn ntp_eras() {
+        let epoch = from_ntp(0, 0).unwrap();
+        assert_eq!(epoch.date(), date_greg!(1900 - 01 - 01).convert());
+
+        // 2036-02-07 06:28:16, the first second of era 1
+        let era_1 = from_ntp(1, 0).unwrap();
+        assert_eq!(era_1.date(), date_greg!(2036 - 02 - 07).convert());
+        assert_eq!(to_ntp(era_1), (1, 0));
+        assert_eq!(from_ntp_near(0, date!(M030 - 01 - 01)), Some(era_1));
+        assert_eq!(from_ntp_near(0, date!(L950 - 01 - 01)), Some(epoch));
+
+        assert_eq!(
+            to_ntp(DateTime::new(Date::UNIX_EPOCH_DATE, 0).unwrap()),
+            (0, 2_208_988_800)
+        );
+
+        for datetime in [
+            DateTime::new(Date::MIN, 0).unwrap(),
+            DateTime::new(Date::MAX, DateTime::SECONDS_PER_DAY - 1).unwrap(),
+        ] {
+            let (era, seconds) = to_ntp(datetime);
+
+            assert_eq!(from_ntp(era, seconds), Some(datetime));
+            assert_eq!(from_ntp_near(seconds, datetime.date()), Some(datetime));
+        }
+
+        assert_eq!(from_ntp(i32::MIN, 0), None);
+        assert_eq!(from_ntp(i32::MAX, 0), None);
+    }
+
+    #[test]
+    fn gps_weeks() {
+        let epoch = from_gps_week(0, 0).unwrap();
+
+        assert_eq!(epoch.date(), date_greg!(1980 - 01 - 06).convert());
+        assert_eq!(to_gps_week(epoch), Some((0, 0)));
+        assert_eq!(
+            epoch
+                .date()
+                .yesterday()
+                .and_then(|d| to_gps_week(DateTime::new(d, 0)?)),
+            None
+        );
+        assert_eq!(from_gps_week(0, SECONDS_PER_WEEK), None);
+        assert_eq!(from_gps_week(u32::MAX, 0), None);
+
+        // the second rollover on 2019-04-07
+        let rollover = from_gps_week(2048, 0).unwrap();
+        assert_eq!(rollover.date(), date_greg!(2019 - 04 - 07).convert());
+        assert_eq!(
+            from_gps_week_10bit(0, 0, date!(M019 - 01 - 01)),
+            Some(rollover)
+        );
+        assert_eq!(from_gps_week_10bit(0, 0, rollover.date()), Some(rollover));
+        assert_eq!(
+            from_gps_week_10bit(1023, 0, rollover.date()),
+            from_gps_week(3071, 0)
+        );
+        assert_eq!(from_gps_week_10bit(5, 0, Date::MIN), from_gps_week(5, 0));
+        assert_eq!(from_gps_week_10bit(1024, 0, Date::MIN), None);
+    }
+
+    #[test]
+    fn gps_to_utc() {
+        let table: &[(Date, i32)] = &[(date!(M016 - 11 - 08), 37)];
+        let gps = from_gps_week(2315, 221_418).unwrap();
+        let utc = gps
+            .convert_time_scale(TimeScale::Gps, TimeScale::Utc, table)
+            .unwrap();
+
+        assert_eq!(table.tai_minus_utc(utc.date()), Some(37));
+        assert_eq!((utc.hour(), utc.minute(), utc.second()), (13, 30, 0));
+    }
+}
@@ -0,0 +1,148 @@
+//! Conversions from and to the 100-nanosecond tick counts of .NET and Windows.
+//!
+//! - .NET `DateTime.Ticks` counts from 0001-01-01 00:00 (Gregorian) and is limited to
+//!   the years 1 to 9999.
+//! - Windows `FILETIME` counts from 1601-01-01 00:00 UTC.
+//!
+//! [`DateTime`] has a resolution of one second, so fractions of a second are truncated.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::compat::ticks::{from_dotnet_ticks, from_filetime, to_dotnet_ticks};
+//! use sac13::date;
+//!
+//! // new DateTime(2024, 5, 21, 13, 30, 0).Ticks
+//! let dt = from_dotnet_ticks(638_518_950_000_000_000).unwrap();
+//!
+//! assert_eq!(dt.date(), date!(M024 - 03 - 07));
+//! assert_eq!((dt.hour(), dt.minute()), (13, 30));
+//! assert_eq!(to_dotnet_ticks(dt), Some(638_518_950_000_000_000));
+//!
+//! assert_eq!(from_filetime(133_607_718_000_000_000), Some(dt));
+//! ```
+
+use super::{from_units, to_units};
+use crate::DateTime;
+
+/// Number of ticks per second.
+pub const TICKS_PER_SECOND: i64 = 10_000_000;
+
+/// Number of ticks per day.
+pub const TICKS_PER_DAY: i64 = TICKS_PER_SECOND * DateTime::SECONDS_PER_DAY as i64;
+
+/// Ticks of 9999-12-31 23:59:59.9999999, the last value of .NET `DateTime`.
+pub const MAX_DOTNET_TICKS: i64 = 3_155_378_975_999_999_999;
+
+/// Julian Day Number of 0001-01-01 (Gregorian).
+const DOTNET_EPOCH_JULIAN: i64 = 1_721_426;
+
+/// Julian Day Number of 1601-01-01 (Gregorian).
+const FILETIME_EPOCH_JULIAN: i64 = 2_305_814;
+
+/// Date and time of .NET `DateTime.Ticks`.
+///
+/// Returns `None` for ticks outside `0..=MAX_DOTNET_TICKS`.
+#[must_use]
+pub const fn from_dotnet_ticks(ticks: i64) -> Option<DateTime> {
+    if ticks < 0 || ticks > MAX_DOTNET_TICKS {
+        return None;
+    }
+
+    from_units(ticks, TICKS_PER_SECOND, DOTNET_EPOCH_JULIAN)
+}
+
+/// .NET `DateTime.Ticks` of the date and time.
+///
+/// Returns `None` for dates before 0001-01-01 and after 9999-12-31 (Gregorian).
+#[must_use]
+pub const fn to_dotnet_ticks(datetime: DateTime) -> Option<i64> {
+    let ticks = to_units(datetime, TICKS_PER_SECOND, DOTNET_EPOCH_JULIAN);
+
+    if ticks < 0 || ticks > MAX_DOTNET_TICKS {
+        None
+    } else {
+        Some(ticks)
+    }
+}
+
+/// Date and time (UTC) of a Windows `FILETIME`, with the two 32-bit halves combined
+/// as `(high << 32) | low`.
+///
+/// Returns `None` if the date is after [`Date::MAX`](crate::CalendarDate::MAX).
+#[must_use]
+pub const fn from_filetime(filetime: u64) -> Option<DateTime> {
+    if filetime > i64::MAX as u64 {
+        return None;
+    }
+
+    from_units(
+        filetime.cast_signed(),
+        TICKS_PER_SECOND,
+        FILETIME_EPOCH_JULIAN,
+    )
+}
+
+/// Windows `FILETIME` of the date and time (UTC).
+///
+/// Returns `None` for dates before 1601-01-01 (Gregorian).
+#[must_use]
+pub const fn to_filetime(datetime: DateTime) -> Option<u64> {
+    let ticks = to_units(datetime, TICKS_PER_SECOND, FILETIME_EPOCH_JULIAN);
+
+    if ticks < 0 {
+        None
+    } else {
+        Some(ticks.cast_unsigned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CalendarDate, Date};
+
+    #[test]
+    fn epochs() {
+        let dotnet_epoch = from_dotnet_ticks(0).unwrap();
+        let filetime_epoch = from_filetime(0).unwrap();
+
+        assert_eq!(dotnet_epoch.date(), date_greg!(0001 - 01 - 01).convert());
+        assert_eq!(filetime_epoch.date(), date_greg!(1601 - 01 - 01).convert());
+        assert_eq!(
+            from_dotnet_ticks(to_units(
+                filetime_epoch,
+                TICKS_PER_SECOND,
+                DOTNET_EPOCH_JULIAN
+            )),
+            Some(filetime_epoch)
+        );
+
+        // 1970-01-01, the Unix epoch
+        assert_eq!(
+            from_filetime(116_444_736_000_000_000).map(|dt| dt.date()),
+            Some(Date::UNIX_EPOCH_DATE)
+        );
+    }
+
+    #[test]
+    fn limits() {
+        let last = from_dotnet_ticks(MAX_DOTNET_TICKS).unwrap();
+
+        assert_eq!(last.date(), date_greg!(9999 - 12 - 31).convert());
+        assert_eq!(last.seconds_of_day(), DateTime::SECONDS_PER_DAY - 1);
+        assert_eq!(to_dotnet_ticks(last), Some(MAX_DOTNET_TICKS - 9_999_999));
+
+        assert_eq!(from_dotnet_ticks(-1), None);
+        assert_eq!(from_dotnet_ticks(MAX_DOTNET_TICKS + 1), None);
+        assert_eq!(from_filetime(u64::MAX), None);
+
+        let min = DateTime::new(Date::MIN, 0).unwrap();
+        let max = DateTime::new(Date::MAX, DateTime::SECONDS_PER_DAY - 1).unwrap();
+
+        assert_eq!(to_dotnet_ticks(min), None);
+        assert_eq!(to_dotnet_ticks(max), None);
+        assert_eq!(to_filetime(min), None);
+        assert_eq!(to_filetime(max).and_then(from_filetime), Some(max));
+    }
+}
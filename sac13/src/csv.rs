@@ -0,0 +1,308 @@
+//! Field codecs for dates in spreadsheet formats, for `#[serde(with = "...")]`.
+//!
+//! The [`Serialize`](serde::Serialize) implementation of [`Date`] writes SAC13 dates,
+//! but spreadsheets (and the CSV files they export) use Gregorian dates or serial day numbers.
+//! The modules convert on the fly, so records can use [`Date`] directly, e.g. with the `csv` crate.
+//!
+//! Requires the `csv` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::{Date, date};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     #[serde(with = "sac13::csv::gregorian_iso")]
+//!     issued: Date,
+//!
+//!     #[serde(with = "sac13::csv::excel_serial")]
+//!     due: Date,
+//! }
+//!
+//! let invoice: Invoice = serde_json::from_str(r#"{"issued":"2024-05-21","due":45464}"#).unwrap();
+//!
+//! assert_eq!(invoice.issued, date!(M024 - 03 - 07));
+//! assert_eq!(invoice.due, date!(M024 - 04 - 10));
+//! ```
+
+use core::fmt;
+
+use serde::de;
+
+use crate::{Date, GregorianDate, day_counts::ExcelDay, traits::CalendarDate};
+
+/// Gregorian dates in ISO 8601 format (`2024-05-21`), see [`GregorianDate::to_iso8601()`].
+pub mod gregorian_iso {
+    use serde::{Deserializer, Serializer};
+
+    use super::{CalendarDate, Date, GregorianDate, StrVisitor};
+
+    /// Serializes the Gregorian date of the SAC13 date.
+    ///
+    /// # Errors
+    ///
+    /// Only errors of the serializer.
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&GregorianDate::from_sac13(*date).to_iso8601())
+    }
+
+    /// Deserializes a Gregorian date in ISO 8601 format.
+    ///
+    /// # Errors
+    ///
+    /// If the input isn't a valid date in the SAC13 range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a Gregorian date like 2024-05-21",
+            parse: |v| {
+                GregorianDate::from_iso8601(v)
+                    .ok()
+                    .map(CalendarDate::convert)
+            },
+        })
+    }
+}
+
+/// Gregorian dates in the US format of spreadsheets (`5/21/2024`, month first).
+///
+/// Leading zeros (`05/21/2024`) are accepted when deserializing.
+pub mod gregorian_us {
+    use serde::{Deserializer, Serializer};
+
+    use super::{Date, GregorianDate, StrVisitor};
+
+    /// Serializes the Gregorian date of the SAC13 date.
+    ///
+    /// # Errors
+    ///
+    /// Only errors of the serializer.
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let date = GregorianDate::from_sac13(*date);
+        serializer.collect_str(&format_args!(
+            "{}/{}/{}",
+            date.month(),
+            date.day(),
+            date.year()
+        ))
+    }
+
+    /// Deserializes a Gregorian date in US format.
+    ///
+    /// # Errors
+    ///
+    /// If the input isn't a valid date in the SAC13 range.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a Gregorian date like 5/21/2024",
+            parse: |v| {
+                let mut parts = v.split('/');
+                let month = parts.next()?.parse().ok()?;
+                let day = parts.next()?.parse().ok()?;
+                let year = parts.next()?.parse().ok()?;
+
+                if parts.next().is_some() {
+                    return None;
+                }
+
+                Date::from_gregorian_ymd(year, month, day)
+            },
+        })
+    }
+}
+
+/// Serial day numbers of spreadsheets in the 1900 date system (Excel, Lotus 1-2-3),
+/// where 1 is 1900-01-01 and 45433 is 2024-05-21 (see [`ExcelDay`]).
+///
+/// The 1900 date system has a day 60 for February 29th, 1900, which doesn't exist.
+/// It's rejected when deserializing and numbers of earlier days are shifted accordingly.
+/// Days before 1900-01-01 can't be serialized. When deserializing, fractional
+/// numbers (date and time) are accepted and the time is ignored, and so are numbers in strings,
+/// which is how formats without types (like CSV) contain them.
+pub mod excel_serial {
+    use serde::{Deserializer, Serializer, ser};
+
+    use super::{Date, SerialVisitor, serial_of_date};
+
+    /// Serializes the serial day number of the date.
+    ///
+    /// # Errors
+    ///
+    /// If the date is before 1900-01-01 (Gregorian).
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let serial = serial_of_date(*date)
+            .ok_or_else(|| ser::Error::custom("dates before 1900-01-01 have no serial number"))?;
+
+        serializer.serialize_u32(serial)
+    }
+
+    /// Deserializes a serial day number.
+    ///
+    /// # Errors
+    ///
+    /// If the input isn't a positive number, or is 60 (the fictitious 1900-02-29).
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        deserializer.deserialize_any(SerialVisitor)
+    }
+}
+
+fn date_of_serial(serial: i64) -> Option<Date> {
+    ExcelDay::from_serial(i32::try_from(serial).ok()?).map(ExcelDay::to_date)
+}
+
+fn serial_of_date(date: Date) -> Option<u32> {
+    date.to_excel_day()
+        .to_serial()
+        .and_then(|serial| u32::try_from(serial).ok())
+}
+
+struct StrVisitor {
+    expecting: &'static str,
+    parse: fn(&str) -> Option<Date>,
+}
+
+impl de::Visitor<'_> for StrVisitor {
+    type Value = Date;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        (self.parse)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+struct SerialVisitor;
+
+impl de::Visitor<'_> for SerialVisitor {
+    type Value = Date;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a spreadsheet serial day number like 45433")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        date_of_serial(v).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        i64::try_from(v)
+            .ok()
+            .and_then(date_of_serial)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    #[allow(clippy::cast_possible_truncation)] // out of range values saturate and are rejected
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        // truncating is the same as rounding down for the valid (positive) numbers
+        Some(v)
+            .filter(|v| *v >= 1.0)
+            .and_then(|v| date_of_serial(v as i64))
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Float(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let v = v.trim();
+
+        if let Ok(serial) = v.parse::<i64>() {
+            return self.visit_i64(serial);
+        }
+
+        match v.parse::<f64>() {
+            Ok(serial) => self.visit_f64(serial),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use std::prelude::rust_2024::*;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "gregorian_iso")]
+        iso: Date,
+
+        #[serde(with = "gregorian_us")]
+        us: Date,
+
+        #[serde(with = "excel_serial")]
+        serial: Date,
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let input = "iso,us,serial\n2024-05-21,05/21/2024,45433\n1900-03-01,3/1/1900,61.75\n";
+
+        let rows: Vec<Row> = ::csv::Reader::from_reader(input.as_bytes())
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows[0].iso, date!(M024 - 03 - 07));
+        assert!(
+            rows.iter()
+                .all(|row| row.iso == row.us && row.us == row.serial)
+        );
+
+        let mut writer = ::csv::Writer::from_writer(Vec::new());
+        for row in &rows {
+            writer.serialize(row).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner().unwrap()).unwrap(),
+            "iso,us,serial\n2024-05-21,5/21/2024,45433\n1900-03-01,3/1/1900,61\n"
+        );
+    }
+
+    #[test]
+    fn serial_numbers_around_the_leap_year_bug() {
+        let gregorian = |serial| date_of_serial(serial).map(GregorianDate::from_sac13);
+
+        assert_eq!(gregorian(1), GregorianDate::from_ymd(1900, 1, 1));
+        assert_eq!(gregorian(59), GregorianDate::from_ymd(1900, 2, 28));
+        assert_eq!(gregorian(60), None);
+        assert_eq!(gregorian(61), GregorianDate::from_ymd(1900, 3, 1));
+        assert_eq!(gregorian(0), None);
+
+        for serial in (1..100).chain([25_569, 45_432, 2_958_465]) {
+            if serial != 60 {
+                assert_eq!(
+                    date_of_serial(serial).and_then(serial_of_date),
+                    u32::try_from(serial).ok()
+                );
+            }
+        }
+
+        assert_eq!(serial_of_date(Date::MIN), None);
+        assert_eq!(
+            date_of_serial(1)
+                .and_then(CalendarDate::yesterday)
+                .and_then(serial_of_date),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        #[derive(Deserialize)]
+        struct Serial {
+            #[serde(with = "excel_serial", rename = "date")]
+            _date: Date,
+        }
+
+        for json in ["0", "60", "-1", "0.5", "\"x\"", "\"60\"", "1e20"] {
+            assert!(
+                serde_json::from_str::<Serial>(&format!(r#"{{"date":{json}}}"#)).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use core::{cmp::Ordering, fmt::Display};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
 
-use crate::{scalars::JulianDay, traits::CalendarDate};
+use crate::{
+    Date, Month, ParseError, ParseErrorKind, Year, YearMonth,
+    scalars::JulianDay,
+    traits::{CalendarDate, Datelike},
+};
 
 /// Gregorian Calendar date _(proleptic, when applicable)_.
 ///
@@ -54,6 +58,26 @@ impl GregorianDate {
         Self { year, month, day }.limit_sac13()
     }
 
+    /// The Gregorian date of the given SAC13 date.
+    ///
+    /// Same as [`convert()`](CalendarDate::convert()), but infallible and usable in const contexts.
+    ///
+    /// ```
+    /// use sac13::{GregorianDate, date, date_greg};
+    ///
+    /// const START: GregorianDate = GregorianDate::from_sac13(date!(M000 - 01 - 01));
+    /// assert_eq!(START, date_greg!(2000 - 03 - 20));
+    /// ```
+    #[must_use]
+    pub const fn from_sac13(date: Date) -> Self {
+        // every SAC13 date has a Gregorian date
+        let Some(gregorian) = Self::from_julian_day_number(date.to_julian_day().value()) else {
+            unreachable!()
+        };
+
+        gregorian
+    }
+
     #[must_use]
     pub const fn is_leap_year(year: i16) -> bool {
         // TODO verify negative years, but should work because we check "== 0"
@@ -97,51 +121,362 @@ impl GregorianDate {
         self.day
     }
 
-    const fn limit_sac13(self) -> Option<Self> {
-        if matches!(Self::const_cmp(self, Self::MIN), Ordering::Less)
-            || matches!(Self::const_cmp(self, Self::MAX), Ordering::Greater)
-        {
-            None
-        } else {
-            Some(self)
+    /// Date of Easter Sunday in the given year, computed with the Gregorian computus
+    /// (the anonymous Gregorian algorithm, also known as Meeus/Jones/Butcher).
+    ///
+    /// The result is always between March 22nd and April 25th. Other movable feasts are a
+    /// fixed number of days away (e.g. Good Friday is 2 days before, Pentecost 49 days after Easter).
+    ///
+    /// Returns [`None`] if Easter of that year is outside the range of SAC13.
+    /// See [`Date::easter()`](crate::Date::easter()) for the SAC13 date.
+    ///
+    /// ```
+    /// use sac13::{GregorianDate, date_greg};
+    ///
+    /// assert_eq!(GregorianDate::easter(2024), Some(date_greg!(2024 - 03 - 31)));
+    /// assert_eq!(GregorianDate::easter(2025), Some(date_greg!(2025 - 04 - 20)));
+    /// assert_eq!(GregorianDate::easter(16000), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::many_single_char_names)] // names of the published algorithm
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // month 3 - 4, day 1 - 31
+    pub const fn easter(year: i16) -> Option<Self> {
+        let y = year as i32;
+
+        let a = y.rem_euclid(19);
+        let b = y.div_euclid(100);
+        let c = y.rem_euclid(100);
+        let d = b.div_euclid(4);
+        let e = b.rem_euclid(4);
+        let f = (b + 8).div_euclid(25);
+        let g = (b - f + 1).div_euclid(3);
+        let h = (19 * a + b - d - g + 15).rem_euclid(30);
+        let i = c / 4;
+        let k = c % 4;
+        let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+
+        Self {
+            year,
+            month: (n / 31) as u8,
+            day: (n % 31 + 1) as u8,
         }
+        .limit_sac13()
     }
 
-    const fn const_cmp(lhs: Self, rhs: Self) -> Ordering {
-        if lhs.year < rhs.year {
-            Ordering::Less
-        } else if lhs.year > rhs.year {
-            Ordering::Greater
-        } else if lhs.month < rhs.month {
-            Ordering::Less
-        } else if lhs.month > rhs.month {
-            Ordering::Greater
-        } else if lhs.day < rhs.day {
-            Ordering::Less
-        } else if lhs.day > rhs.day {
-            Ordering::Greater
-        } else {
-            Ordering::Equal
+    /// Displays the date in the ISO 8601 calendar date format.
+    ///
+    /// Years from 0 to 9999 are written with four digits (`YYYY-MM-DD`). All other years use
+    /// the expanded representation with an explicit sign and five digits (`±YYYYY-MM-DD`),
+    /// which covers the whole range from [`MIN`](Self::MIN) to [`MAX`](Self::MAX).
+    /// Years before 1 CE use astronomical year numbering (1 BCE is year 0).
+    ///
+    /// [`from_iso8601()`](Self::from_iso8601()) parses exactly this format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    ///
+    /// assert_eq!(date_greg!(2020 - 03 - 21).to_iso8601().to_string(), "2020-03-21");
+    /// assert_eq!(GregorianDate::MAX.to_iso8601().to_string(), "+16000-03-17");
+    /// assert_eq!(GregorianDate::MIN.to_iso8601().to_string(), "-10000-03-22");
+    ///
+    /// let date = GregorianDate::from_ymd(-500, 1, 1).unwrap();
+    /// assert_eq!(date.to_iso8601().to_string(), "-00500-01-01");
+    /// ```
+    #[must_use]
+    pub const fn to_iso8601(&self) -> impl Display + use<> {
+        struct Iso8601(GregorianDate);
+
+        impl Display for Iso8601 {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let GregorianDate { year, month, day } = self.0;
+
+                if (0..=9999).contains(&year) {
+                    write!(f, "{year:04}-{month:02}-{day:02}")
+                } else {
+                    write!(f, "{year:+06}-{month:02}-{day:02}")
+                }
+            }
         }
-    }
-}
 
-impl Display for GregorianDate {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+        Iso8601(*self)
     }
-}
 
-impl CalendarDate for GregorianDate {
-    const MIN: Self = Self::MIN;
-    const MAX: Self = Self::MAX;
+    /// Parses a date in the format of [`to_iso8601()`](Self::to_iso8601()).
+    ///
+    /// This is a strict parser: the year must use the expanded representation (sign and
+    /// five digits) exactly if it's outside 0 to 9999. Use [`parse_date_str()`](crate::parse_date_str())
+    /// for user input in other formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] that points to the first offending part of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{GregorianDate, ParseErrorKind, date_greg};
+    ///
+    /// assert_eq!(GregorianDate::from_iso8601("2020-03-21"), Ok(date_greg!(2020 - 03 - 21)));
+    /// assert_eq!(GregorianDate::from_iso8601("+12020-03-21").map(|d| d.year()), Ok(12020));
+    ///
+    /// let error = GregorianDate::from_iso8601("+02020-03-21").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidYear);
+    /// ```
+    pub fn from_iso8601(input: &str) -> Result<Self, ParseError> {
+        let bytes = input.as_bytes();
+
+        if let Some(i) = bytes.iter().position(|b| !b.is_ascii()) {
+            return Err(ParseError::invalid_character(bytes, i));
+        }
+
+        let expanded = matches!(bytes.first(), Some(b'+' | b'-'));
+        let year_len = if expanded { 6 } else { 4 };
+
+        if bytes.len() != year_len + 6 {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidLength,
+                0..bytes.len(),
+            ));
+        }
+
+        for (i, &b) in bytes.iter().enumerate().skip(usize::from(expanded)) {
+            let valid = if i == year_len || i == year_len + 3 {
+                b == b'-'
+            } else {
+                b.is_ascii_digit()
+            };
+
+            if !valid {
+                return Err(ParseError::invalid_character(bytes, i));
+            }
+        }
+
+        let number = |range: core::ops::Range<usize>| {
+            bytes[range]
+                .iter()
+                .fold(0, |acc, &b| acc * 10 + i32::from(b - b'0'))
+        };
+
+        let year = match bytes[0] {
+            b'-' => -number(1..6),
+            b'+' => number(1..6),
+            _ => number(0..4),
+        };
+
+        if expanded == (0..=9999).contains(&year) {
+            return Err(ParseError::new(ParseErrorKind::InvalidYear, 0..year_len));
+        }
+
+        Self::from_year_and_rest(bytes, year_len, year)
+    }
 
+    /// Displays the date with the given [format of the year](GregorianYearFormat).
+    ///
+    /// The [`Display`] implementation uses [`Minimal`](GregorianYearFormat::Minimal)
+    /// and the alternate flag (`{:#}`) [`Padded`](GregorianYearFormat::Padded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{GregorianDate, GregorianYearFormat};
+    ///
+    /// let max = GregorianDate::MAX;
+    /// let early = GregorianDate::from_ymd(999, 1, 1).unwrap();
+    ///
+    /// assert_eq!(max.to_string(), "16000-03-17");
+    /// assert_eq!(format!("{max:#}"), "+16000-03-17");
+    /// assert_eq!(format!("{early:#}"), "0999-01-01");
+    /// assert_eq!(early.display_with(GregorianYearFormat::Iso8601).to_string(), "0999-01-01");
+    /// ```
     #[must_use]
+    pub const fn display_with(&self, format: GregorianYearFormat) -> impl Display + use<> {
+        struct WithFormat(GregorianDate, GregorianYearFormat);
+
+        impl Display for WithFormat {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let Self(GregorianDate { year, month, day }, format) = *self;
+                let year = i32::from(year);
+
+                match format {
+                    GregorianYearFormat::Minimal => write!(f, "{year}")?,
+                    GregorianYearFormat::Padded if year > 9999 => write!(f, "+{year}")?,
+                    GregorianYearFormat::Padded if year < 0 => write!(f, "-{:04}", -year)?,
+                    GregorianYearFormat::Padded => write!(f, "{year:04}")?,
+                    GregorianYearFormat::Iso8601 if (0..=9999).contains(&year) => {
+                        write!(f, "{year:04}")?;
+                    }
+                    GregorianYearFormat::Iso8601 => write!(f, "{year:+06}")?,
+                }
+
+                write!(f, "-{month:02}-{day:02}")
+            }
+        }
+
+        WithFormat(*self, format)
+    }
+
+    /// Parses a date exactly in the output format of [`display_with()`](Self::display_with()).
+    ///
+    /// The [`FromStr`] implementation accepts all formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] that points to the first offending part of the input.
+    /// The year has the kind [`InvalidYear`](ParseErrorKind::InvalidYear) if it's
+    /// written in a different format (e.g. with a missing sign or padding).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{GregorianDate, GregorianYearFormat, ParseErrorKind};
+    ///
+    /// let padded = GregorianDate::parse_with("+16000-03-17", GregorianYearFormat::Padded);
+    /// assert_eq!(padded, Ok(GregorianDate::MAX));
+    ///
+    /// let minimal = GregorianDate::parse_with("0999-01-01", GregorianYearFormat::Minimal);
+    /// assert_eq!(minimal.unwrap_err().kind(), ParseErrorKind::InvalidYear);
+    ///
+    /// let any: GregorianDate = "0999-01-01".parse().unwrap();
+    /// assert_eq!(Some(any), GregorianDate::from_ymd(999, 1, 1));
+    /// ```
+    pub fn parse_with(input: &str, format: GregorianYearFormat) -> Result<Self, ParseError> {
+        Self::parse_any_year(input, Some(format))
+    }
+
+    /// Parses a date with an optional sign and one to five year digits.
+    /// If a format is given, the year must be written exactly like in that format.
+    fn parse_any_year(
+        input: &str,
+        format: Option<GregorianYearFormat>,
+    ) -> Result<Self, ParseError> {
+        let bytes = input.as_bytes();
+
+        if let Some(i) = bytes.iter().position(|b| !b.is_ascii()) {
+            return Err(ParseError::invalid_character(bytes, i));
+        }
+
+        let sign_len = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+        let digits = bytes[sign_len..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        let year_len = sign_len + digits;
+
+        if digits == 0 {
+            return Err(if year_len < bytes.len() {
+                ParseError::invalid_character(bytes, year_len)
+            } else {
+                ParseError::new(ParseErrorKind::InvalidLength, 0..bytes.len())
+            });
+        }
+
+        if digits > 5 {
+            return Err(ParseError::new(ParseErrorKind::InvalidYear, 0..year_len));
+        }
+
+        if bytes.len() != year_len + 6 {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidLength,
+                0..bytes.len(),
+            ));
+        }
+
+        let magnitude = bytes[sign_len..year_len]
+            .iter()
+            .fold(0, |acc, &b| acc * 10 + i32::from(b - b'0'));
+        let year = if bytes[0] == b'-' {
+            -magnitude
+        } else {
+            magnitude
+        };
+
+        // the year zero is never written as `-0`
+        if bytes[0] == b'-' && magnitude == 0 {
+            return Err(ParseError::new(ParseErrorKind::InvalidYear, 0..year_len));
+        }
+
+        if let Some(format) = format {
+            let natural_digits = magnitude.checked_ilog10().map_or(1, |log| log as usize + 1);
+
+            let (signed, digits_expected) = match format {
+                GregorianYearFormat::Minimal => (year < 0, natural_digits),
+                GregorianYearFormat::Padded => (!(0..=9999).contains(&year), natural_digits.max(4)),
+                GregorianYearFormat::Iso8601 if (0..=9999).contains(&year) => (false, 4),
+                GregorianYearFormat::Iso8601 => (true, 5),
+            };
+
+            if (sign_len == 1) != signed || digits != digits_expected {
+                return Err(ParseError::new(ParseErrorKind::InvalidYear, 0..year_len));
+            }
+        }
+
+        Self::from_year_and_rest(bytes, year_len, year)
+    }
+
+    /// Parses the `-MM-DD` after the year and checks the date.
+    fn from_year_and_rest(bytes: &[u8], year_len: usize, year: i32) -> Result<Self, ParseError> {
+        for (i, &b) in bytes.iter().enumerate().skip(year_len) {
+            let valid = if i == year_len || i == year_len + 3 {
+                b == b'-'
+            } else {
+                b.is_ascii_digit()
+            };
+
+            if !valid {
+                return Err(ParseError::invalid_character(bytes, i));
+            }
+        }
+
+        let number = |range: core::ops::Range<usize>| {
+            bytes[range]
+                .iter()
+                .fold(0, |acc, &b| acc * 10 + i32::from(b - b'0'))
+        };
+
+        let Ok(year) = i16::try_from(year) else {
+            return Err(ParseError::new(ParseErrorKind::OutOfRange, 0..bytes.len()));
+        };
+
+        let month = number(year_len + 1..year_len + 3);
+        let day = number(year_len + 4..year_len + 6);
+
+        let Some(month_len) = u8::try_from(month)
+            .ok()
+            .and_then(|m| Self::month_len(year, m))
+        else {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidMonth,
+                year_len + 1..year_len + 3,
+            ));
+        };
+
+        if day == 0 || day > i32::from(month_len) {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidDay,
+                year_len + 4..year_len + 6,
+            ));
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // checked above
+        Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+        }
+        .limit_sac13()
+        .ok_or_else(|| ParseError::new(ParseErrorKind::OutOfRange, 0..bytes.len()))
+    }
+
+    /// Julian Day Number of the date (const version of [`CalendarDate::as_julian()`]).
     #[allow(non_upper_case_globals)] // allowed to match nomenclature of E.G. Richards
     #[allow(clippy::let_and_return)]
     #[allow(non_snake_case)]
     #[allow(unused)]
-    fn as_julian(&self) -> i32 {
+    pub(crate) const fn julian_day_number(self) -> i32 {
         // Based on Edward Graham Richards Algorithm, Chapter 15
         // 15.11 Calendar Conversion Algorithms
         // https://aa.usno.navy.mil/downloads/c15_usb_online.pdf (page 617 ff)
@@ -162,9 +497,9 @@ impl CalendarDate for GregorianDate {
         const B: i32 = 274277;
         const C: i32 = -38;
 
-        let D = i32::from(self.day);
-        let M = i32::from(self.month);
-        let Y = i32::from(self.year);
+        let D = self.day as i32;
+        let M = self.month as i32;
+        let Y = self.year as i32;
 
         let h = M - m;
         let g = Y + y - (n - h).div_euclid(n);
@@ -177,11 +512,11 @@ impl CalendarDate for GregorianDate {
         J
     }
 
-    #[must_use]
+    /// Const version of [`CalendarDate::from_julian()`].
     #[allow(non_upper_case_globals)] // allowed to match nomenclature of E.G. Richards
     #[allow(non_snake_case)]
     #[allow(unused)]
-    fn from_julian(value: i32) -> Option<Self> {
+    pub(crate) const fn from_julian_day_number(value: i32) -> Option<Self> {
         // Based on Edward Graham Richards Algorithm, Chapter 15
         // 15.11 Calendar Conversion Algorithms
         // https://aa.usno.navy.mil/downloads/c15_usb_online.pdf (page 617 ff)
@@ -202,7 +537,7 @@ impl CalendarDate for GregorianDate {
         const B: i32 = 274277;
         const C: i32 = -38;
 
-        if !(JulianDay::MIN_INT..=JulianDay::MAX_INT).contains(&value) {
+        if value < JulianDay::MIN_INT || value > JulianDay::MAX_INT {
             return None;
         }
 
@@ -225,6 +560,173 @@ impl CalendarDate for GregorianDate {
         })
     }
 
+    const fn limit_sac13(self) -> Option<Self> {
+        if matches!(Self::const_cmp(self, Self::MIN), Ordering::Less)
+            || matches!(Self::const_cmp(self, Self::MAX), Ordering::Greater)
+        {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    const fn const_cmp(lhs: Self, rhs: Self) -> Ordering {
+        if lhs.year < rhs.year {
+            Ordering::Less
+        } else if lhs.year > rhs.year {
+            Ordering::Greater
+        } else if lhs.month < rhs.month {
+            Ordering::Less
+        } else if lhs.month > rhs.month {
+            Ordering::Greater
+        } else if lhs.day < rhs.day {
+            Ordering::Less
+        } else if lhs.day > rhs.day {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+/// The first and the last SAC13 date of a Gregorian month (`1` - `12`).
+///
+/// Months at the limits of the SAC13 range are cut to [`Date::MIN`](CalendarDate::MIN)
+/// and [`Date::MAX`](CalendarDate::MAX). Returns `None` if the month is invalid or
+/// completely outside the SAC13 range.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, gregorian_month_span};
+///
+/// // April 2024
+/// assert_eq!(
+///     gregorian_month_span(2024, 4),
+///     Some((date!(M024 - 01 - 13), date!(M024 - 02 - 14)))
+/// );
+/// assert_eq!(gregorian_month_span(2024, 13), None);
+/// ```
+#[must_use]
+pub const fn gregorian_month_span(year: i16, month: u8) -> Option<(Date, Date)> {
+    let last_day = ok!(GregorianDate::month_len(year, month));
+
+    let mut first = GregorianDate {
+        year,
+        month,
+        day: 1,
+    };
+    let mut last = GregorianDate {
+        year,
+        month,
+        day: last_day,
+    };
+
+    if GregorianDate::const_cmp(first, GregorianDate::MIN).is_lt() {
+        first = GregorianDate::MIN;
+    }
+
+    if GregorianDate::const_cmp(last, GregorianDate::MAX).is_gt() {
+        last = GregorianDate::MAX;
+    }
+
+    if GregorianDate::const_cmp(first, last).is_gt() {
+        return None;
+    }
+
+    let (Some(first), Some(last)) = (
+        Date::from_gregorian_ymd(first.year, first.month, first.day),
+        Date::from_gregorian_ymd(last.year, last.month, last.day),
+    ) else {
+        unreachable!()
+    };
+
+    Some((first, last))
+}
+
+/// The first and the last Gregorian date of a SAC13 month.
+///
+/// The inverse of [`gregorian_month_span()`].
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Month, date_greg, sac13_month_span_in_gregorian, year};
+///
+/// assert_eq!(
+///     sac13_month_span_in_gregorian(year!(M024), Month::April),
+///     (date_greg!(2024 - 04 - 17), date_greg!(2024 - 05 - 14))
+/// );
+/// ```
+#[must_use]
+pub const fn sac13_month_span_in_gregorian(
+    year: Year,
+    month: Month,
+) -> (GregorianDate, GregorianDate) {
+    let month = YearMonth::new(year, month);
+
+    (
+        GregorianDate::from_sac13(month.first_day()),
+        GregorianDate::from_sac13(month.last_day()),
+    )
+}
+
+/// How [`GregorianDate::display_with()`] writes the year.
+///
+/// The SAC13 range covers Gregorian years from -10000 to 16000, so years with more than
+/// four digits are common at the edges and need a sign to be unambiguous for ISO 8601 parsers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GregorianYearFormat {
+    /// The plain number, with `-` for negative years (`16000-03-17`, `999-01-01`, `-500-01-01`).
+    /// This is what [`Display`] writes.
+    #[default]
+    Minimal,
+
+    /// At least four digits, with `+` for years above 9999 and `-` for negative years
+    /// (`+16000-03-17`, `0999-01-01`, `-0500-01-01`). This is what `{:#}` writes.
+    Padded,
+
+    /// The ISO 8601 expanded representation of [`to_iso8601()`](GregorianDate::to_iso8601()):
+    /// four digits for years 0 to 9999, otherwise a sign and five digits
+    /// (`+16000-03-17`, `0999-01-01`, `-00500-01-01`).
+    Iso8601,
+}
+
+impl Display for GregorianDate {
+    /// Displays the date as `YYYY-MM-DD` with the [`Minimal`](GregorianYearFormat::Minimal)
+    /// year or, with the alternate flag (`{:#}`), the [`Padded`](GregorianYearFormat::Padded) one.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let format = if f.alternate() {
+            GregorianYearFormat::Padded
+        } else {
+            GregorianYearFormat::Minimal
+        };
+
+        write!(f, "{}", self.display_with(format))
+    }
+}
+
+impl FromStr for GregorianDate {
+    type Err = ParseError;
+
+    /// Parses the output of [`GregorianDate::display_with()`] in any [`GregorianYearFormat`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_any_year(s, None)
+    }
+}
+
+impl CalendarDate for GregorianDate {
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+
+    fn as_julian(&self) -> i32 {
+        self.julian_day_number()
+    }
+
+    fn from_julian(value: i32) -> Option<Self> {
+        Self::from_julian_day_number(value)
+    }
+
     fn tomorrow(mut self) -> Option<Self> {
         // Note: the implementation should be simple,
         // and almost trivial to show its correctness,
@@ -232,14 +734,14 @@ impl CalendarDate for GregorianDate {
 
         if self.day < 28 {
             self.day += 1;
-            return Some(self);
+            return self.limit_sac13();
         }
 
         let days = ok!(Self::month_len(self.year, self.month));
 
         if self.day < days {
             self.day += 1;
-            return Some(self);
+            return self.limit_sac13();
         }
 
         self.day = 1;
@@ -259,7 +761,7 @@ impl CalendarDate for GregorianDate {
 
         if self.day > 1 {
             self.day -= 1;
-            return Some(self);
+            return self.limit_sac13();
         }
 
         self.month = if self.month == 1 { 12 } else { self.month - 1 };
@@ -274,10 +776,75 @@ impl CalendarDate for GregorianDate {
     }
 }
 
+impl Datelike for GregorianDate {
+    fn year_value(&self) -> i32 {
+        self.year.into()
+    }
+
+    fn month_ordinal(&self) -> u8 {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn ordinal(&self) -> u16 {
+        const DAYS_BEFORE_MONTH: [u16; 12] =
+            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+        let mut ordinal = DAYS_BEFORE_MONTH[(self.month - 1) as usize] + u16::from(self.day);
+
+        if self.month > 2 && Self::is_leap_year(self.year) {
+            ordinal += 1;
+        }
+
+        ordinal
+    }
+
+    fn weekday_ordinal(&self) -> u8 {
+        self.convert::<JulianDay>().weekday() as u8 + 1
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::{gregorian_month_span, sac13_month_span_in_gregorian};
+    use crate::ParseErrorKind;
     use crate::prelude::*;
 
+    #[test]
+    fn month_spans_round_trip() {
+        let gregorian_month = |date: Date| date.convert::<GregorianDate>().month();
+
+        for year in [-10_000, -1, 0, 2024, 2100, 16_000] {
+            for month in 1..=12 {
+                let Some((first, last)) = gregorian_month_span(year, month) else {
+                    assert!(matches!((year, month), (-10_000, 1 | 2) | (16_000, 4..)));
+                    continue;
+                };
+
+                assert_eq!(gregorian_month(first), month);
+                assert_eq!(gregorian_month(last), month);
+                assert!(
+                    first
+                        .yesterday()
+                        .is_none_or(|d| gregorian_month(d) != month)
+                );
+                assert!(last.tomorrow().is_none_or(|d| gregorian_month(d) != month));
+            }
+        }
+
+        for date in [Date::MIN, date!(M021 - 06 - 29), Date::MAX] {
+            let (first, last) = sac13_month_span_in_gregorian(date.year(), date.month());
+            let days = Date::month_len(date.year(), date.month());
+
+            assert_eq!(first.convert::<Date>().month(), date.month());
+            assert_eq!(last.convert::<Date>().month(), date.month());
+            assert_eq!(last.as_julian() - first.as_julian() + 1, i32::from(days));
+        }
+    }
+
     #[test]
     fn gregorian_julian_day_number_conversion_works_with_samples() {
         macro_rules! same {
@@ -296,4 +863,118 @@ mod tests {
         same!(2000 - 03 - 20, 2451624);
         same!(1600 - 02 - 29, 2305507);
     }
+
+    #[test]
+    fn datelike_ordinal_and_weekday() {
+        assert_eq!(date_greg!(2024 - 01 - 01).ordinal(), 1);
+        assert_eq!(date_greg!(2024 - 03 - 01).ordinal(), 61);
+        assert_eq!(date_greg!(2023 - 03 - 01).ordinal(), 60);
+        assert_eq!(date_greg!(2024 - 12 - 31).ordinal(), 366);
+
+        // 2024-12-05 was a Thursday
+        assert_eq!(date_greg!(2024 - 12 - 05).weekday_ordinal(), 4);
+    }
+
+    #[test]
+    fn iso8601_expanded_years() {
+        for (input, kind, span) in [
+            ("2020-02-30", ParseErrorKind::InvalidDay, 8..10),
+            ("2020-13-01", ParseErrorKind::InvalidMonth, 5..7),
+            ("-0500-01-01", ParseErrorKind::InvalidLength, 0..11),
+            ("+09999-01-01", ParseErrorKind::InvalidYear, 0..6),
+            ("-00000-01-01", ParseErrorKind::InvalidYear, 0..6),
+            ("+16000-03-18", ParseErrorKind::OutOfRange, 0..12),
+            ("+99999-01-01", ParseErrorKind::OutOfRange, 0..12),
+            ("2020/01-01", ParseErrorKind::InvalidCharacter, 4..5),
+            ("+1-000-01-01", ParseErrorKind::InvalidCharacter, 2..3),
+        ] {
+            let error = GregorianDate::from_iso8601(input).unwrap_err();
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+
+        for date in [
+            GregorianDate::MIN,
+            GregorianDate::MAX,
+            GregorianDate::from_ymd(0, 1, 1).unwrap(),
+            GregorianDate::from_ymd(-1, 12, 31).unwrap(),
+            date_greg!(9999 - 12 - 31),
+        ] {
+            let iso = format!("{}", date.to_iso8601());
+            assert_eq!(GregorianDate::from_iso8601(&iso), Ok(date), "{iso}");
+        }
+
+        assert_eq!(
+            format!(
+                "{}",
+                GregorianDate::from_ymd(-1, 12, 31).unwrap().to_iso8601()
+            ),
+            "-00001-12-31"
+        );
+    }
+
+    #[test]
+    fn year_formats_round_trip() {
+        use super::GregorianYearFormat::{Iso8601, Minimal, Padded};
+
+        let early = GregorianDate::from_ymd(999, 1, 1).unwrap();
+        let negative = GregorianDate::from_ymd(-500, 1, 1).unwrap();
+
+        for (date, minimal, padded, iso) in [
+            (
+                GregorianDate::MAX,
+                "16000-03-17",
+                "+16000-03-17",
+                "+16000-03-17",
+            ),
+            (early, "999-01-01", "0999-01-01", "0999-01-01"),
+            (negative, "-500-01-01", "-0500-01-01", "-00500-01-01"),
+            (
+                date_greg!(2024 - 05 - 21),
+                "2024-05-21",
+                "2024-05-21",
+                "2024-05-21",
+            ),
+        ] {
+            for (format, text) in [(Minimal, minimal), (Padded, padded), (Iso8601, iso)] {
+                assert_eq!(format!("{}", date.display_with(format)), text);
+                assert_eq!(GregorianDate::parse_with(text, format), Ok(date), "{text}");
+                assert_eq!(text.parse(), Ok(date), "{text}");
+            }
+
+            assert_eq!(format!("{date}"), minimal);
+            assert_eq!(format!("{date:#}"), padded);
+        }
+
+        for (input, format) in [
+            ("0999-01-01", Minimal),
+            ("999-01-01", Padded),
+            ("16000-03-17", Padded),
+            ("+2024-05-21", Padded),
+            ("-0500-01-01", Iso8601),
+            ("-0-01-01", Minimal),
+            ("+16000-03-17", Minimal),
+        ] {
+            let error = GregorianDate::parse_with(input, format).unwrap_err();
+            assert_eq!(error.kind(), ParseErrorKind::InvalidYear, "{input}");
+        }
+
+        for (input, kind) in [
+            ("-0-01-01", ParseErrorKind::InvalidYear),
+            ("123456-01-01", ParseErrorKind::InvalidYear),
+            ("--01-01", ParseErrorKind::InvalidCharacter),
+            ("-01-01", ParseErrorKind::InvalidLength),
+            ("2024-05-2", ParseErrorKind::InvalidLength),
+            ("+16000-03-18", ParseErrorKind::OutOfRange),
+        ] {
+            let error = input.parse::<GregorianDate>().unwrap_err();
+            assert_eq!(error.kind(), kind, "{input}");
+        }
+    }
+
+    #[test]
+    fn tomorrow_and_yesterday_respect_the_range() {
+        // Both limits are in the middle of a month.
+        assert_eq!(GregorianDate::MAX.tomorrow(), None);
+        assert_eq!(GregorianDate::MIN.yesterday(), None);
+    }
 }
@@ -1,8 +1,20 @@
 pub mod raw_date;
 
-use core::fmt::Display;
+use core::{cmp::Ordering, fmt::Display};
 
-use crate::{month::Month, scalars::Year, traits::CalendarDate};
+#[cfg(feature = "names")]
+use crate::HumanizedRelative;
+use crate::{
+    CalendarDuration, DateBuilder, DayOfMonth, Granularity, GregorianDate, LeapPolicy, SyncDays,
+    UtcOffset, Week,
+    month::Month,
+    parse::{
+        CALENDAR_ANNOTATION, ParseError, ParseErrorKind, check_layout, strip_calendar_annotation,
+    },
+    scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay, Year},
+    traits::{CalendarDate, Datelike},
+    weekday::Weekday,
+};
 use raw_date::{YearOrdinal, date_to_yo, yo_to_date};
 
 /// SAC13 date.
@@ -26,6 +38,21 @@ pub struct Date {
 }
 
 impl Date {
+    /// Description of the canonical string format, see [`to_canonical_bytes()`](Self::to_canonical_bytes()).
+    ///
+    /// `L` is the millennium letter (`A` - `Z`), followed by the three remaining year digits,
+    /// the two digit month (`01` - `13`) and the two digit day (`01` - `29`).
+    pub const CANONICAL_FORMAT: &'static str = "Lyyy-mm-dd";
+
+    /// First byte of the keys of [`to_sortable_key()`](Self::to_sortable_key()).
+    pub const SORTABLE_KEY_VERSION: u8 = b'1';
+
+    /// The first day of the SAC13 calendar (A000-01-01), same as [`Date::MIN`](CalendarDate::MIN).
+    pub const SAC13_EPOCH: Self = date!(A000 - 01 - 01);
+
+    /// The day of the Unix epoch (1970-01-01 in the Gregorian calendar), the [Unix Day](UnixDay) zero.
+    pub const UNIX_EPOCH_DATE: Self = date!(L969 - 11 - 07);
+
     /// SAC13 date from given year, month, day.
     ///
     /// Returns `None` for invalid dates.
@@ -49,62 +76,1262 @@ impl Date {
         }
     }
 
-    /// Like [from_ymd()](Self::from_ymd()) but from integers instead of typed [`Year`] and [`Month`].
+    /// Like [`from_ymd()`](Self::from_ymd()), but with a typed [day](DayOfMonth),
+    /// so all components have distinct types and can't be swapped by accident.
+    ///
+    /// Returns `None` for day 29 in months without a synchronization day.
+    #[must_use]
+    pub const fn from_ymd_typed(year: Year, month: Month, day: DayOfMonth) -> Option<Self> {
+        Self::from_ymd(year, month, day.value())
+    }
+
+    /// Same as [`from_ymd_typed()`](Self::from_ymd_typed()), but with the arguments
+    /// in day-month-year order, e.g. to match the order of user input.
+    ///
+    /// Because every argument has its own type, mixing up the order doesn't compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, DayOfMonth, Month, date, year};
+    ///
+    /// let day = DayOfMonth::new(16).unwrap();
+    ///
+    /// assert_eq!(Date::from_dmy(day, Month::May, year!(M020)), Some(date!(M020 - 03 - 16)));
+    /// assert_eq!(Date::from_mdy(Month::May, day, year!(M020)), Some(date!(M020 - 03 - 16)));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use sac13::{Date, DayOfMonth, Month, year};
+    ///
+    /// let day = DayOfMonth::new(16).unwrap();
+    /// let date = Date::from_dmy(Month::May, day, year!(M020));
+    /// ```
+    #[must_use]
+    pub const fn from_dmy(day: DayOfMonth, month: Month, year: Year) -> Option<Self> {
+        Self::from_ymd_typed(year, month, day)
+    }
+
+    /// Same as [`from_ymd_typed()`](Self::from_ymd_typed()), but with the arguments
+    /// in month-day-year order. See [`from_dmy()`](Self::from_dmy()).
+    #[must_use]
+    pub const fn from_mdy(month: Month, day: DayOfMonth, year: Year) -> Option<Self> {
+        Self::from_ymd_typed(year, month, day)
+    }
+
+    /// Builder for a date from partially specified, unvalidated components,
+    /// which reports exactly which constraint failed.
+    ///
+    /// See [`DateBuilder`] for details.
+    pub const fn builder() -> DateBuilder {
+        DateBuilder::new()
+    }
+
+    /// Like [from_ymd()](Self::from_ymd()) but from integers instead of typed [`Year`] and [`Month`].
+    ///
+    /// Returns [`None`] for invalid dates.
+    ///
+    /// Swapping the month and the day might silently produce another valid date. Prefer the
+    /// typed constructors (like [`from_ymd_typed()`](Self::from_ymd_typed())) or the
+    /// [builder](Self::builder()) if the components come from different sources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date,date};
+    ///
+    /// assert_eq!(Date::from_ymd_untyped(12020,02,16), Some(date!(M020 - 02 - 16)));
+    /// assert_eq!(Date::from_ymd_untyped(12020,02,29), None); // April 29th does not exist in SAC13
+    /// ```
+    #[must_use]
+    pub const fn from_ymd_untyped(year: u16, month: u8, day: u8) -> Option<Self> {
+        let y = match Year::new(year) {
+            None => return None,
+            Some(y) => y,
+        };
+
+        let m = match Month::new(month) {
+            None => return None,
+            Some(m) => m,
+        };
+
+        Self::from_ymd(y, m, day)
+    }
+
+    /// Creates the SAC13 date of the given Gregorian Calendar date.
+    ///
+    /// Returns `None` if the Gregorian date is invalid or outside the SAC13 range,
+    /// see [`GregorianDate::from_ymd()`]. Same as `GregorianDate::from_ymd(..).map(|d| d.convert())`,
+    /// but usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// assert_eq!(Date::from_gregorian_ymd(2020, 3, 21), Some(date!(M020 - 01 - 02)));
+    /// assert_eq!(Date::from_gregorian_ymd(2021, 2, 29), None);
+    /// ```
+    #[must_use]
+    pub const fn from_gregorian_ymd(year: i16, month: u8, day: u8) -> Option<Self> {
+        let gregorian = ok!(GregorianDate::from_ymd(year, month, day));
+
+        // all Gregorian dates are within the SAC13 range
+        let Some(day) = JulianDay::from_julian_day_number(gregorian.julian_day_number()) else {
+            unreachable!()
+        };
+
+        Some(day.to_date())
+    }
+
+    /// Like [`from_ymd_untyped()`](Self::from_ymd_untyped()) but clamps invalid components
+    /// instead of returning `None`.
+    ///
+    /// The year is clamped to the SAC13 range (0 - 25'999), the month to 1 - 13
+    /// and the day to the length of the month. Useful for "best effort" imports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// assert_eq!(Date::from_ymd_clamped(12022, 6, 29), date!(M022 - 06 - 28));
+    /// assert_eq!(Date::from_ymd_clamped(12022, 0, 0), date!(M022 - 01 - 01));
+    /// assert_eq!(Date::from_ymd_clamped(30000, 14, 31), date!(Z999 - 13 - 29));
+    /// ```
+    #[must_use]
+    pub const fn from_ymd_clamped(year: u16, month: u8, day: u8) -> Self {
+        let year = match Year::new(year) {
+            Some(y) => y,
+            None => Year::MAX,
+        };
+
+        let month = match Month::new(month) {
+            Some(m) => m,
+            None if month == 0 => Month::March,
+            None => Month::Addenduary,
+        };
+
+        Self::with_clamped_day(year, month, if day == 0 { 1 } else { day })
+    }
+
+    /// Restricts the date to the given interval.
+    ///
+    /// Same as [`Ord::clamp()`], but usable in const contexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// let min = date!(M020 - 01 - 01);
+    /// let max = date!(M020 - 13 - 29);
+    ///
+    /// assert_eq!(date!(M019 - 05 - 05).clamp(min, max), min);
+    /// assert_eq!(date!(M020 - 05 - 05).clamp(min, max), date!(M020 - 05 - 05));
+    /// ```
+    #[must_use]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            !matches!(Self::const_cmp(min, max), Ordering::Greater),
+            "min must not be greater than max"
+        );
+
+        if matches!(Self::const_cmp(self, min), Ordering::Less) {
+            min
+        } else if matches!(Self::const_cmp(self, max), Ordering::Greater) {
+            max
+        } else {
+            self
+        }
+    }
+
+    const fn const_cmp(lhs: Self, rhs: Self) -> Ordering {
+        const fn key(date: Date) -> u32 {
+            (date.year.value() as u32) << 16 | (date.month.ord() as u32) << 8 | date.day as u32
+        }
+
+        let (lhs, rhs) = (key(lhs), key(rhs));
+
+        if lhs < rhs {
+            Ordering::Less
+        } else if lhs > rhs {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// The date in the canonical format (e.g. `M020-05-21`) as ASCII bytes.
+    ///
+    /// # Format stability
+    ///
+    /// Unlike [`Display`] and [`parse_date_str()`](crate::parse_date_str()), which might become
+    /// more flexible over time, the canonical format is guaranteed to never change across versions.
+    /// It's always exactly 10 ASCII bytes ([`CANONICAL_FORMAT`](Self::CANONICAL_FORMAT)),
+    /// so it's safe to use for persistent storage, cache keys or hashing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(&date!(M020 - 05 - 21).to_canonical_bytes(), b"M020-05-21");
+    /// ```
+    #[must_use]
+    pub const fn to_canonical_bytes(&self) -> [u8; 10] {
+        const fn digit(value: u16) -> u8 {
+            b'0' + (value % 10) as u8
+        }
+
+        let [y0, y1, y2, y3] = self.year.to_ascii();
+        let m = self.month.ord() as u16;
+        let d = self.day as u16;
+
+        [
+            y0,
+            y1,
+            y2,
+            y3,
+            b'-',
+            digit(m / 10),
+            digit(m),
+            b'-',
+            digit(d / 10),
+            digit(d),
+        ]
+    }
+
+    /// The date in the canonical format (e.g. `M020-05-21`).
+    ///
+    /// See [`to_canonical_bytes()`](Self::to_canonical_bytes()) for the stability guarantee.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_canonical_string(&self) -> alloc::string::String {
+        let bytes = self.to_canonical_bytes();
+
+        // the canonical format is always ASCII
+        bytes.iter().map(|&b| b as char).collect()
+    }
+
+    /// A key for key-value stores whose byte order is the chronological order of the dates.
+    ///
+    /// The key is the [`SORTABLE_KEY_VERSION`](Self::SORTABLE_KEY_VERSION) byte followed by
+    /// the [canonical bytes](Self::to_canonical_bytes()), e.g. `1M020-05-21`. Millennium
+    /// letters and zero-padded numbers sort lexicographically like the dates they encode.
+    /// Should the key format ever change, the new keys get a different first byte, so both
+    /// can coexist in a store.
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// let a = date!(L999 - 13 - 29).to_sortable_key();
+    /// let b = date!(M000 - 01 - 01).to_sortable_key();
+    ///
+    /// assert_eq!(&b, b"1M000-01-01");
+    /// assert!(a < b);
+    /// assert_eq!(Date::from_sortable_key(&b), Some(date!(M000 - 01 - 01)));
+    /// ```
+    #[must_use]
+    pub const fn to_sortable_key(&self) -> [u8; 11] {
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9] = self.to_canonical_bytes();
+        [
+            Self::SORTABLE_KEY_VERSION,
+            c0,
+            c1,
+            c2,
+            c3,
+            c4,
+            c5,
+            c6,
+            c7,
+            c8,
+            c9,
+        ]
+    }
+
+    /// Parses a key created by [`to_sortable_key()`](Self::to_sortable_key()).
+    ///
+    /// Returns `None` for anything else, including keys of a different version.
+    #[must_use]
+    pub const fn from_sortable_key(key: &[u8]) -> Option<Self> {
+        match key {
+            [Self::SORTABLE_KEY_VERSION, canonical @ ..] => Self::from_canonical_bytes(canonical),
+            _ => None,
+        }
+    }
+
+    /// Parses a date in the canonical format (e.g. `M020-05-21`).
+    ///
+    /// This is a strict parser that only accepts exactly the output of
+    /// [`to_canonical_bytes()`](Self::to_canonical_bytes()). Returns `None` for anything else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// assert_eq!(Date::from_canonical_str("M020-05-21"), Some(date!(M020 - 05 - 21)));
+    /// assert_eq!(Date::from_canonical_str("M020-5-21"), None);
+    /// assert_eq!(Date::from_canonical_str("21.05.M020"), None);
+    /// ```
+    #[must_use]
+    pub const fn from_canonical_str(input: &str) -> Option<Self> {
+        Self::from_canonical_bytes(input.as_bytes())
+    }
+
+    /// Like [`from_canonical_str()`](Self::from_canonical_str()), but for ASCII bytes
+    /// (e.g. records stored in flash).
+    ///
+    /// Both are `const fn`, so tables of dates can be parsed at compile time,
+    /// see [`parse_table!`](crate::parse_table!).
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// const DATE: Option<Date> = Date::from_canonical_bytes(b"M020-05-21");
+    /// assert_eq!(DATE, Some(date!(M020 - 05 - 21)));
+    /// ```
+    #[must_use]
+    pub const fn from_canonical_bytes(input: &[u8]) -> Option<Self> {
+        const fn two_digits(d0: u8, d1: u8) -> Option<u8> {
+            if d0.is_ascii_digit() && d1.is_ascii_digit() {
+                Some((d0 - b'0') * 10 + (d1 - b'0'))
+            } else {
+                None
+            }
+        }
+
+        if input.len() != 10 || input[4] != b'-' || input[7] != b'-' {
+            return None;
+        }
+
+        let year = ok!(Year::parse_year_digits(
+            input[0], input[1], input[2], input[3]
+        ));
+        let month = ok!(Month::new(ok!(two_digits(input[5], input[6]))));
+
+        Self::from_ymd(year, month, ok!(two_digits(input[8], input[9])))
+    }
+
+    /// Parses a date in the [`Display`] format (e.g. `M020-05-21`).
+    ///
+    /// This is the strict counterpart of [`Display`]: every displayed date parses back
+    /// to the same date, and nothing else is accepted. It's also used by [`FromStr`](core::str::FromStr)
+    /// and (with the `serde` feature) for deserialization. Use [`parse_date_str()`](crate::parse_date_str())
+    /// for user input in other formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] that points to the first offending part of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, ParseErrorKind, date};
+    ///
+    /// let date = date!(M020 - 05 - 21);
+    ///
+    /// assert_eq!(Date::parse_display(&format!("{date}")), Ok(date));
+    /// assert_eq!("M020-05-21".parse(), Ok(date));
+    ///
+    /// let error = Date::parse_display("M020-5-21").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidLength);
+    /// ```
+    pub fn parse_display(input: &str) -> Result<Self, ParseError> {
+        // `Display` writes the canonical format
+        Self::from_canonical_str(input).ok_or_else(|| Self::canonical_error(input.as_bytes()))
+    }
+
+    /// Displays the date as a calendar-annotated Gregorian date (e.g. `2020-03-21[u-ca=sac13]`).
+    ///
+    /// This is the Internet Extended Date/Time Format (IXDTF, RFC 9557): systems that only
+    /// transport ISO 8601 dates can store it as is, and [`from_ixdtf()`](Self::from_ixdtf())
+    /// restores the SAC13 date without any loss. The Gregorian part uses
+    /// [`GregorianDate::to_iso8601()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// let date = date!(M020 - 01 - 02);
+    /// let text = date.to_ixdtf().to_string();
+    ///
+    /// assert_eq!(text, "2020-03-21[u-ca=sac13]");
+    /// assert_eq!(Date::from_ixdtf(&text), Ok(date));
+    /// ```
+    #[must_use]
+    pub fn to_ixdtf(&self) -> impl Display + use<> {
+        struct Ixdtf(GregorianDate);
+
+        impl Display for Ixdtf {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}{CALENDAR_ANNOTATION}", self.0.to_iso8601())
+            }
+        }
+
+        Ixdtf(self.convert())
+    }
+
+    /// Parses a calendar-annotated Gregorian date (e.g. `2020-03-21[u-ca=sac13]`).
+    ///
+    /// The date must be in the format of [`GregorianDate::from_iso8601()`] and the annotations must
+    /// contain `u-ca=sac13`. Other elective annotations (and a time zone) are ignored,
+    /// other critical annotations (like `[!u-ca=gregory]`) are rejected, see
+    /// [`to_ixdtf()`](Self::to_ixdtf()).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] that points to the first offending part of the input.
+    /// The kind is [`ParseErrorKind::InvalidAnnotation`] if the calendar annotation is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, ParseErrorKind, date};
+    ///
+    /// assert_eq!(Date::from_ixdtf("2020-03-21[!u-ca=sac13]"), Ok(date!(M020 - 01 - 02)));
+    /// assert_eq!(Date::from_ixdtf("2020-03-21[Europe/Vienna][u-ca=sac13]"), Ok(date!(M020 - 01 - 02)));
+    ///
+    /// let error = Date::from_ixdtf("2020-03-21").unwrap_err();
+    /// assert_eq!(error.kind(), ParseErrorKind::InvalidAnnotation);
+    /// ```
+    pub fn from_ixdtf(input: &str) -> Result<Self, ParseError> {
+        let date = strip_calendar_annotation(input)?;
+        Ok(GregorianDate::from_iso8601(date)?.convert())
+    }
+
+    /// Displays the corresponding Gregorian date, like [`GregorianDate`]'s `Display` implementation.
+    ///
+    /// The conversion happens when the date is formatted (and not at all if a log
+    /// statement is filtered out).
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 02).display_as_gregorian().to_string(), "2020-03-21");
+    /// ```
+    #[must_use]
+    pub const fn display_as_gregorian(&self) -> impl Display + use<> {
+        struct AsGregorian(Date);
+
+        impl Display for AsGregorian {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.0.convert::<GregorianDate>())
+            }
+        }
+
+        AsGregorian(*self)
+    }
+
+    /// Displays the date followed by the corresponding Gregorian date in parentheses.
+    ///
+    /// Like [`display_as_gregorian()`](Self::display_as_gregorian()), the conversion happens
+    /// when the date is formatted. Use [`Dual`](crate::Dual) to convert once and keep
+    /// both dates.
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 02).display_dual().to_string(), "M020-01-02 (2020-03-21)");
+    /// ```
+    #[must_use]
+    pub const fn display_dual(&self) -> impl Display + use<> {
+        struct LazyDual(Date);
+
+        impl Display for LazyDual {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                crate::Dual::from_sac13(self.0).fmt(f)
+            }
+        }
+
+        LazyDual(*self)
+    }
+
+    /// The reason why `input` isn't a date in the canonical format.
+    fn canonical_error(input: &[u8]) -> ParseError {
+        if let Err(error) = check_layout(input, Self::CANONICAL_FORMAT.as_bytes()) {
+            return error;
+        }
+
+        let month = (input[5] - b'0') * 10 + (input[6] - b'0');
+
+        if Month::new(month).is_none() {
+            ParseError::new(ParseErrorKind::InvalidMonth, 5..7)
+        } else {
+            ParseError::new(ParseErrorKind::InvalidDay, 8..10)
+        }
+    }
+
+    /// Year component of the date.
+    #[must_use]
+    pub const fn year(&self) -> Year {
+        self.year
+    }
+
+    /// Month component of the date.
+    #[must_use]
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Day component of the date.
+    #[must_use]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Day component of the date as a [`DayOfMonth`].
+    #[must_use]
+    pub const fn day_of_month(&self) -> DayOfMonth {
+        let Some(day) = DayOfMonth::new(self.day) else {
+            unreachable!()
+        };
+
+        day
+    }
+
+    /// SAC13 Weekday ordinal.
+    ///
+    /// Returns which day of the week it is. Typically 1-7, 8 on synchronization days.
+    /// Note that 1-7 are NOT synonymous with Monday - Sunday. SAC13 doesn't have weekdays
+    /// in the typical sense.
+    #[must_use]
+    pub const fn weekday_ordinal(&self) -> u8 {
+        match self.day {
+            29 => 8,
+            x => (x - 1) % 7 + 1,
+        }
+    }
+
+    /// Gregorian weekday (Monday - Sunday) of the date.
+    ///
+    /// Unrelated to the SAC13 [`weekday_ordinal()`](Self::weekday_ordinal()), but useful
+    /// to relate a date to the seven-day week that is in use alongside the Gregorian calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Weekday, date};
+    ///
+    /// // 2000-03-20 (Gregorian)
+    /// assert_eq!(date!(M000 - 03 - 01).weekday(), Weekday::Monday);
+    /// ```
+    #[must_use]
+    pub const fn weekday(&self) -> Weekday {
+        self.to_julian_day().weekday()
+    }
+
+    /// The next date (after this one) that falls on the given Gregorian weekday.
+    ///
+    /// With [`SyncDays::Skip`] synchronization days are passed over, so the result is
+    /// a week later if the next occurrence is a synchronization day.
+    ///
+    /// Returns `None` if there is no such date within the SAC13 range.
+    ///
+    /// ```
+    /// use sac13::{SyncDays, Weekday, date};
+    ///
+    /// let monday = date!(M000 - 03 - 01);
+    ///
+    /// assert_eq!(
+    ///     monday.next_gregorian_weekday(Weekday::Monday, SyncDays::Include),
+    ///     Some(date!(M000 - 03 - 08))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn next_gregorian_weekday(self, weekday: Weekday, sync_days: SyncDays) -> Option<Self> {
+        self.find(true, |date| {
+            date.weekday() == weekday && date.matches_sync_days(sync_days)
+        })
+    }
+
+    /// The previous date (before this one) that falls on the given Gregorian weekday.
+    ///
+    /// See [`next_gregorian_weekday()`](Self::next_gregorian_weekday()).
+    #[must_use]
+    pub fn previous_gregorian_weekday(self, weekday: Weekday, sync_days: SyncDays) -> Option<Self> {
+        self.find(false, |date| {
+            date.weekday() == weekday && date.matches_sync_days(sync_days)
+        })
+    }
+
+    /// The next date (after this one) with the given SAC13 [weekday ordinal](Self::weekday_ordinal()).
+    ///
+    /// The ordinal 8 finds the next synchronization day, unless they are skipped.
+    /// For the ordinals 1 - 7 `sync_days` makes no difference.
+    ///
+    /// Returns `None` if the ordinal isn't between 1 and 8, for 8 with [`SyncDays::Skip`],
+    /// or if there is no such date within the SAC13 range.
+    ///
+    /// ```
+    /// use sac13::{SyncDays, date};
+    ///
+    /// let date = date!(M020 - 05 - 21);
+    ///
+    /// assert_eq!(date.next_weekday_ordinal(1, SyncDays::Skip), Some(date!(M020 - 05 - 22)));
+    /// assert_eq!(date.next_weekday_ordinal(7, SyncDays::Skip), Some(date!(M020 - 05 - 28)));
+    /// assert_eq!(date.next_weekday_ordinal(8, SyncDays::Include), Some(date!(M020 - 13 - 29)));
+    /// assert_eq!(date.next_weekday_ordinal(8, SyncDays::Skip), None);
+    /// ```
+    #[must_use]
+    pub fn next_weekday_ordinal(self, ordinal: u8, sync_days: SyncDays) -> Option<Self> {
+        if !Self::is_weekday_ordinal_searchable(ordinal, sync_days) {
+            return None;
+        }
+
+        self.find(true, |date| date.weekday_ordinal() == ordinal)
+    }
+
+    /// The previous date (before this one) with the given SAC13 [weekday ordinal](Self::weekday_ordinal()).
+    ///
+    /// See [`next_weekday_ordinal()`](Self::next_weekday_ordinal()).
+    #[must_use]
+    pub fn previous_weekday_ordinal(self, ordinal: u8, sync_days: SyncDays) -> Option<Self> {
+        if !Self::is_weekday_ordinal_searchable(ordinal, sync_days) {
+            return None;
+        }
+
+        self.find(false, |date| date.weekday_ordinal() == ordinal)
+    }
+
+    const fn is_weekday_ordinal_searchable(ordinal: u8, sync_days: SyncDays) -> bool {
+        match ordinal {
+            1..=7 => true,
+            8 => matches!(sync_days, SyncDays::Include),
+            _ => false,
+        }
+    }
+
+    const fn matches_sync_days(self, sync_days: SyncDays) -> bool {
+        self.day != 29 || matches!(sync_days, SyncDays::Include)
+    }
+
+    /// The first date after (or before) this one that matches.
+    ///
+    /// Steps day by day, which is fine for the short distances of weekday searches
+    /// (at most a year for the ordinal 8).
+    fn find(self, forward: bool, matches: impl Fn(&Self) -> bool) -> Option<Self> {
+        let mut date = self;
+
+        loop {
+            date = if forward {
+                date.tomorrow()
+            } else {
+                date.yesterday()
+            }?;
+
+            if matches(&date) {
+                return Some(date);
+            }
+        }
+    }
+
+    /// Day of the year, starting with 1.
+    ///
+    /// Ranges from 1 to 365 (366 on [leap years](Year::is_leap)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 01).day_of_year(), 1);
+    /// assert_eq!(date!(M020 - 08 - 04).day_of_year(), 200);
+    /// ```
+    #[must_use]
+    pub const fn day_of_year(&self) -> u16 {
+        date_to_yo(*self).day() + 1
+    }
+
+    /// SAC13 Day Number of the date (days since A000-01-01).
+    ///
+    /// Same as `convert()`, but usable in const contexts. [`Sac13Day::to_date()`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{date, day_counts::Sac13Day};
+    ///
+    /// const DAY: Sac13Day = date!(A000 - 01 - 02).to_sac13_day();
+    ///
+    /// assert_eq!(DAY.value(), 1);
+    /// assert_eq!(DAY.to_date(), date!(A000 - 01 - 02));
+    /// ```
+    #[must_use]
+    pub const fn to_sac13_day(self) -> Sac13Day {
+        match Sac13Day::from_julian_day_number(self.julian_day_number()) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// SAC13 Year Cycle Epoch Day Number of the date.
+    ///
+    /// Same as `convert()`, but usable in const contexts. [`CycleEpochDay::to_date()`] is the inverse.
+    #[must_use]
+    pub const fn to_cycle_epoch_day(self) -> CycleEpochDay {
+        date_to_yo(self).epoch_day()
+    }
+
+    /// Julian Day Number of the date.
+    ///
+    /// Same as `convert()`, but usable in const contexts. [`JulianDay::to_date()`] is the inverse.
+    #[must_use]
+    pub const fn to_julian_day(self) -> JulianDay {
+        match JulianDay::from_julian_day_number(self.julian_day_number()) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// Days since the Unix Epoch (1970-01-01 Gregorian).
+    ///
+    /// Same as `convert()`, but usable in const contexts. [`UnixDay::to_date()`] is the inverse.
+    #[must_use]
+    pub const fn to_unix_day(self) -> UnixDay {
+        match UnixDay::from_julian_day_number(self.julian_day_number()) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// Spreadsheet day count of the date, see [`ExcelDay`].
+    ///
+    /// Same as `convert()`, but usable in const contexts. [`ExcelDay::to_date()`] is the inverse.
+    #[must_use]
+    pub const fn to_excel_day(self) -> ExcelDay {
+        match ExcelDay::from_julian_day_number(self.julian_day_number()) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// The date of the Julian Day Number, clamped to [`Date::MIN`](CalendarDate::MIN)
+    /// and [`Date::MAX`](CalendarDate::MAX).
+    ///
+    /// Unlike [`from_julian()`](CalendarDate::from_julian()) it never fails, which is handy
+    /// in display-only contexts. Use [`JulianDay::range_check()`] to find out whether
+    /// the value was clamped.
+    ///
+    /// ```
+    /// use sac13::{CalendarDate, Date, date};
+    ///
+    /// assert_eq!(Date::from_julian_saturating(2_451_624), date!(M000 - 01 - 01));
+    /// assert_eq!(Date::from_julian_saturating(i32::MIN), Date::MIN);
+    /// assert_eq!(Date::from_julian_saturating(i32::MAX), Date::MAX);
+    /// ```
+    #[must_use]
+    pub const fn from_julian_saturating(value: i32) -> Self {
+        JulianDay::new_saturating(value).to_date()
+    }
+
+    /// The date of the [Unix Day](UnixDay), clamped to [`Date::MIN`](CalendarDate::MIN)
+    /// and [`Date::MAX`](CalendarDate::MAX). See [`from_julian_saturating()`](Self::from_julian_saturating()).
+    ///
+    /// ```
+    /// use sac13::{CalendarDate, Date};
+    ///
+    /// assert_eq!(Date::from_unix_day_saturating(0), Date::UNIX_EPOCH_DATE);
+    /// assert_eq!(Date::from_unix_day_saturating(i32::MAX), Date::MAX);
+    /// ```
+    #[must_use]
+    pub const fn from_unix_day_saturating(value: i32) -> Self {
+        UnixDay::new_saturating(value).to_date()
+    }
+
+    const fn julian_day_number(self) -> i32 {
+        self.to_cycle_epoch_day().julian_day_number()
+    }
+
+    /// The Unix timestamps (in seconds) the date covers in the given time zone offset,
+    /// as half-open range `(start, end)`: `start <= timestamp < end`.
+    ///
+    /// Useful to translate a date filter into timestamp predicates, e.g. for log queries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{UtcOffset, date};
+    ///
+    /// // 2000-03-20 (Gregorian)
+    /// let date = date!(M000 - 01 - 01);
+    ///
+    /// assert_eq!(
+    ///     date.unix_timestamp_range(UtcOffset::UTC),
+    ///     (953_510_400, 953_596_800)
+    /// );
+    /// assert_eq!(
+    ///     date.unix_timestamp_range(UtcOffset::from_hm(2, 0).unwrap()),
+    ///     (953_503_200, 953_589_600)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn unix_timestamp_range(&self, offset: UtcOffset) -> (i64, i64) {
+        const SECONDS_PER_DAY: i64 = 86_400;
+
+        // local midnight is earlier in UTC for offsets east of UTC
+        let start = self.to_unix_day().value() as i64 * SECONDS_PER_DAY - offset.seconds() as i64;
+
+        (start, start + SECONDS_PER_DAY)
+    }
+
+    /// Number of days left in the year after this date.
+    ///
+    /// The date itself isn't counted, so the last day of the year (Addenduary 29th) returns zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 01).days_remaining_in_year(), 364);
+    /// assert_eq!(date!(M020 - 13 - 29).days_remaining_in_year(), 0);
+    /// ```
+    #[must_use]
+    pub const fn days_remaining_in_year(&self) -> u16 {
+        self.year.days() - self.day_of_year()
+    }
+
+    /// Elapsed fraction of the year at the start of this date (0 on the first day of the year).
+    ///
+    /// Returned as the number of elapsed days and the length of the year, e.g. for exact
+    /// (integer) proration. See [`year_fraction()`](Self::year_fraction()) for a float.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 01).year_fraction_parts(), (0, 365));
+    /// assert_eq!(date!(M021 - 07 - 01).year_fraction_parts(), (169, 366));
+    /// ```
+    #[must_use]
+    pub const fn year_fraction_parts(&self) -> (u16, u16) {
+        (self.day_of_year() - 1, self.year.days())
+    }
+
+    /// Elapsed fraction of the year at the start of this date, from 0 (inclusive) to 1 (exclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 01).year_fraction(), 0.0);
+    /// assert_eq!(date!(M020 - 07 - 15).year_fraction(), 182.0 / 365.0);
+    /// ```
+    #[must_use]
+    pub fn year_fraction(&self) -> f64 {
+        let (elapsed, days) = self.year_fraction_parts();
+        f64::from(elapsed) / f64::from(days)
+    }
+
+    /// Number of years between `a` and `b` as fraction, e.g. for interest accrual or the
+    /// proration of subscriptions. Negative if `b` is before `a`.
+    ///
+    /// Every day counts as the fraction of the year it belongs to (1/365, or 1/366 on leap years),
+    /// like the ACT/ACT convention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Date, date};
+    ///
+    /// let a = date!(M020 - 07 - 15);
+    ///
+    /// assert_eq!(Date::days_between_fraction_of_year(a, date!(M020 - 07 - 25)), 10.0 / 365.0);
+    /// assert_eq!(Date::days_between_fraction_of_year(date!(M020 - 01 - 01), date!(M022 - 01 - 01)), 2.0);
+    /// ```
+    #[must_use]
+    pub fn days_between_fraction_of_year(a: Self, b: Self) -> f64 {
+        let (elapsed_a, days_a) = a.year_fraction_parts();
+        let (elapsed_b, days_b) = b.year_fraction_parts();
+
+        let years = f64::from(b.year.value()) - f64::from(a.year.value());
+        let (elapsed_a, days_a) = (f64::from(elapsed_a), f64::from(days_a));
+        let (elapsed_b, days_b) = (f64::from(elapsed_b), f64::from(days_b));
+
+        // The numerator is an integer far below 2^53, so there's only one rounding step (the division).
+        (years * days_a * days_b + elapsed_b * days_a - elapsed_a * days_b) / (days_a * days_b)
+    }
+
+    /// Week of the month (1-4).
+    ///
+    /// Every SAC13 month consists of exactly four weeks. Synchronization days
+    /// (day 29, see [`weekday_ordinal()`](Self::weekday_ordinal())) are treated
+    /// as the 8th day of the fourth week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 05 - 07).week_of_month(), 1);
+    /// assert_eq!(date!(M020 - 05 - 08).week_of_month(), 2);
+    /// assert_eq!(date!(M020 - 13 - 29).week_of_month(), 4);
+    /// ```
+    #[must_use]
+    pub const fn week_of_month(&self) -> u8 {
+        match self.day {
+            29 => 4,
+            x => (x - 1) / 7 + 1,
+        }
+    }
+
+    /// Week of the year (1-52).
+    ///
+    /// Synchronization days belong to the last week of their month,
+    /// just like with [`week_of_month()`](Self::week_of_month()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 01 - 01).week_of_year(), 1);
+    /// assert_eq!(date!(M021 - 06 - 29).week_of_year(), 24);
+    /// assert_eq!(date!(M020 - 13 - 29).week_of_year(), 52);
+    /// ```
+    #[must_use]
+    pub const fn week_of_year(&self) -> u8 {
+        (self.month.ord() - 1) * 4 + self.week_of_month()
+    }
+
+    /// First day of the week this date belongs to.
+    ///
+    /// Synchronization days align to the 22nd of the month, which is the start
+    /// of the fourth week they belong to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 05 - 14).align_to_week_start(), date!(M020 - 05 - 08));
+    /// assert_eq!(date!(M020 - 13 - 29).align_to_week_start(), date!(M020 - 13 - 22));
+    /// ```
+    #[must_use]
+    pub const fn align_to_week_start(self) -> Self {
+        Self {
+            day: (self.week_of_month() - 1) * 7 + 1,
+            ..self
+        }
+    }
+
+    /// First day of the [period](Granularity) the date belongs to, e.g. to bucket
+    /// dates for a histogram.
+    ///
+    /// Same as [`Granularity::period_start()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{Granularity, date};
+    ///
+    /// let date = date!(M020 - 05 - 21);
+    ///
+    /// assert_eq!(date.truncate(Granularity::Week), date!(M020 - 05 - 15));
+    /// assert_eq!(date.truncate(Granularity::Month), date!(M020 - 05 - 01));
+    /// assert_eq!(date.truncate(Granularity::Quarter), date!(M020 - 04 - 08));
+    /// assert_eq!(date.truncate(Granularity::Year), date!(M020 - 01 - 01));
+    /// ```
+    #[must_use]
+    pub const fn truncate(self, granularity: Granularity) -> Self {
+        granularity.period_start(self)
+    }
+
+    /// The [`Week`] this date belongs to (see [`week_of_year()`](Self::week_of_year())).
+    #[must_use]
+    pub const fn in_week(self) -> Week {
+        let Some(week) = Week::new(self.year, self.week_of_year()) else {
+            unreachable!()
+        };
+
+        week
+    }
+
+    /// Adds (or subtracts, if negative) the given number of days.
+    ///
+    /// Returns `None` if the result is outside the SAC13 range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 13 - 28).add_days(2), Some(date!(M021 - 01 - 01)));
+    /// assert_eq!(date!(M021 - 01 - 01).add_days(-1), Some(date!(M020 - 13 - 29)));
+    /// ```
+    #[must_use]
+    pub fn add_days(self, days: i32) -> Option<Self> {
+        Self::from_julian(self.as_julian().checked_add(days)?)
+    }
+
+    /// Adds (or subtracts, if negative) the given number of months, keeping the day.
+    ///
+    /// If the day doesn't exist in the resulting month (the 29th of a 28-day month)
+    /// it's clamped to the 28th. Returns `None` if the result is outside the SAC13 range.
+    /// Use [`add_months_with()`](Self::add_months_with()) for a different [`LeapPolicy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 12 - 15).add_months(2), Some(date!(M021 - 01 - 15)));
+    /// assert_eq!(date!(M020 - 13 - 29).add_months(1), Some(date!(M021 - 01 - 28)));
+    /// ```
+    #[must_use]
+    pub fn add_months(self, months: i32) -> Option<Self> {
+        self.add_months_with(months, LeapPolicy::ClampToPrevious)
+    }
+
+    /// Adds (or subtracts, if negative) the given number of months, keeping the day.
+    ///
+    /// The [`LeapPolicy`] decides what happens if the day doesn't exist in the resulting month.
+    /// Returns `None` if the result is outside the SAC13 range or rejected by the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{LeapPolicy, date};
+    ///
+    /// let year_day = date!(M020 - 13 - 29);
+    ///
+    /// assert_eq!(year_day.add_months_with(1, LeapPolicy::Reject), None);
+    /// assert_eq!(
+    ///     year_day.add_months_with(1, LeapPolicy::RollToNext),
+    ///     Some(date!(M021 - 02 - 01))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn add_months_with(self, months: i32, policy: LeapPolicy) -> Option<Self> {
+        let index = i32::from(self.year.value()) * 13 + i32::from(self.month.ord()) - 1;
+        let index = u32::try_from(index.checked_add(months)?).ok()?;
+
+        let year = Year::new(u16::try_from(index / 13).ok()?)?;
+        let month = Month::new((index % 13) as u8 + 1)?;
+
+        Self::from_ymd_with(year, month, self.day, policy)
+    }
+
+    /// Adds (or subtracts, if negative) the given number of years, keeping month and day.
+    ///
+    /// The leap day (August 29th) is clamped to August 28th if the resulting year is a
+    /// common year. Returns `None` if the result is outside the SAC13 range.
+    /// Use [`add_years_with()`](Self::add_years_with()) for a different [`LeapPolicy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(date!(M020 - 05 - 21).add_years(5), Some(date!(M025 - 05 - 21)));
+    /// assert_eq!(date!(M021 - 06 - 29).add_years(1), Some(date!(M022 - 06 - 28)));
+    /// ```
+    #[must_use]
+    pub fn add_years(self, years: i32) -> Option<Self> {
+        self.add_years_with(years, LeapPolicy::ClampToPrevious)
+    }
+
+    /// Adds (or subtracts, if negative) the given number of years, keeping month and day.
+    ///
+    /// The [`LeapPolicy`] decides what happens to the leap day if the resulting year is a common year.
+    /// Returns `None` if the result is outside the SAC13 range or rejected by the policy.
+    #[must_use]
+    pub fn add_years_with(self, years: i32, policy: LeapPolicy) -> Option<Self> {
+        let year = i32::from(self.year.value()).checked_add(years)?;
+        let year = Year::new(u16::try_from(year).ok()?)?;
+
+        self.anniversary(year, policy)
+    }
+
+    /// The same month and day in the given year, e.g. for birthdays or yearly recurring events.
+    ///
+    /// The [`LeapPolicy`] decides what happens to the leap day if `year` is a common year.
+    /// See [`LeapPolicy`] for an example.
+    #[must_use]
+    pub const fn anniversary(self, year: Year, policy: LeapPolicy) -> Option<Self> {
+        Self::from_ymd_with(year, self.month, self.day, policy)
+    }
+
+    /// Adds a [`CalendarDuration`] to the date.
+    ///
+    /// See [`CalendarDuration`] for the exact semantics.
+    /// Returns `None` if the result is outside the SAC13 range.
+    #[must_use]
+    pub fn checked_add(self, duration: CalendarDuration) -> Option<Self> {
+        let days = duration
+            .weeks()
+            .checked_mul(7)?
+            .checked_add(duration.days())?;
+
+        self.add_years(i32::try_from(duration.years()).ok()?)?
+            .add_months(i32::try_from(duration.months()).ok()?)?
+            .add_days(i32::try_from(days).ok()?)
+    }
+
+    /// Subtracts a [`CalendarDuration`] from the date.
+    ///
+    /// The components are applied in the same order as with [`checked_add()`](Self::checked_add()),
+    /// just backwards in time. Returns `None` if the result is outside the SAC13 range.
+    #[must_use]
+    pub fn checked_sub(self, duration: CalendarDuration) -> Option<Self> {
+        let days = duration
+            .weeks()
+            .checked_mul(7)?
+            .checked_add(duration.days())?;
+
+        self.add_years(-i32::try_from(duration.years()).ok()?)?
+            .add_months(-i32::try_from(duration.months()).ok()?)?
+            .add_days(-i32::try_from(days).ok()?)
+    }
+
+    /// The age of someone born on `birth` on this date, in completed years, months and days.
     ///
-    /// Returns [`None`] for invalid dates.
+    /// The result is exact: `birth.checked_add(age)` gives this date again.
+    /// The years and months are counted like [`add_years()`](Self::add_years()) and
+    /// [`add_months()`](Self::add_months()) count them, so someone born on the leap day
+    /// (August 29th) completes a year on August 28th in common years, and someone born on
+    /// Addenduary 29th completes a month on the 28th of the following month.
+    ///
+    /// Returns [`CalendarDuration::ZERO`] if this date is before `birth`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sac13::{Date,date};
+    /// use sac13::{CalendarDuration, date};
     ///
-    /// assert_eq!(Date::from_ymd_untyped(12020,02,16), Some(date!(M020 - 02 - 16)));
-    /// assert_eq!(Date::from_ymd_untyped(12020,02,29), None); // April 29th does not exist in SAC13
+    /// let birth = date!(M000 - 05 - 21);
+    /// let age = date!(M020 - 07 - 03).age_on(birth);
+    ///
+    /// assert_eq!(age, CalendarDuration::new(20, 1, 0, 10));
+    /// assert_eq!(birth.checked_add(age), Some(date!(M020 - 07 - 03)));
+    ///
+    /// // leap day birth
+    /// assert_eq!(date!(M022 - 06 - 28).years_since(date!(M021 - 06 - 29)), 1);
+    /// assert_eq!(date!(M022 - 06 - 27).years_since(date!(M021 - 06 - 29)), 0);
     /// ```
     #[must_use]
-    pub const fn from_ymd_untyped(year: u16, month: u8, day: u8) -> Option<Self> {
-        let y = match Year::new(year) {
-            None => return None,
-            Some(y) => y,
-        };
+    pub fn age_on(&self, birth: Self) -> CalendarDuration {
+        if *self < birth {
+            return CalendarDuration::ZERO;
+        }
 
-        let m = match Month::new(month) {
-            None => return None,
-            Some(m) => m,
+        // never `None`: the anniversaries are between `birth` and `self`
+        let anniversary = |years, months| {
+            birth
+                .checked_add(CalendarDuration::new(years, months, 0, 0))
+                .unwrap_or(Self::MAX)
         };
 
-        Self::from_ymd(y, m, day)
+        let mut years = u32::from(self.year.value() - birth.year.value());
+
+        if anniversary(years, 0) > *self {
+            years -= 1;
+        }
+
+        let mut months = 0;
+
+        while months < 12 && anniversary(years, months + 1) <= *self {
+            months += 1;
+        }
+
+        let days = self.as_julian() - anniversary(years, months).as_julian();
+
+        CalendarDuration::new(years, months, 0, days.unsigned_abs())
     }
 
-    /// Year component of the date.
+    /// The age of someone born on `birth` on this date, in completed years.
+    ///
+    /// See [`age_on()`](Self::age_on()) for details.
     #[must_use]
-    pub const fn year(&self) -> Year {
-        self.year
+    pub fn years_since(&self, birth: Self) -> u16 {
+        // at most 25'999
+        u16::try_from(self.age_on(birth).years()).unwrap_or(u16::MAX)
     }
 
-    /// Month component of the date.
+    /// Approximate, human-friendly phrase describing this date relative to `base`,
+    /// like "yesterday", "in 3 days" or "2 months ago".
+    ///
+    /// The result implements [`Display`] and doesn't allocate.
+    /// See [`HumanizedRelative`] for details and how to configure the granularity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{date, Granularity};
+    ///
+    /// let today = date!(M020 - 05 - 21);
+    ///
+    /// assert_eq!(date!(M020 - 05 - 18).humanize_relative_to(today).to_string(), "3 days ago");
+    /// assert_eq!(date!(M020 - 07 - 21).humanize_relative_to(today).to_string(), "in 2 months");
+    ///
+    /// let coarse = date!(M020 - 05 - 18).humanize_relative_to(today).granularity(Granularity::Month);
+    /// assert_eq!(coarse.to_string(), "this month");
+    /// ```
+    #[cfg(feature = "names")]
     #[must_use]
-    pub const fn month(&self) -> Month {
-        self.month
+    pub fn humanize_relative_to(self, base: Self) -> HumanizedRelative {
+        HumanizedRelative::new(self.as_julian() - base.as_julian())
     }
 
-    /// Day component of the date.
-    #[must_use]
-    pub const fn day(&self) -> u8 {
-        self.day
+    /// Date with the given components, but the day resolved with the given policy
+    /// if it's larger than the month is long.
+    const fn from_ymd_with(year: Year, month: Month, day: u8, policy: LeapPolicy) -> Option<Self> {
+        if day <= Self::month_len(year, month) {
+            return Self::from_ymd(year, month, day);
+        }
+
+        match policy {
+            LeapPolicy::Reject => None,
+            LeapPolicy::ClampToPrevious => Some(Self::with_clamped_day(year, month, day)),
+            // only 28-day months can be too short, so the next month is in the same year
+            LeapPolicy::RollToNext => Self::from_ymd(year, month.next(), 1),
+        }
     }
 
-    /// SAC13 Weekday ordinal.
+    /// Date with the given components, but the day clamped to the length of the month.
+    const fn with_clamped_day(year: Year, month: Month, day: u8) -> Self {
+        let len = Self::month_len(year, month);
+
+        Self {
+            year,
+            month,
+            day: if day > len { len } else { day },
+        }
+    }
+
+    /// Easter Sunday of the SAC13 year, i.e. the [Gregorian Easter](GregorianDate::easter())
+    /// of the Gregorian year in which the SAC13 year starts (e.g. 2020 for M020).
     ///
-    /// Returns which day of the week it is. Typically 1-7, 8 on synchronization days.
-    /// Note that 1-7 are NOT synonymous with Monday - Sunday. SAC13 doesn't have weekdays
-    /// in the typical sense.
+    /// The result is within the given year, except for a few years in the distant past
+    /// (e.g. A218) that start after March 22nd, where Easter is the year day of the previous year.
+    ///
+    /// ```
+    /// use sac13::{Date, date, year};
+    ///
+    /// let easter = Date::easter(year!(M024));
+    /// assert_eq!(easter, date!(M024 - 01 - 12));
+    ///
+    /// // Pentecost
+    /// assert_eq!(easter.add_days(49), Some(date!(M024 - 03 - 05)));
+    /// ```
     #[must_use]
-    pub const fn weekday_ordinal(&self) -> u8 {
-        match self.day {
-            29 => 8,
-            x => (x - 1) % 7 + 1,
-        }
+    #[allow(clippy::cast_possible_wrap)] // years are at most 25'999
+    pub fn easter(year: Year) -> Self {
+        // A000 starts on March 22nd and Z999 ends after April 25th
+        let Some(easter) = GregorianDate::easter((year.value() as i16) - 10_000) else {
+            unreachable!()
+        };
+
+        easter.convert()
     }
 
     /// All months have 28 days, except (Addenduary)[Month::Addenduary], and (August)[Month::August] on [leap years](Year::is_leap), which are 29 days long.
@@ -119,6 +1346,22 @@ impl Date {
     }
 }
 
+impl Default for Date {
+    /// The first representable date A000-01-01 ([`Date::MIN`](CalendarDate::MIN)).
+    ///
+    /// This matches the default [`Year`] (A000) and [`Month`] (March), just like
+    /// integers default to zero.
+    ///
+    /// ```
+    /// use sac13::date;
+    ///
+    /// assert_eq!(sac13::Date::default(), date!(A000 - 01 - 01));
+    /// ```
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
 impl Display for Date {
     /// Displays the SAC13 date.
     ///
@@ -129,7 +1372,22 @@ impl Display for Date {
     /// assert_eq!(formatted_date, "M020-05-21");
     /// ```
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month.ord(), self.day)
+        write!(
+            f,
+            "{}-{}-{:02}",
+            self.year,
+            self.month.as_number(),
+            self.day
+        )
+    }
+}
+
+impl core::str::FromStr for Date {
+    type Err = ParseError;
+
+    /// Same as [`Date::parse_display()`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_display(s)
     }
 }
 
@@ -194,15 +1452,116 @@ impl CalendarDate for Date {
     }
 }
 
+impl Datelike for Date {
+    fn year_value(&self) -> i32 {
+        self.year.value().into()
+    }
+
+    fn month_ordinal(&self) -> u8 {
+        self.month.ord()
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn ordinal(&self) -> u16 {
+        self.day_of_year()
+    }
+
+    fn weekday_ordinal(&self) -> u8 {
+        Self::weekday_ordinal(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use core::fmt;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+    use super::Date;
+
+    impl Serialize for Date {
+        /// Serializes the date as string in its display format (e.g. `M020-05-21`).
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct DateVisitor;
+
+    impl de::Visitor<'_> for DateVisitor {
+        type Value = Date;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a SAC13 date like M020-05-21")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            // strict, so that only the serialized format is accepted
+            Date::parse_display(v).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Date {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(DateVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod valuable_impl {
+    use valuable::{
+        Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit,
+    };
+
+    use super::Date;
+
+    static FIELDS: &[NamedField<'static>] = &[
+        NamedField::new("year"),
+        NamedField::new("month"),
+        NamedField::new("day"),
+        NamedField::new("text"),
+    ];
+
+    impl Valuable for Date {
+        fn as_value(&self) -> Value<'_> {
+            Value::Structable(self)
+        }
+
+        /// Visits the numeric year (e.g. `12020`), month and day and the display format as `text`.
+        fn visit(&self, visit: &mut dyn Visit) {
+            let bytes = self.to_canonical_bytes();
+            let Ok(text) = core::str::from_utf8(&bytes) else {
+                unreachable!("the canonical format is ASCII")
+            };
+
+            visit.visit_named_fields(&NamedValues::new(
+                FIELDS,
+                &[
+                    Value::U16(self.year().value()),
+                    Value::U8(self.month().ord()),
+                    Value::U8(self.day()),
+                    Value::String(text),
+                ],
+            ));
+        }
+    }
+
+    impl Structable for Date {
+        fn definition(&self) -> StructDef<'_> {
+            StructDef::new_static("Date", Fields::Named(FIELDS))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use raw_date::YearOrdinal;
 
-    use crate::{
-        scalars::{CycleEpochDay, JulianDay, UnixDay},
-        traits::CalendarDate,
-        weekday::Weekday,
-    };
+    use crate::{GregorianDate, traits::CalendarDate};
 
     use super::*;
 
@@ -217,6 +1576,269 @@ mod tests {
         assert!(date!(M019 - 06 - 17) < date!(M020 - 05 - 16));
     }
 
+    #[test]
+    fn parse_display_is_strict() {
+        use crate::ParseErrorKind::*;
+
+        for (input, kind, span) in [
+            ("", InvalidLength, 0..0),
+            ("M020-05-2", InvalidLength, 0..9),
+            ("M020-05-021", InvalidLength, 0..11),
+            (" M020-05-21", InvalidLength, 0..11),
+            ("m020-05-21", InvalidCharacter, 0..1),
+            ("2020-05-21", InvalidCharacter, 0..1),
+            ("M020.05.21", InvalidCharacter, 4..5),
+            ("M020-05-2x", InvalidCharacter, 9..10),
+            ("M020-05-ä1", InvalidCharacter, 8..10),
+            ("M020-14-01", InvalidMonth, 5..7),
+            ("M020-05-00", InvalidDay, 8..10),
+            ("M022-06-29", InvalidDay, 8..10),
+        ] {
+            let error = Date::parse_display(input).unwrap_err();
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_the_display_format() {
+        let date = date!(M020 - 05 - 21);
+        let json = serde_json::to_string(&date).unwrap();
+
+        assert_eq!(json, r#""M020-05-21""#);
+        assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), date);
+
+        // formats that `parse_date_str()` would accept are rejected
+        for json in [r#""M020-5-21""#, r#""21.05.M020""#, r#""2020-05-21""#] {
+            assert!(serde_json::from_str::<Date>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn weekday_searches() {
+        let date = date!(M020 - 05 - 21);
+
+        for weekday in Weekday::ALL {
+            let next = date
+                .next_gregorian_weekday(weekday, SyncDays::Include)
+                .unwrap();
+            let previous = date
+                .previous_gregorian_weekday(weekday, SyncDays::Include)
+                .unwrap();
+
+            assert_eq!(next.weekday(), weekday);
+            assert!((1..=7).contains(&(next.as_julian() - date.as_julian())));
+            // both skip this date if it has the weekday already
+            let distance = if weekday == date.weekday() { 14 } else { 7 };
+            assert_eq!(next.as_julian() - previous.as_julian(), distance);
+        }
+
+        // M020-13-29 is a Friday, M021-06-29 (leap day) a Saturday
+        let before_year_day = date!(M020 - 13 - 28);
+        assert_eq!(
+            before_year_day.next_gregorian_weekday(Weekday::Friday, SyncDays::Skip),
+            Some(date!(M021 - 01 - 07))
+        );
+        assert_eq!(
+            date!(M021 - 07 - 01).previous_gregorian_weekday(Weekday::Saturday, SyncDays::Skip),
+            Some(date!(M021 - 06 - 22))
+        );
+
+        assert_eq!(
+            date!(M021 - 01 - 01).next_weekday_ordinal(8, SyncDays::Include),
+            Some(date!(M021 - 06 - 29))
+        );
+        assert_eq!(
+            date!(M021 - 01 - 01).previous_weekday_ordinal(8, SyncDays::Include),
+            Some(date!(M020 - 13 - 29))
+        );
+        assert_eq!(
+            date!(M020 - 13 - 29).next_weekday_ordinal(1, SyncDays::Skip),
+            Some(date!(M021 - 01 - 01))
+        );
+        assert_eq!(
+            date!(M021 - 01 - 01).previous_weekday_ordinal(7, SyncDays::Skip),
+            Some(date!(M020 - 13 - 28))
+        );
+
+        assert_eq!(date.next_weekday_ordinal(0, SyncDays::Include), None);
+        assert_eq!(date.next_weekday_ordinal(9, SyncDays::Include), None);
+        assert_eq!(Date::MAX.next_weekday_ordinal(1, SyncDays::Include), None);
+        assert_eq!(
+            Date::MIN.previous_gregorian_weekday(Weekday::Monday, SyncDays::Include),
+            None
+        );
+    }
+
+    #[test]
+    fn age() {
+        let birth = date!(M000 - 13 - 29);
+
+        for (on, age) in [
+            (date!(M000 - 13 - 29), CalendarDuration::ZERO),
+            (date!(M001 - 01 - 27), CalendarDuration::new(0, 0, 0, 27)),
+            (date!(M001 - 01 - 28), CalendarDuration::new(0, 1, 0, 0)),
+            (date!(M001 - 13 - 28), CalendarDuration::new(0, 12, 0, 28)),
+            (date!(M001 - 13 - 29), CalendarDuration::new(1, 0, 0, 0)),
+            (date!(M030 - 01 - 01), CalendarDuration::new(29, 0, 0, 1)),
+        ] {
+            assert_eq!(on.age_on(birth), age, "{on}");
+            assert_eq!(birth.checked_add(age), Some(on), "{on}");
+        }
+
+        // born on the leap day
+        let birth = date!(M021 - 06 - 29);
+
+        assert_eq!(
+            date!(M022 - 06 - 28).age_on(birth),
+            CalendarDuration::from_years(1)
+        );
+        assert_eq!(date!(M025 - 06 - 28).years_since(birth), 3);
+        assert_eq!(date!(M025 - 06 - 29).years_since(birth), 4);
+
+        assert_eq!(birth.age_on(date!(M021 - 07 - 01)), CalendarDuration::ZERO);
+        assert_eq!(Date::MAX.years_since(Date::MIN), 25_999);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn year_fractions() {
+        // a leap year (M021) and a common year (M022)
+        let a = date!(M021 - 13 - 29);
+        let b = date!(M022 - 01 - 01);
+
+        assert_eq!(a.year_fraction_parts(), (365, 366));
+        assert_eq!(a.year_fraction(), 365.0 / 366.0);
+        assert_eq!(Date::days_between_fraction_of_year(a, b), 1.0 / 366.0);
+        assert_eq!(Date::days_between_fraction_of_year(b, a), -1.0 / 366.0);
+        assert_eq!(Date::days_between_fraction_of_year(a, a), 0.0);
+
+        let c = date!(M022 - 01 - 02);
+        let expected = 1.0 / 366.0 + 1.0 / 365.0;
+        assert!((Date::days_between_fraction_of_year(a, c) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn unix_timestamp_ranges() {
+        let west = UtcOffset::from_hm(-10, 0).unwrap();
+
+        for date in [Date::MIN, date!(M020 - 05 - 21), Date::MAX] {
+            let (start, end) = date.unix_timestamp_range(west);
+            let (next_start, _) = date.tomorrow().unwrap_or(date).unix_timestamp_range(west);
+
+            assert_eq!(end - start, 86_400);
+            assert_eq!(
+                start,
+                i64::from(date.to_unix_day().value()) * 86_400 + 36_000
+            );
+            assert!(next_start == end || date == Date::MAX);
+        }
+    }
+
+    #[test]
+    fn epoch_constants() {
+        assert_eq!(Date::SAC13_EPOCH, Date::MIN);
+        assert_eq!(Date::SAC13_EPOCH.to_sac13_day().value(), 0);
+        assert_eq!(CycleEpochDay::YEAR_ZERO.to_date(), Date::SAC13_EPOCH);
+
+        assert_eq!(Date::UNIX_EPOCH_DATE.to_unix_day().value(), 0);
+        assert_eq!(
+            Date::UNIX_EPOCH_DATE.convert::<GregorianDate>(),
+            date_greg!(1970 - 01 - 01)
+        );
+
+        assert_eq!(
+            JulianDay::GREGORIAN_REFORM.convert::<GregorianDate>(),
+            date_greg!(1582 - 10 - 15)
+        );
+    }
+
+    #[test]
+    fn named_day_count_conversions_match_convert() {
+        for date in [Date::MIN, date!(M020 - 13 - 28), Date::MAX] {
+            assert_eq!(date.to_sac13_day(), date.convert::<Sac13Day>());
+            assert_eq!(date.to_cycle_epoch_day(), date.convert::<CycleEpochDay>());
+            assert_eq!(date.to_julian_day(), date.convert::<JulianDay>());
+            assert_eq!(date.to_unix_day(), date.convert::<UnixDay>());
+            assert_eq!(date.to_excel_day(), date.convert::<ExcelDay>());
+            assert_eq!(
+                date.to_excel_day().value(),
+                date.to_unix_day().value() + ExcelDay::UNIX_DAY_OFFSET
+            );
+
+            assert_eq!(date.to_sac13_day().to_date(), date);
+            assert_eq!(date.to_cycle_epoch_day().to_date(), date);
+            assert_eq!(date.to_julian_day().to_date(), date);
+            assert_eq!(date.to_unix_day().to_date(), date);
+            assert_eq!(date.to_excel_day().to_date(), date);
+        }
+    }
+
+    #[test]
+    fn weekday_of_all_day_counts() {
+        let date = date!(M000 - 03 - 07); // 2000-03-26 (Gregorian)
+
+        assert_eq!(date.weekday(), Weekday::Sunday);
+        assert_eq!(date.to_sac13_day().weekday(), Weekday::Sunday);
+        assert_eq!(date.to_cycle_epoch_day().weekday(), Weekday::Sunday);
+        assert_eq!(date.to_julian_day().weekday(), Weekday::Sunday);
+        assert_eq!(date.to_unix_day().weekday(), Weekday::Sunday);
+
+        assert_eq!(
+            Date::MIN.weekday().next(),
+            Date::MIN.tomorrow().unwrap().weekday()
+        );
+        assert_eq!(
+            Date::MAX.weekday().previous(),
+            Date::MAX.yesterday().unwrap().weekday()
+        );
+    }
+
+    #[test]
+    fn weekday_in_const_context() {
+        const SCHEDULE: [(Weekday, u8); 2] = [
+            (
+                weekday!(M020 - 05 - 21),
+                date!(M020 - 05 - 21).weekday_ordinal(),
+            ),
+            (Date::MAX.weekday(), Date::MAX.weekday_ordinal()),
+        ];
+
+        assert_eq!(SCHEDULE[0], (date!(M020 - 05 - 21).weekday(), 7));
+        assert_eq!(SCHEDULE[1], (Date::MAX.weekday(), 8));
+    }
+
+    #[test]
+    fn leap_policies() {
+        let leap_day = date!(M021 - 06 - 29);
+
+        for policy in [
+            LeapPolicy::Reject,
+            LeapPolicy::ClampToPrevious,
+            LeapPolicy::RollToNext,
+        ] {
+            assert_eq!(
+                leap_day.add_years_with(4, policy),
+                Some(date!(M025 - 06 - 29))
+            );
+            assert_eq!(leap_day.add_months_with(1_000_000, policy), None);
+        }
+
+        assert_eq!(leap_day.add_years_with(1, LeapPolicy::Reject), None);
+        assert_eq!(
+            leap_day.add_years_with(1, LeapPolicy::RollToNext),
+            Some(date!(M022 - 07 - 01))
+        );
+        assert_eq!(
+            date!(M020 - 13 - 29).add_months_with(-1, LeapPolicy::RollToNext),
+            Some(date!(M020 - 13 - 01))
+        );
+        assert_eq!(
+            Date::MAX.add_months_with(-1, LeapPolicy::ClampToPrevious),
+            Some(date!(Z999 - 12 - 28))
+        );
+    }
+
     #[test]
     pub fn reference_date_unix_epoch_works() {
         let date: Date = UnixDay::new(11036).unwrap().convert();
@@ -254,9 +1876,211 @@ mod tests {
         assert_eq!(result.day(), 0);
     }
 
+    #[test]
+    fn year_ordinal_parse_and_display() {
+        let last = YearOrdinal::from_date(date!(M021 - 13 - 29));
+
+        assert_eq!(format!("{last}"), "M021-366");
+        assert_eq!("M021-366".parse(), Ok(last));
+        assert_eq!(
+            last.tomorrow().map(|o| format!("{o}")).as_deref(),
+            Some("M022-001")
+        );
+        assert_eq!(
+            last.tomorrow().and_then(CalendarDate::yesterday),
+            Some(last)
+        );
+        assert_eq!(YearOrdinal::MAX.tomorrow(), None);
+        assert_eq!(YearOrdinal::MIN.yesterday(), None);
+
+        for (input, kind, span) in [
+            ("M022-366", ParseErrorKind::InvalidDay, 5..8),
+            ("M022-000", ParseErrorKind::InvalidDay, 5..8),
+            ("M022-12", ParseErrorKind::InvalidLength, 0..7),
+            ("M022/120", ParseErrorKind::InvalidCharacter, 4..5),
+            ("m022-120", ParseErrorKind::InvalidCharacter, 0..1),
+        ] {
+            let error = input.parse::<YearOrdinal>().unwrap_err();
+
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+    }
+
+    #[test]
+    fn datelike_ordinal_respects_leap_day() {
+        assert_eq!(Datelike::ordinal(&date!(M020 - 01 - 01)), 1);
+        assert_eq!(Datelike::ordinal(&date!(M021 - 06 - 29)), 169);
+        assert_eq!(Datelike::ordinal(&date!(M021 - 07 - 01)), 170);
+        assert_eq!(Datelike::ordinal(&date!(M020 - 13 - 29)), 365);
+        assert_eq!(Datelike::ordinal(&date!(M021 - 13 - 29)), 366);
+    }
+
+    #[test]
+    fn from_and_try_from_between_calendar_types() {
+        let greg: GregorianDate = date!(M000 - 01 - 01).into();
+        assert_eq!(greg, date_greg!(2000 - 03 - 20));
+
+        #[allow(clippy::unnecessary_fallible_conversions)] // explicitly testing `TryFrom`
+        let date: Result<Date, _> = greg.try_into();
+        assert_eq!(date, Ok(date!(M000 - 01 - 01)));
+
+        let unix: UnixDay = date!(M000 - 01 - 01).into();
+        assert_eq!(unix.value(), 11036);
+    }
+
+    #[test]
+    fn days_remaining_in_leap_year() {
+        assert_eq!(date!(M021 - 01 - 01).days_remaining_in_year(), 365);
+        assert_eq!(date!(M021 - 06 - 29).days_remaining_in_year(), 197);
+        assert_eq!(date!(M021 - 13 - 29).days_remaining_in_year(), 0);
+    }
+
+    #[test]
+    fn arithmetic_respects_limits() {
+        assert_eq!(Date::MAX.add_days(1), None);
+        assert_eq!(Date::MIN.add_days(-1), None);
+        assert_eq!(Date::MAX.add_months(1), None);
+        assert_eq!(Date::MIN.add_months(-1), None);
+        assert_eq!(Date::MIN.add_years(26_000), None);
+        assert_eq!(Date::MIN.add_days(i32::MAX), None);
+
+        assert_eq!(
+            Date::MIN.add_months(25_999 * 13 + 12),
+            Some(date!(Z999 - 13 - 01))
+        );
+    }
+
+    #[test]
+    fn checked_sub_reverses_checked_add_for_regular_days() {
+        let duration = CalendarDuration::new(1, 15, 2, 3);
+        let date = date!(M020 - 05 - 21);
+
+        let later = date.checked_add(duration).unwrap();
+        assert_eq!(later, date!(M022 - 08 - 10));
+        assert_eq!(later.checked_sub(duration), Some(date));
+    }
+
+    #[test]
+    fn const_cmp_matches_ord() {
+        let dates = [
+            Date::MIN,
+            date!(M020 - 05 - 21),
+            date!(M020 - 05 - 22),
+            date!(M020 - 06 - 01),
+            date!(M021 - 01 - 01),
+            Date::MAX,
+        ];
+
+        for a in dates {
+            for b in dates {
+                assert_eq!(Date::const_cmp(a, b), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "min must not be greater than max")]
+    fn clamp_panics_on_swapped_limits() {
+        let _ = Date::MIN.clamp(Date::MAX, Date::MIN);
+    }
+
+    #[test]
+    fn canonical_round_trip_at_the_limits() {
+        for date in [Date::MIN, date!(M021 - 06 - 29), Date::MAX] {
+            let bytes = date.to_canonical_bytes();
+            let s = core::str::from_utf8(&bytes).unwrap();
+
+            assert_eq!(Date::from_canonical_str(s), Some(date));
+            assert_eq!(s, format!("{date}"));
+        }
+
+        assert_eq!(Date::from_canonical_str("M022-06-29"), None);
+        assert_eq!(Date::from_canonical_str("m020-05-21"), None);
+        assert_eq!(Date::from_canonical_str("M020_05_21"), None);
+        assert_eq!(Date::from_canonical_str("M020-05-2x"), None);
+    }
+
+    #[test]
+    fn sortable_keys_sort_chronologically() {
+        // every 997th day and its neighbors, in chronological order
+        let mut previous: Option<(Date, [u8; 11])> = None;
+        let mut julian = Date::MIN.as_julian();
+
+        while let Some(date) = Date::from_julian(julian) {
+            for date in [date.yesterday(), Some(date), date.tomorrow()]
+                .into_iter()
+                .flatten()
+            {
+                let key = date.to_sortable_key();
+                assert_eq!(Date::from_sortable_key(&key), Some(date));
+
+                if let Some((previous_date, previous_key)) = previous {
+                    assert_eq!(previous_date.cmp(&date), previous_key.cmp(&key));
+                }
+
+                previous = Some((date, key));
+            }
+
+            julian += 997;
+        }
+
+        assert_eq!(Date::from_sortable_key(b"M020-05-21"), None);
+        assert_eq!(Date::from_sortable_key(b"2M020-05-21"), None);
+    }
+
     #[test]
     pub fn leap_year_rule_works_as_expected() {
         assert!(year!(L814).is_common());
         assert!(year!(L815).is_leap());
     }
+
+    #[test]
+    fn easter_is_in_its_sac13_year() {
+        for (year, month, day) in [(2000, 4, 23), (1818, 3, 22), (2285, 3, 22), (2038, 4, 25)] {
+            assert_eq!(
+                GregorianDate::easter(year),
+                GregorianDate::from_ymd(year, month, day)
+            );
+        }
+
+        for year in (0..=Year::MAX.value()).filter_map(Year::new) {
+            let easter = Date::easter(year);
+
+            // the earliest Easter (March 22nd) can be before the start of early years
+            assert!(
+                easter.year() == year
+                    || (easter.year().next() == Some(year) && easter.month() == Month::Addenduary),
+                "{year}: {easter}"
+            );
+            assert_eq!(easter.weekday(), Weekday::Sunday);
+        }
+    }
+
+    #[test]
+    fn saturating_conversions_match_checked_ones() {
+        let [min, max] = [Date::MIN.as_julian(), Date::MAX.as_julian()];
+
+        for jdn in [
+            i32::MIN,
+            min - 1,
+            min,
+            min + 1,
+            2_451_624,
+            max - 1,
+            max,
+            max + 1,
+            i32::MAX,
+        ] {
+            let expected =
+                Date::from_julian(jdn).unwrap_or(if jdn < min { Date::MIN } else { Date::MAX });
+
+            assert_eq!(Date::from_julian_saturating(jdn), expected);
+            assert_eq!(
+                JulianDay::range_check(jdn).is_in_range(),
+                Date::from_julian(jdn).is_some()
+            );
+        }
+
+        assert_eq!(Year::new_saturating(u16::MAX), Year::MAX);
+    }
 }
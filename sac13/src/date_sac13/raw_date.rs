@@ -1,9 +1,10 @@
-use core::fmt::Display;
+use core::{fmt::Display, str::FromStr};
 
 use crate::{
+    parse::check_layout,
     scalars::{CycleEpochDay, Year},
     traits::CalendarDate,
-    Month,
+    Month, ParseError, ParseErrorKind,
 };
 
 use super::Date;
@@ -72,9 +73,26 @@ macro_rules! to_days_rest {
     };
 }
 
-/// SAC13 year ordinal. Consists of a year and the day of the year.
+/// SAC13 ordinal date. Consists of a year and the day of the year.
 ///
-/// This is only used internally and is not exposed for library consumers.
+/// [`new()`](Self::new()) and [`day()`](Self::day()) use the zero-based day of the year,
+/// [`from_ordinal()`](Self::from_ordinal()) and [`ordinal()`](Self::ordinal()) the one-based
+/// day like [`Date::day_of_year()`].
+///
+/// It's displayed like an ISO 8601 ordinal date, with the one-based day of the year as
+/// three digits (e.g. `M020-123`), and [`FromStr`] parses exactly this format.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+///
+/// let ordinal: YearOrdinal = "M021-169".parse().unwrap();
+///
+/// assert_eq!(ordinal.to_date(), date!(M021 - 06 - 29)); // leap day
+/// assert_eq!(YearOrdinal::from_date(date!(M020 - 01 - 01)).to_string(), "M020-001");
+/// assert_eq!(ordinal.add_days(197).unwrap().to_string(), "M021-366");
+/// ```
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct YearOrdinal {
     year: Year,
@@ -91,18 +109,63 @@ impl YearOrdinal {
         }
     }
 
+    /// Ordinal date from the one-based day of the year (1 - 365 or 366).
+    ///
+    /// Returns `None` if the day doesn't exist in the year.
+    #[must_use]
+    pub const fn from_ordinal(year: Year, ordinal: u16) -> Option<Self> {
+        if ordinal == 0 {
+            None
+        } else {
+            Self::new(year, ordinal - 1)
+        }
+    }
+
+    /// Ordinal date of a SAC13 [`Date`] (const version of `convert()`).
+    #[must_use]
+    pub const fn from_date(date: Date) -> Self {
+        date_to_yo(date)
+    }
+
+    /// Converts the ordinal date to a SAC13 [`Date`] (const version of `convert()`).
+    #[must_use]
+    pub const fn to_date(self) -> Date {
+        yo_to_date(self)
+    }
+
     #[must_use]
     pub const fn year(&self) -> Year {
         self.year
     }
 
+    /// Zero-based day of the year.
     #[must_use]
     pub const fn day(&self) -> u16 {
         self.day
     }
 
+    /// One-based day of the year (1 - 365 or 366).
+    #[must_use]
+    pub const fn ordinal(&self) -> u16 {
+        self.day + 1
+    }
+
+    /// Adds (or subtracts, if negative) the given number of days.
+    ///
+    /// Returns `None` if the result is outside the SAC13 range.
+    #[must_use]
+    pub fn add_days(self, days: i32) -> Option<Self> {
+        Self::from_julian(self.as_julian().checked_add(days)?)
+    }
+
+    /// Number of days from this day until `other` (negative if `other` is earlier).
     #[must_use]
-    const fn from_epoch_day(value: CycleEpochDay) -> Self {
+    pub fn days_until(self, other: Self) -> i32 {
+        other.as_julian() - self.as_julian()
+    }
+
+    #[must_use]
+    pub(crate) const fn from_epoch_day(value: CycleEpochDay) -> Self {
         let mut d = value.value();
         let mut y = 0;
 
@@ -119,7 +182,7 @@ impl YearOrdinal {
     }
 
     #[must_use]
-    const fn epoch_day(self) -> CycleEpochDay {
+    pub(crate) const fn epoch_day(self) -> CycleEpochDay {
         let mut d = self.day as u32;
         let mut y = (self.year.value() + RAW_YEAR_OFFSET) as u32;
 
@@ -135,7 +198,28 @@ impl YearOrdinal {
 
 impl Display for YearOrdinal {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}-{}", self.year(), self.day())
+        write!(f, "{}-{:03}", self.year(), self.ordinal())
+    }
+}
+
+impl FromStr for YearOrdinal {
+    type Err = ParseError;
+
+    /// Parses exactly the [`Display`] format (e.g. `M020-123`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.as_bytes();
+        check_layout(input, b"Lyyy-ddd")?;
+
+        let Some(year) = Year::from_ascii([input[0], input[1], input[2], input[3]]) else {
+            unreachable!("every letter followed by three digits is a valid year")
+        };
+
+        let ordinal = input[5..]
+            .iter()
+            .fold(0, |acc, &b| acc * 10 + u16::from(b - b'0'));
+
+        Self::from_ordinal(year, ordinal)
+            .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidDay, 5..8))
     }
 }
 
@@ -150,17 +234,43 @@ impl CalendarDate for YearOrdinal {
     fn from_julian(value: i32) -> Option<Self> {
         Some(Self::from_epoch_day(CycleEpochDay::from_julian(value)?))
     }
+
+    fn tomorrow(self) -> Option<Self> {
+        if self.day + 1 < self.year.days() {
+            Some(Self {
+                day: self.day + 1,
+                ..self
+            })
+        } else {
+            Some(Self {
+                year: self.year.next()?,
+                day: 0,
+            })
+        }
+    }
+
+    fn yesterday(self) -> Option<Self> {
+        if self.day > 0 {
+            Some(Self {
+                day: self.day - 1,
+                ..self
+            })
+        } else {
+            let year = self.year.previous()?;
+
+            Some(Self {
+                year,
+                day: year.days() - 1,
+            })
+        }
+    }
 }
 
-pub fn date_to_yo(value: Date) -> YearOrdinal {
+pub const fn date_to_yo(value: Date) -> YearOrdinal {
     let year = value.year;
     let month = value.month;
 
-    let mut day = (month.ord() as u16 - 1) * 28 + value.day as u16 - 1;
-
-    if year.is_leap() && month > Month::August {
-        day += 1;
-    }
+    let day = month.cumulative_days_before(year.year_type()) + value.day as u16 - 1;
 
     YearOrdinal { year, day }
 }
@@ -0,0 +1,407 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+use core::iter::FusedIterator;
+
+use crate::{
+    Date, MonthDaySet, Year,
+    date_sac13::raw_date::{YearOrdinal, date_to_yo, yo_to_date},
+};
+
+/// A set of dates of a single SAC13 year, stored as a 366-bit bitmap.
+///
+/// It doesn't allocate and all lookups and set operations work on the whole bitmap at once.
+/// Use [`DateSet`] (with the `alloc` feature) for sets that span multiple years.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Month, MonthDaySet, YearDateSet, date, year};
+///
+/// let holidays = MonthDaySet::EMPTY.with(Month::March, 1).with(Month::August, 29);
+///
+/// // the leap day only exists in leap years
+/// assert_eq!(YearDateSet::from_month_days(year!(M020), &holidays).len(), 1);
+///
+/// let mut booked = YearDateSet::from_month_days(year!(M021), &holidays);
+/// assert!(booked.insert(date!(M021 - 05 - 05)));
+///
+/// assert_eq!(
+///     booked.iter().collect::<Vec<_>>(),
+///     [date!(M021 - 01 - 01), date!(M021 - 05 - 05), date!(M021 - 06 - 29)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearDateSet {
+    year: Year,
+    /// Bit `n % 64` of `bits[n / 64]` for the zero-based day of the year `n`.
+    bits: [u64; 6],
+}
+
+impl YearDateSet {
+    /// Empty set for the given year.
+    #[must_use]
+    pub const fn new(year: Year) -> Self {
+        Self { year, bits: [0; 6] }
+    }
+
+    /// All days of the year that match the month and day of the [`MonthDaySet`].
+    #[must_use]
+    pub fn from_month_days(year: Year, month_days: &MonthDaySet) -> Self {
+        let mut set = Self::new(year);
+
+        for (month, day) in month_days.iter() {
+            if let Some(date) = Date::from_ymd(year, month, day) {
+                set.insert(date);
+            }
+        }
+
+        set
+    }
+
+    /// The year of the dates in the set.
+    #[must_use]
+    pub const fn year(&self) -> Year {
+        self.year
+    }
+
+    /// Adds the date to the set.
+    ///
+    /// Returns `true` if the date was added, and `false` if it was already part of
+    /// the set or isn't in the year of the set.
+    pub const fn insert(&mut self, date: Date) -> bool {
+        let Some((i, bit)) = self.position(date) else {
+            return false;
+        };
+
+        let added = self.bits[i] & bit == 0;
+        self.bits[i] |= bit;
+        added
+    }
+
+    /// Removes the date from the set. Returns `true` if it was part of the set.
+    pub const fn remove(&mut self, date: Date) -> bool {
+        let Some((i, bit)) = self.position(date) else {
+            return false;
+        };
+
+        let removed = self.bits[i] & bit != 0;
+        self.bits[i] &= !bit;
+        removed
+    }
+
+    /// Returns `true` if the date is part of the set.
+    #[must_use]
+    pub const fn contains(&self, date: Date) -> bool {
+        match self.position(date) {
+            Some((i, bit)) => self.bits[i] & bit != 0,
+            None => false,
+        }
+    }
+
+    /// Number of dates in the set.
+    #[must_use]
+    pub const fn len(&self) -> u32 {
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < self.bits.len() {
+            len += self.bits[i].count_ones();
+            i += 1;
+        }
+
+        len
+    }
+
+    /// Returns `true` if the set doesn't contain any dates.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Dates contained in either set.
+    ///
+    /// Returns `None` if the sets are for different years.
+    #[must_use]
+    pub const fn union(&self, other: &Self) -> Option<Self> {
+        self.combine(other, SetOp::Union)
+    }
+
+    /// Dates contained in both sets.
+    ///
+    /// Returns `None` if the sets are for different years.
+    #[must_use]
+    pub const fn intersection(&self, other: &Self) -> Option<Self> {
+        self.combine(other, SetOp::Intersection)
+    }
+
+    /// Dates of this set that aren't contained in `other`.
+    ///
+    /// Returns `None` if the sets are for different years.
+    #[must_use]
+    pub const fn difference(&self, other: &Self) -> Option<Self> {
+        self.combine(other, SetOp::Difference)
+    }
+
+    /// Iterates over the dates in chronological order.
+    #[must_use]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Date> + FusedIterator + use<> {
+        let Self { year, bits } = *self;
+
+        (0..year.days())
+            .filter(move |&day| bits[usize::from(day / 64)] & (1 << (day % 64)) != 0)
+            .map(move |day| {
+                let Some(ordinal) = YearOrdinal::new(year, day) else {
+                    unreachable!()
+                };
+
+                yo_to_date(ordinal)
+            })
+    }
+
+    /// Index and bit of the date, or `None` if it's not in the year of the set.
+    const fn position(&self, date: Date) -> Option<(usize, u64)> {
+        if date.year().value() != self.year.value() {
+            return None;
+        }
+
+        let day = date_to_yo(date).day();
+        Some(((day / 64) as usize, 1 << (day % 64)))
+    }
+
+    const fn combine(&self, other: &Self, op: SetOp) -> Option<Self> {
+        if self.year.value() != other.year.value() {
+            return None;
+        }
+
+        let mut set = *self;
+        let mut i = 0;
+
+        while i < set.bits.len() {
+            set.bits[i] = match op {
+                SetOp::Union => self.bits[i] | other.bits[i],
+                SetOp::Intersection => self.bits[i] & other.bits[i],
+                SetOp::Difference => self.bits[i] & !other.bits[i],
+            };
+            i += 1;
+        }
+
+        Some(set)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// A set of dates that can span multiple years.
+///
+/// It's sparse: only years that contain at least one date are stored (as a [`YearDateSet`]).
+/// Iteration is in chronological order.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateSet, date};
+///
+/// let a: DateSet = [date!(M020 - 01 - 01), date!(M021 - 01 - 01)].into_iter().collect();
+/// let b: DateSet = [date!(M021 - 01 - 01), date!(M500 - 01 - 01)].into_iter().collect();
+///
+/// assert_eq!(a.union(&b).len(), 3);
+/// assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [date!(M021 - 01 - 01)]);
+/// assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [date!(M020 - 01 - 01)]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DateSet {
+    /// Never contains empty sets.
+    years: BTreeMap<Year, YearDateSet>,
+}
+
+#[cfg(feature = "alloc")]
+impl DateSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            years: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the date to the set. Returns `true` if it wasn't part of the set before.
+    pub fn insert(&mut self, date: Date) -> bool {
+        self.years
+            .entry(date.year())
+            .or_insert_with(|| YearDateSet::new(date.year()))
+            .insert(date)
+    }
+
+    /// Removes the date from the set. Returns `true` if it was part of the set.
+    pub fn remove(&mut self, date: Date) -> bool {
+        let Some(year) = self.years.get_mut(&date.year()) else {
+            return false;
+        };
+
+        let removed = year.remove(date);
+
+        if year.is_empty() {
+            self.years.remove(&date.year());
+        }
+
+        removed
+    }
+
+    /// Returns `true` if the date is part of the set.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.years
+            .get(&date.year())
+            .is_some_and(|year| year.contains(date))
+    }
+
+    /// The dates of a single year. Returns `None` if the set doesn't contain any dates of the year.
+    #[must_use]
+    pub fn year(&self, year: Year) -> Option<&YearDateSet> {
+        self.years.get(&year)
+    }
+
+    /// Number of dates in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.years.values().map(|year| year.len() as usize).sum()
+    }
+
+    /// Returns `true` if the set doesn't contain any dates.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.years.is_empty()
+    }
+
+    /// Dates contained in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut years = self.years.clone();
+
+        for (year, set) in &other.years {
+            let Some(merged) = years
+                .get(year)
+                .map_or(Some(*set), |existing| existing.union(set))
+            else {
+                unreachable!("both sets are for the same year")
+            };
+
+            years.insert(*year, merged);
+        }
+
+        Self { years }
+    }
+
+    /// Dates contained in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| b.and_then(|b| a.intersection(b)))
+    }
+
+    /// Dates of this set that aren't contained in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| b.map_or(Some(*a), |b| a.difference(b)))
+    }
+
+    /// Iterates over the dates in chronological order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Date> + FusedIterator + '_ {
+        self.years.values().flat_map(YearDateSet::iter)
+    }
+
+    /// Combines the years of `self` with the same years of `other` and drops empty results.
+    fn combine(
+        &self,
+        other: &Self,
+        op: impl Fn(&YearDateSet, Option<&YearDateSet>) -> Option<YearDateSet>,
+    ) -> Self {
+        let years = self
+            .years
+            .iter()
+            .filter_map(|(year, set)| Some((*year, op(set, other.years.get(year))?)))
+            .filter(|(_, set)| !set.is_empty())
+            .collect();
+
+        Self { years }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<Date> for DateSet {
+    fn extend<T: IntoIterator<Item = Date>>(&mut self, iter: T) {
+        for date in iter {
+            self.insert(date);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<Date> for DateSet {
+    fn from_iter<T: IntoIterator<Item = Date>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year_date_set() {
+        let mut set = YearDateSet::new(year!(M021));
+
+        for date in [
+            date!(M021 - 13 - 29),
+            date!(M021 - 06 - 29),
+            date!(M021 - 01 - 01),
+        ] {
+            assert!(set.insert(date));
+            assert!(!set.insert(date));
+        }
+
+        assert!(!set.insert(date!(M020 - 01 - 01)));
+        assert!(set.remove(date!(M021 - 01 - 01)));
+        assert!(!set.remove(date!(M021 - 01 - 01)));
+
+        let mut dates = set.iter();
+        assert_eq!(dates.next(), Some(date!(M021 - 06 - 29)));
+        assert_eq!(dates.next_back(), Some(date!(M021 - 13 - 29)));
+        assert_eq!(dates.next(), None);
+
+        let other = YearDateSet::new(year!(M022));
+        assert_eq!(set.union(&other), None);
+        assert_eq!(set.difference(&set).map(|s| s.is_empty()), Some(true));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn date_set_drops_empty_years() {
+        let mut set: DateSet = [date!(M021 - 05 - 05), date!(M020 - 05 - 05)]
+            .into_iter()
+            .collect();
+
+        assert!(set.remove(date!(M021 - 05 - 05)));
+        assert_eq!(set.year(year!(M021)), None);
+
+        let mut other = DateSet::new();
+        other.insert(date!(M020 - 05 - 06));
+
+        assert!(set.intersection(&other).is_empty());
+        assert_eq!(set.difference(&other), set);
+        assert_eq!(
+            set.union(&other).iter().next_back(),
+            Some(date!(M020 - 05 - 06))
+        );
+    }
+}
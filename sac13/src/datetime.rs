@@ -0,0 +1,398 @@
+use core::fmt::Display;
+
+use crate::{
+    Date, GregorianDate, ParseError, ParseErrorKind,
+    day_counts::Sac13Day,
+    parse::{CALENDAR_ANNOTATION, strip_calendar_annotation},
+    traits::CalendarDate,
+};
+
+/// A SAC13 [`Date`] combined with a time of day in whole seconds.
+///
+/// There are no time zones: the time is just the number of seconds since midnight
+/// of whatever time scale the application uses (typically UTC).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateTime, date};
+///
+/// let dt = DateTime::new(date!(M020 - 05 - 21), 13 * 3600 + 30 * 60).unwrap();
+///
+/// assert_eq!(dt.date(), date!(M020 - 05 - 21));
+/// assert_eq!((dt.hour(), dt.minute(), dt.second()), (13, 30, 0));
+/// assert_eq!(format!("{dt}"), "M020-05-21T13:30:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    date: Date,
+    seconds: u32,
+}
+
+impl DateTime {
+    /// Number of seconds per day.
+    pub const SECONDS_PER_DAY: u32 = 86_400;
+
+    /// Date and seconds since midnight.
+    ///
+    /// Returns `None` if `seconds` is not less than [`SECONDS_PER_DAY`](Self::SECONDS_PER_DAY).
+    #[must_use]
+    pub const fn new(date: Date, seconds: u32) -> Option<Self> {
+        if seconds < Self::SECONDS_PER_DAY {
+            Some(Self { date, seconds })
+        } else {
+            None
+        }
+    }
+
+    /// Date and hour, minute and second of the day.
+    ///
+    /// Returns `None` if a component is out of range.
+    #[must_use]
+    pub const fn from_hms(date: Date, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour >= 24 || minute >= 60 || second >= 60 {
+            return None;
+        }
+
+        Self::new(
+            date,
+            hour as u32 * 3600 + minute as u32 * 60 + second as u32,
+        )
+    }
+
+    /// The date.
+    #[must_use]
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Seconds since midnight.
+    #[must_use]
+    pub const fn seconds_of_day(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Hour of the day (0-23).
+    #[must_use]
+    pub const fn hour(&self) -> u8 {
+        #[allow(clippy::cast_possible_truncation)] // less than 86400 seconds, so at most 23
+        let hour = (self.seconds / 3600) as u8;
+        hour
+    }
+
+    /// Minute of the hour (0-59).
+    #[must_use]
+    pub const fn minute(&self) -> u8 {
+        (self.seconds / 60 % 60) as u8
+    }
+
+    /// Second of the minute (0-59).
+    #[must_use]
+    pub const fn second(&self) -> u8 {
+        (self.seconds % 60) as u8
+    }
+
+    /// Packs the date and time into a single integer, e.g. as a key for time-series storage.
+    ///
+    /// The [SAC13 Day Number](Sac13Day) is stored in the upper 32 bits and the seconds
+    /// of the day in the lower 32 bits, so the packed values sort in the same order as
+    /// the dates and times they represent.
+    ///
+    /// ```
+    /// use sac13::{DateTime, date};
+    ///
+    /// let a = DateTime::new(date!(M020 - 05 - 21), 86_399).unwrap();
+    /// let b = DateTime::new(date!(M020 - 05 - 22), 0).unwrap();
+    ///
+    /// assert!(a.to_packed_u64() < b.to_packed_u64());
+    /// assert_eq!(DateTime::from_packed_u64(a.to_packed_u64()), Some(a));
+    /// ```
+    #[must_use]
+    pub const fn to_packed_u64(&self) -> u64 {
+        ((self.date.to_sac13_day().value() as u64) << 32) | self.seconds as u64
+    }
+
+    /// Unpacks a value created by [`to_packed_u64()`](Self::to_packed_u64()).
+    ///
+    /// Returns `None` if the day or the seconds are out of range.
+    #[must_use]
+    pub const fn from_packed_u64(packed: u64) -> Option<Self> {
+        let day = ok!(Sac13Day::new((packed >> 32) as u32));
+
+        #[allow(clippy::cast_possible_truncation)] // masked to the lower 32 bits
+        let seconds = (packed & 0xFFFF_FFFF) as u32;
+
+        Self::new(day.to_date(), seconds)
+    }
+
+    /// The date and time like its [`Display`] output (e.g. `M020-05-21T13:30:00`),
+    /// but without the formatting machinery.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// use sac13::{DateTime, date};
+    ///
+    /// let dt = DateTime::from_hms(date!(M020 - 05 - 21), 13, 30, 0).unwrap();
+    /// assert_eq!(dt.to_canonical_string(), dt.to_string());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_canonical_string(&self) -> alloc::string::String {
+        let mut text = self.date.to_canonical_string();
+        text.reserve_exact(9);
+
+        for (separator, value) in [
+            ('T', self.hour()),
+            (':', self.minute()),
+            (':', self.second()),
+        ] {
+            text.push(separator);
+            text.push(char::from(b'0' + value / 10));
+            text.push(char::from(b'0' + value % 10));
+        }
+
+        text
+    }
+
+    /// Displays the date and time as a calendar-annotated Gregorian date and time
+    /// (e.g. `2020-03-21T13:30:00[u-ca=sac13]`), see [`Date::to_ixdtf()`].
+    ///
+    /// ```
+    /// use sac13::{DateTime, date};
+    ///
+    /// let dt = DateTime::from_hms(date!(M020 - 01 - 02), 13, 30, 0).unwrap();
+    /// let text = dt.to_ixdtf().to_string();
+    ///
+    /// assert_eq!(text, "2020-03-21T13:30:00[u-ca=sac13]");
+    /// assert_eq!(DateTime::from_ixdtf(&text), Ok(dt));
+    /// ```
+    #[must_use]
+    pub fn to_ixdtf(&self) -> impl Display + use<> {
+        struct Ixdtf(GregorianDate, u8, u8, u8);
+
+        impl Display for Ixdtf {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let Self(date, hour, minute, second) = self;
+
+                write!(
+                    f,
+                    "{}T{hour:02}:{minute:02}:{second:02}{CALENDAR_ANNOTATION}",
+                    date.to_iso8601()
+                )
+            }
+        }
+
+        Ixdtf(
+            self.date.convert(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+        )
+    }
+
+    /// Parses the format of [`to_ixdtf()`](Self::to_ixdtf()) (e.g. `2020-03-21T13:30:00[u-ca=sac13]`).
+    ///
+    /// The time must have exactly the form `HH:MM:SS`. The annotations are handled
+    /// like by [`Date::from_ixdtf()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] that points to the first offending part of the input.
+    pub fn from_ixdtf(input: &str) -> Result<Self, ParseError> {
+        let datetime = strip_calendar_annotation(input)?;
+
+        let Some(t) = datetime.find('T') else {
+            return Err(ParseError::new(
+                ParseErrorKind::MissingComponent,
+                datetime.len()..datetime.len(),
+            ));
+        };
+
+        let date: Date = GregorianDate::from_iso8601(&datetime[..t])?.convert();
+        let time = &datetime.as_bytes()[t + 1..];
+        let span = t + 1..datetime.len();
+
+        let [h0, h1, b':', m0, m1, b':', s0, s1] = *time else {
+            return Err(ParseError::new(ParseErrorKind::InvalidTime, span));
+        };
+
+        let number = |d0: u8, d1: u8| {
+            (d0.is_ascii_digit() && d1.is_ascii_digit()).then(|| (d0 - b'0') * 10 + (d1 - b'0'))
+        };
+
+        match (number(h0, h1), number(m0, m1), number(s0, s1)) {
+            (Some(hour), Some(minute), Some(second)) => Self::from_hms(date, hour, minute, second),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidTime, span))
+    }
+}
+
+impl Display for DateTime {
+    /// Displays the date and time like `M020-05-21T13:30:00`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date,
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod valuable_impl {
+    use valuable::{
+        Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit,
+    };
+
+    use super::DateTime;
+
+    static FIELDS: &[NamedField<'static>] = &[
+        NamedField::new("date"),
+        NamedField::new("hour"),
+        NamedField::new("minute"),
+        NamedField::new("second"),
+    ];
+
+    impl Valuable for DateTime {
+        fn as_value(&self) -> Value<'_> {
+            Value::Structable(self)
+        }
+
+        /// Visits the [`Date`](crate::Date) (as nested structure) and the time of day.
+        fn visit(&self, visit: &mut dyn Visit) {
+            visit.visit_named_fields(&NamedValues::new(
+                FIELDS,
+                &[
+                    self.date.as_value(),
+                    Value::U8(self.hour()),
+                    Value::U8(self.minute()),
+                    Value::U8(self.second()),
+                ],
+            ));
+        }
+    }
+
+    impl Structable for DateTime {
+        fn definition(&self) -> StructDef<'_> {
+            StructDef::new_static("DateTime", Fields::Named(FIELDS))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packing_round_trip_and_order() {
+        let values = [
+            DateTime::new(Date::MIN, 0).unwrap(),
+            DateTime::from_hms(date!(M020 - 13 - 29), 23, 59, 59).unwrap(),
+            DateTime::new(date!(M021 - 01 - 01), 0).unwrap(),
+            DateTime::new(Date::MAX, DateTime::SECONDS_PER_DAY - 1).unwrap(),
+        ];
+
+        for pair in values.windows(2) {
+            assert!(pair[0].to_packed_u64() < pair[1].to_packed_u64());
+        }
+
+        for value in values {
+            assert_eq!(
+                DateTime::from_packed_u64(value.to_packed_u64()),
+                Some(value)
+            );
+        }
+
+        assert_eq!(DateTime::new(Date::MIN, DateTime::SECONDS_PER_DAY), None);
+        assert_eq!(DateTime::from_packed_u64(86_400), None);
+        assert_eq!(DateTime::from_packed_u64(u64::MAX), None);
+    }
+
+    #[test]
+    fn ixdtf_annotations() {
+        let dt = DateTime::from_hms(date!(M020 - 01 - 02), 13, 30, 0).unwrap();
+
+        assert_eq!(
+            DateTime::from_ixdtf("2020-03-21T13:30:00[u-ca=sac13][foo=bar]"),
+            Ok(dt)
+        );
+        assert_eq!(
+            DateTime::from_ixdtf("2020-03-21T13:30:00[UTC][u-ca=SAC13]"),
+            Ok(dt)
+        );
+
+        for (input, kind, span) in [
+            (
+                "2020-03-21T13:30:00[u-ca=sac13][!foo=bar]",
+                ParseErrorKind::InvalidAnnotation,
+                31..41,
+            ),
+            (
+                "2020-03-21T13:30:00[u-ca=gregory]",
+                ParseErrorKind::InvalidAnnotation,
+                19..33,
+            ),
+            (
+                "2020-03-21T13:30:00[u-ca=sac13][UTC]",
+                ParseErrorKind::InvalidAnnotation,
+                31..36,
+            ),
+            (
+                "2020-03-21T13:30:00[u-ca=sac13",
+                ParseErrorKind::InvalidAnnotation,
+                19..30,
+            ),
+            (
+                "2020-03-21T13:30:00[u-ca=sac13]x",
+                ParseErrorKind::InvalidAnnotation,
+                31..32,
+            ),
+            (
+                "2020-03-21T13:30:00[foo=bar]",
+                ParseErrorKind::InvalidAnnotation,
+                19..28,
+            ),
+            (
+                "2020-03-21T24:00:00[u-ca=sac13]",
+                ParseErrorKind::InvalidTime,
+                11..19,
+            ),
+            (
+                "2020-03-21T13:30[u-ca=sac13]",
+                ParseErrorKind::InvalidTime,
+                11..16,
+            ),
+            (
+                "2020-03-21[u-ca=sac13]",
+                ParseErrorKind::MissingComponent,
+                10..10,
+            ),
+            (
+                "2020-02-30T13:30:00[u-ca=sac13]",
+                ParseErrorKind::InvalidDay,
+                8..10,
+            ),
+        ] {
+            let error = DateTime::from_ixdtf(input).unwrap_err();
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn valuable_structure() {
+        use valuable::Valuable;
+
+        let dt = DateTime::from_hms(date!(M020 - 05 - 03), 7, 8, 9).unwrap();
+
+        assert_eq!(
+            format!("{:?}", dt.as_value()),
+            r#"DateTime { date: Date { year: 12020, month: 5, day: 3, text: "M020-05-03" }, hour: 7, minute: 8, second: 9 }"#
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use core::fmt::Display;
+
+/// Day of a SAC13 month (1 - 29).
+///
+/// Unlike a plain `u8`, it can't be confused with a month ordinal when passed to
+/// [`Date::from_ymd_typed()`](crate::Date::from_ymd_typed()). Day 29 only exists in
+/// months with a synchronization day, which is checked when the date is created.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Date, DayOfMonth, Month, date, year};
+///
+/// const DAY: DayOfMonth = DayOfMonth::new(16).unwrap();
+///
+/// assert_eq!(
+///     Date::from_ymd_typed(year!(M020), Month::May, DAY),
+///     Some(date!(M020 - 03 - 16))
+/// );
+/// assert_eq!(date!(M020 - 03 - 16).day_of_month(), DAY);
+/// assert_eq!(DayOfMonth::new(30), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayOfMonth(u8);
+
+impl DayOfMonth {
+    /// The first day of every month.
+    pub const MIN: Self = Self(1);
+
+    /// The synchronization day (the leap day or the year day).
+    pub const MAX: Self = Self(29);
+
+    /// Day of the month from its number (1 - 29).
+    ///
+    /// Returns `None` for invalid days.
+    #[must_use]
+    pub const fn new(day: u8) -> Option<Self> {
+        if day == 0 || day > 29 {
+            None
+        } else {
+            Some(Self(day))
+        }
+    }
+
+    /// Returns the number of the day (1 - 29).
+    #[must_use]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` for day 29, which only exists in months with a synchronization day.
+    #[must_use]
+    pub const fn is_sync_day(self) -> bool {
+        self.0 == 29
+    }
+}
+
+impl Display for DayOfMonth {
+    /// Displays the day zero-padded to two digits, like in dates.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl From<DayOfMonth> for u8 {
+    fn from(value: DayOfMonth) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u8> for DayOfMonth {
+    type Error = ();
+
+    /// Same as [`DayOfMonth::new()`].
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(())
+    }
+}
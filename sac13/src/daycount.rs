@@ -0,0 +1,137 @@
+//! Day-count conventions for accrual calculations, e.g. interest or fees.
+//!
+//! Each convention defines how many days are between two dates and how long a year is.
+//! All functions take the start and the end of the accrual period and return a negative
+//! result if the end is before the start.
+//!
+//! - [`act_365_fixed()`]: actual days, every year has 365 days.
+//! - [`act_act()`]: actual days, each counted as fraction of the year it belongs to (365 or 366 days).
+//! - [`sac_28_364()`]: the SAC13-native convention, every month has 28 days and every year 364,
+//!   so synchronization days (the 29th) don't accrue. The counterpart of the Gregorian 30/360.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::date;
+//! use sac13::daycount::{Convention, act_365_fixed, sac_28_364};
+//!
+//! let start = date!(M020 - 13 - 15);
+//! let end = date!(M021 - 01 - 15); // one month later, across the year day
+//!
+//! assert_eq!(act_365_fixed(start, end), 29.0 / 365.0);
+//! assert_eq!(sac_28_364(start, end), 28.0 / 364.0);
+//! assert_eq!(Convention::Sac28_364.year_fraction(start, end), 1.0 / 13.0);
+//! ```
+
+use crate::{Date, traits::CalendarDate};
+
+/// A day-count convention, e.g. from the terms of a contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Convention {
+    /// See [`act_365_fixed()`].
+    Act365Fixed,
+
+    /// See [`act_act()`].
+    ActAct,
+
+    /// See [`sac_28_364()`].
+    Sac28_364,
+}
+
+impl Convention {
+    /// Number of days between the dates according to the convention.
+    #[must_use]
+    pub fn day_count(self, start: Date, end: Date) -> i32 {
+        match self {
+            Self::Act365Fixed | Self::ActAct => actual_days(start, end),
+            Self::Sac28_364 => days_28_364(start, end),
+        }
+    }
+
+    /// Length of the period between the dates in years according to the convention.
+    #[must_use]
+    pub fn year_fraction(self, start: Date, end: Date) -> f64 {
+        match self {
+            Self::Act365Fixed => act_365_fixed(start, end),
+            Self::ActAct => act_act(start, end),
+            Self::Sac28_364 => sac_28_364(start, end),
+        }
+    }
+}
+
+/// Actual number of days between the dates.
+#[must_use]
+pub fn actual_days(start: Date, end: Date) -> i32 {
+    end.as_julian() - start.as_julian()
+}
+
+/// ACT/365 Fixed: the actual number of days divided by 365.
+#[must_use]
+pub fn act_365_fixed(start: Date, end: Date) -> f64 {
+    f64::from(actual_days(start, end)) / 365.0
+}
+
+/// ACT/ACT: every day counts as the fraction of the year it belongs to (1/365, or 1/366 on leap years).
+///
+/// Same as [`Date::days_between_fraction_of_year()`].
+#[must_use]
+pub fn act_act(start: Date, end: Date) -> f64 {
+    Date::days_between_fraction_of_year(start, end)
+}
+
+/// Number of days between the dates, if every month had 28 days.
+///
+/// The 29th (synchronization days) counts as the 28th, so the period from
+/// the 28th to the 29th has no days, and neither does the period across a synchronization day.
+#[must_use]
+pub fn days_28_364(start: Date, end: Date) -> i32 {
+    let days = |date: Date| {
+        i32::from(date.year().value()) * 364
+            + (i32::from(date.month().ord()) - 1) * 28
+            + i32::from(date.day().min(28))
+    };
+
+    days(end) - days(start)
+}
+
+/// 28/364: the number of days as if every month had 28 days (see [`days_28_364()`]) divided by 364.
+///
+/// A month is always exactly 1/13 year and a year is exactly 1.
+#[must_use]
+pub fn sac_28_364(start: Date, end: Date) -> f64 {
+    f64::from(days_28_364(start, end)) / 364.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn conventions() {
+        // M021 is a leap year
+        let start = date!(M021 - 01 - 01);
+        let end = date!(M022 - 01 - 01);
+
+        assert_eq!(actual_days(start, end), 366);
+        assert_eq!(days_28_364(start, end), 364);
+
+        assert_eq!(act_365_fixed(start, end), 366.0 / 365.0);
+        assert_eq!(act_act(start, end), 1.0);
+        assert_eq!(sac_28_364(start, end), 1.0);
+        assert_eq!(sac_28_364(end, start), -1.0);
+
+        // synchronization days don't accrue
+        assert_eq!(days_28_364(date!(M021 - 06 - 28), date!(M021 - 06 - 29)), 0);
+        assert_eq!(days_28_364(date!(M021 - 06 - 29), date!(M021 - 07 - 01)), 1);
+
+        for convention in [
+            Convention::Act365Fixed,
+            Convention::ActAct,
+            Convention::Sac28_364,
+        ] {
+            assert_eq!(convention.day_count(start, start), 0);
+            assert_eq!(convention.year_fraction(start, start), 0.0);
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use core::{cmp::Ordering, fmt::Display, hash::Hash};
+
+use crate::{Date, GregorianDate, GregorianOrSac13};
+
+/// A day with both its SAC13 and its Gregorian date, converted once on construction.
+///
+/// Meant for records that are rendered in both calendars, e.g. during a transition period.
+/// The calendar the date was created from is kept, so it can be shown first
+/// and stored back in its original form.
+///
+/// Equality, ordering and hashing only consider the day, not the original calendar.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Dual, date, date_greg};
+///
+/// let from_sac13 = Dual::from_sac13(date!(M024 - 03 - 07));
+/// let from_gregorian = Dual::from_gregorian(date_greg!(2024 - 05 - 21));
+///
+/// assert_eq!(from_sac13, from_gregorian);
+/// assert_eq!(from_gregorian.sac13(), date!(M024 - 03 - 07));
+///
+/// assert_eq!(from_sac13.to_string(), "M024-03-07 (2024-05-21)");
+/// assert_eq!(from_gregorian.to_string(), "2024-05-21 (M024-03-07)");
+/// assert_eq!(from_gregorian.display_sac13().to_string(), "M024-03-07");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Dual {
+    sac13: Date,
+    gregorian: GregorianDate,
+    from_gregorian: bool,
+}
+
+impl Dual {
+    /// The day of the SAC13 date.
+    #[must_use]
+    pub const fn from_sac13(date: Date) -> Self {
+        Self {
+            sac13: date,
+            gregorian: GregorianDate::from_sac13(date),
+            from_gregorian: false,
+        }
+    }
+
+    /// The day of the Gregorian date.
+    #[must_use]
+    pub const fn from_gregorian(date: GregorianDate) -> Self {
+        // every Gregorian date has a SAC13 date
+        let Some(sac13) = Date::from_gregorian_ymd(date.year(), date.month(), date.day()) else {
+            unreachable!()
+        };
+
+        Self {
+            sac13,
+            gregorian: date,
+            from_gregorian: true,
+        }
+    }
+
+    /// The SAC13 date.
+    #[must_use]
+    pub const fn sac13(&self) -> Date {
+        self.sac13
+    }
+
+    /// The Gregorian date.
+    #[must_use]
+    pub const fn gregorian(&self) -> GregorianDate {
+        self.gregorian
+    }
+
+    /// Whether the date was created from a Gregorian date.
+    #[must_use]
+    pub const fn is_from_gregorian(&self) -> bool {
+        self.from_gregorian
+    }
+
+    /// The date in the calendar it was created from.
+    #[must_use]
+    pub const fn original(&self) -> GregorianOrSac13 {
+        if self.from_gregorian {
+            GregorianOrSac13::GregorianDate(self.gregorian)
+        } else {
+            GregorianOrSac13::Sac13Date(self.sac13)
+        }
+    }
+
+    /// Displays only the SAC13 date (e.g. `M024-03-07`).
+    #[must_use]
+    pub const fn display_sac13(&self) -> impl Display + use<> {
+        self.sac13
+    }
+
+    /// Displays only the Gregorian date (e.g. `2024-05-21`).
+    #[must_use]
+    pub const fn display_gregorian(&self) -> impl Display + use<> {
+        self.gregorian
+    }
+}
+
+impl Display for Dual {
+    /// Displays the date in the original calendar, followed by the other one in
+    /// parentheses, e.g. `M024-03-07 (2024-05-21)`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.from_gregorian {
+            write!(f, "{} ({})", self.gregorian, self.sac13)
+        } else {
+            write!(f, "{} ({})", self.sac13, self.gregorian)
+        }
+    }
+}
+
+impl From<Date> for Dual {
+    fn from(date: Date) -> Self {
+        Self::from_sac13(date)
+    }
+}
+
+impl From<GregorianDate> for Dual {
+    fn from(date: GregorianDate) -> Self {
+        Self::from_gregorian(date)
+    }
+}
+
+impl From<Dual> for Date {
+    fn from(dual: Dual) -> Self {
+        dual.sac13
+    }
+}
+
+impl From<Dual> for GregorianDate {
+    fn from(dual: Dual) -> Self {
+        dual.gregorian
+    }
+}
+
+impl PartialEq for Dual {
+    fn eq(&self, other: &Self) -> bool {
+        self.sac13 == other.sac13
+    }
+}
+
+impl Eq for Dual {}
+
+impl PartialOrd for Dual {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Dual {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sac13.cmp(&other.sac13)
+    }
+}
+
+impl Hash for Dual {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.sac13.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CalendarDate;
+
+    #[test]
+    fn both_calendars_match_convert() {
+        for date in [
+            Date::MIN,
+            Date::MAX,
+            date!(M021 - 06 - 29),
+            date!(M020 - 13 - 29),
+        ] {
+            let gregorian: GregorianDate = date.convert();
+            let from_sac13 = Dual::from(date);
+            let from_gregorian = Dual::from(gregorian);
+
+            assert_eq!(from_sac13.gregorian(), gregorian);
+            assert_eq!(from_gregorian.sac13(), date);
+            assert!(!from_sac13.is_from_gregorian());
+            assert!(from_gregorian.is_from_gregorian());
+            assert!(matches!(from_sac13.original(), GregorianOrSac13::Sac13Date(d) if d == date));
+            assert!(matches!(
+                from_gregorian.original(),
+                GregorianOrSac13::GregorianDate(d) if d == gregorian
+            ));
+            assert_eq!(Date::from(from_gregorian), date);
+        }
+
+        assert!(Dual::from(Date::MIN) < Dual::from(GregorianDate::MAX));
+        assert_eq!(
+            format!("{}", Dual::from(GregorianDate::MIN)),
+            "-10000-03-22 (A000-01-01)"
+        );
+    }
+}
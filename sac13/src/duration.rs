@@ -0,0 +1,259 @@
+use core::fmt::Display;
+
+/// A nominal calendar duration consisting of years, months, weeks and days.
+///
+/// Unlike a plain day count the length of a calendar duration depends on the date it's
+/// applied to. [`Date::checked_add()`](crate::Date::checked_add()) applies the components
+/// in the following order:
+///
+/// 1. Years are added, keeping month and day.
+/// 2. Months are added, keeping the day. In SAC13 every month has 28 days,
+///    except Addenduary and August on leap years, which have 29.
+/// 3. Weeks (7 days each) and days are added as a linear day count.
+///
+/// If the day doesn't exist after step 1 or 2 (the 29th of a 28-day month)
+/// it's clamped to the last day of the month (the 28th).
+///
+/// # Text format
+///
+/// Durations can be parsed from and displayed as [ISO 8601 durations](https://en.wikipedia.org/wiki/ISO_8601#Durations)
+/// of the form `PnYnMnWnD`. Components that are zero are omitted, and the zero duration is `P0D`.
+/// Time components (like `PT1H`), fractions and negative values are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{CalendarDuration, date};
+///
+/// let retention = CalendarDuration::parse("P1Y2M10D").unwrap();
+///
+/// assert_eq!(retention, CalendarDuration::new(1, 2, 0, 10));
+/// assert_eq!(format!("{retention}"), "P1Y2M10D");
+///
+/// assert_eq!(
+///     date!(M020 - 01 - 01).checked_add(retention),
+///     Some(date!(M021 - 03 - 11))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CalendarDuration {
+    years: u32,
+    months: u32,
+    weeks: u32,
+    days: u32,
+}
+
+impl CalendarDuration {
+    /// The zero duration.
+    pub const ZERO: Self = Self::new(0, 0, 0, 0);
+
+    /// Duration from its components.
+    #[must_use]
+    pub const fn new(years: u32, months: u32, weeks: u32, days: u32) -> Self {
+        Self {
+            years,
+            months,
+            weeks,
+            days,
+        }
+    }
+
+    /// Duration of the given number of years.
+    #[must_use]
+    pub const fn from_years(years: u32) -> Self {
+        Self::new(years, 0, 0, 0)
+    }
+
+    /// Duration of the given number of months.
+    #[must_use]
+    pub const fn from_months(months: u32) -> Self {
+        Self::new(0, months, 0, 0)
+    }
+
+    /// Duration of the given number of weeks.
+    #[must_use]
+    pub const fn from_weeks(weeks: u32) -> Self {
+        Self::new(0, 0, weeks, 0)
+    }
+
+    /// Duration of the given number of days.
+    #[must_use]
+    pub const fn from_days(days: u32) -> Self {
+        Self::new(0, 0, 0, days)
+    }
+
+    /// Years component.
+    #[must_use]
+    pub const fn years(&self) -> u32 {
+        self.years
+    }
+
+    /// Months component.
+    #[must_use]
+    pub const fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Weeks component.
+    #[must_use]
+    pub const fn weeks(&self) -> u32 {
+        self.weeks
+    }
+
+    /// Days component.
+    #[must_use]
+    pub const fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Returns `true` if all components are zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.weeks == 0 && self.days == 0
+    }
+
+    /// Parses an ISO 8601 duration like `P1Y2M10D` or `P3W`.
+    ///
+    /// The designators must appear in the order `Y`, `M`, `W`, `D` and each at most once.
+    /// Returns `None` for invalid or unsupported input.
+    #[must_use]
+    pub const fn parse(input: &str) -> Option<Self> {
+        const DESIGNATORS: [u8; 4] = [b'Y', b'M', b'W', b'D'];
+
+        let bytes = input.as_bytes();
+
+        if bytes.len() < 3 || bytes[0] != b'P' {
+            return None;
+        }
+
+        let mut components = [0u32; 4];
+
+        // index of the next designator that is allowed to appear
+        let mut next = 0;
+
+        let mut i = 1;
+        let mut value: u32 = 0;
+        let mut digits = 0;
+
+        while i < bytes.len() {
+            let b = bytes[i];
+
+            if b.is_ascii_digit() {
+                value = ok!(value.checked_mul(10));
+                value = ok!(value.checked_add((b - b'0') as u32));
+                digits += 1;
+            } else {
+                if digits == 0 {
+                    return None;
+                }
+
+                while next < DESIGNATORS.len() && DESIGNATORS[next] != b {
+                    next += 1;
+                }
+
+                if next == DESIGNATORS.len() {
+                    // unknown, repeated or out of order designator
+                    return None;
+                }
+
+                components[next] = value;
+                next += 1;
+
+                value = 0;
+                digits = 0;
+            }
+
+            i += 1;
+        }
+
+        if digits != 0 {
+            // trailing number without designator
+            return None;
+        }
+
+        Some(Self::new(
+            components[0],
+            components[1],
+            components[2],
+            components[3],
+        ))
+    }
+}
+
+impl Display for CalendarDuration {
+    /// Displays the duration in the ISO 8601 format (e.g. `P1Y2M10D`).
+    ///
+    /// ```
+    /// use sac13::CalendarDuration;
+    ///
+    /// assert_eq!(format!("{}", CalendarDuration::from_weeks(3)), "P3W");
+    /// assert_eq!(format!("{}", CalendarDuration::ZERO), "P0D");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "P0D");
+        }
+
+        write!(f, "P")?;
+
+        for (value, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_durations() {
+        assert_eq!(
+            CalendarDuration::parse("P1Y"),
+            Some(CalendarDuration::from_years(1))
+        );
+        assert_eq!(CalendarDuration::parse("P0D"), Some(CalendarDuration::ZERO));
+        assert_eq!(
+            CalendarDuration::parse("P2M3W"),
+            Some(CalendarDuration::new(0, 2, 3, 0))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_durations() {
+        for input in [
+            "",
+            "P",
+            "PY",
+            "1Y",
+            "P1",
+            "P1D1Y",
+            "P1Y1Y",
+            "PT1H",
+            "P1.5Y",
+            "P-1D",
+            "p1d",
+            "P99999999999D",
+        ] {
+            assert_eq!(CalendarDuration::parse(input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn display_parse_round_trip() {
+        let d = CalendarDuration::new(12, 0, 7, 1);
+        let s = format!("{d}");
+
+        assert_eq!(s, "P12Y7W1D");
+        assert_eq!(CalendarDuration::parse(&s), Some(d));
+    }
+}
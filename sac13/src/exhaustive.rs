@@ -0,0 +1,264 @@
+//! Exhaustive conformance sweep over every day of the SAC13 range.
+//!
+//! The sweep converts every Julian Day Number between [`JulianDay::MIN`] and [`JulianDay::MAX`]
+//! into all calendar types of this crate and checks that the conversions and the day-by-day
+//! navigation agree with each other. It's the same check the crate runs in its own test suite.
+//!
+//! Bindings (FFI, wasm, ...) can pass a closure that compares their wrapper against the
+//! [`Reference`] values of each day, and split the work into chunks to report progress or
+//! to distribute it over multiple threads.
+//!
+//! ```
+//! use core::num::NonZeroU32;
+//! use sac13::{exhaustive, prelude::*};
+//!
+//! // a wrapper that should behave like `Date::day()`
+//! let wrapper_day = |julian_day: i32| Date::from_julian(julian_day).map(|d| d.day());
+//!
+//! // only the first chunk, the whole range takes a few seconds
+//! let first = exhaustive::chunks(NonZeroU32::new(10_000).unwrap()).next().unwrap();
+//!
+//! let result = exhaustive::check_range(first, |reference| {
+//!     if wrapper_day(reference.julian_day.value()) == Some(reference.date.day()) {
+//!         Ok(())
+//!     } else {
+//!         Err("day of month differs")
+//!     }
+//! });
+//!
+//! assert_eq!(result, Ok(()));
+//! ```
+
+use core::{fmt::Display, iter::FusedIterator, num::NonZeroU32, ops::RangeInclusive};
+
+use crate::{
+    Date, GregorianDate, Weekday,
+    date_sac13::raw_date::YearOrdinal,
+    scalars::{JulianDay, UnixDay},
+    traits::CalendarDate,
+};
+
+/// Number of days in the SAC13 range (the number of checks of a full sweep).
+pub const TOTAL_DAYS: u32 = (JulianDay::MAX_INT - JulianDay::MIN_INT + 1).cast_unsigned();
+
+/// The values this crate computes for a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reference {
+    pub julian_day: JulianDay,
+    pub unix_day: UnixDay,
+    pub date: Date,
+    pub gregorian: GregorianDate,
+    pub weekday: Weekday,
+}
+
+/// Progress of a sweep, reported after each chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progress {
+    /// Number of days checked so far.
+    pub checked: u32,
+
+    /// Number of days of the whole sweep ([`TOTAL_DAYS`]).
+    pub total: u32,
+}
+
+/// The first day that failed a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mismatch {
+    /// Julian Day Number of the day.
+    pub julian_day: i32,
+
+    /// Description of the failed check.
+    pub reason: &'static str,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "JD {}: {}", self.julian_day, self.reason)
+    }
+}
+
+impl core::error::Error for Mismatch {}
+
+/// Splits the range of Julian Day Numbers into consecutive chunks of (at most) `size` days.
+///
+/// The chunks can be checked independently with [`check_range`], e.g. on different threads.
+#[must_use]
+pub fn chunks(
+    size: NonZeroU32,
+) -> impl ExactSizeIterator<Item = RangeInclusive<i32>> + DoubleEndedIterator + FusedIterator {
+    let size = size.get();
+
+    (0..TOTAL_DAYS.div_ceil(size)).map(move |i| {
+        let first = i * size;
+        let last = (first + (size - 1)).min(TOTAL_DAYS - 1);
+
+        let first = JulianDay::MIN_INT + first.cast_signed();
+        let last = JulianDay::MIN_INT + last.cast_signed();
+
+        first..=last
+    })
+}
+
+/// Checks the conversions of a single day and returns its reference values.
+///
+/// # Errors
+///
+/// Returns a [`Mismatch`] if the day is out of range or the calendar types don't agree.
+pub fn check_day(julian_day: i32) -> Result<Reference, Mismatch> {
+    let mismatch = |reason| Mismatch { julian_day, reason };
+
+    let (Some(jd), Some(unix_day), Some(date), Some(gregorian)) = (
+        JulianDay::new(julian_day),
+        UnixDay::from_julian(julian_day),
+        Date::from_julian(julian_day),
+        GregorianDate::from_julian(julian_day),
+    ) else {
+        return Err(mismatch("day is out of range"));
+    };
+
+    if date.as_julian() != julian_day || gregorian.as_julian() != julian_day {
+        return Err(mismatch("date doesn't convert back to the same day"));
+    }
+
+    if date.to_julian_day() != jd
+        || date.to_unix_day() != unix_day
+        || Date::from_gregorian_ymd(gregorian.year(), gregorian.month(), gregorian.day())
+            != Some(date)
+        || GregorianDate::from_sac13(date) != gregorian
+    {
+        return Err(mismatch("const conversion differs"));
+    }
+
+    let weekday = jd.weekday();
+
+    if date.weekday() != weekday || unix_day.weekday() != weekday {
+        return Err(mismatch("weekday differs"));
+    }
+
+    if julian_day == JulianDay::MIN_INT {
+        if date != Date::MIN || gregorian != GregorianDate::MIN || date.yesterday().is_some() {
+            return Err(mismatch("first day isn't the minimum"));
+        }
+    } else if JulianDay::from_julian(julian_day - 1).and_then(CalendarDate::tomorrow) != Some(jd)
+        || Date::from_julian(julian_day - 1).and_then(Date::tomorrow) != Some(date)
+        || YearOrdinal::from_julian(julian_day - 1).and_then(CalendarDate::tomorrow)
+            != Some(YearOrdinal::from_date(date))
+        || GregorianDate::from_julian(julian_day - 1).and_then(GregorianDate::tomorrow)
+            != Some(gregorian)
+    {
+        return Err(mismatch("tomorrow() of the previous day differs"));
+    }
+
+    if julian_day == JulianDay::MAX_INT {
+        if date != Date::MAX || gregorian != GregorianDate::MAX || date.tomorrow().is_some() {
+            return Err(mismatch("last day isn't the maximum"));
+        }
+    } else if JulianDay::from_julian(julian_day + 1).and_then(CalendarDate::yesterday) != Some(jd)
+        || Date::from_julian(julian_day + 1).and_then(Date::yesterday) != Some(date)
+        || YearOrdinal::from_julian(julian_day + 1).and_then(CalendarDate::yesterday)
+            != Some(YearOrdinal::from_date(date))
+        || GregorianDate::from_julian(julian_day + 1).and_then(GregorianDate::yesterday)
+            != Some(gregorian)
+    {
+        return Err(mismatch("yesterday() of the next day differs"));
+    }
+
+    Ok(Reference {
+        julian_day: jd,
+        unix_day,
+        date,
+        gregorian,
+        weekday,
+    })
+}
+
+/// Checks all days of the range with [`check_day`] and passes their reference values to `check`.
+///
+/// `check` returns the reason as error if the checked wrapper doesn't match the reference.
+///
+/// # Errors
+///
+/// Returns the first [`Mismatch`], either of this crate or of `check`.
+pub fn check_range<F>(days: RangeInclusive<i32>, mut check: F) -> Result<(), Mismatch>
+where
+    F: FnMut(&Reference) -> Result<(), &'static str>,
+{
+    for julian_day in days {
+        let reference = check_day(julian_day)?;
+        check(&reference).map_err(|reason| Mismatch { julian_day, reason })?;
+    }
+
+    Ok(())
+}
+
+/// Checks the whole SAC13 range in chunks of `chunk_size` days.
+///
+/// `progress` is called after each chunk.
+///
+/// # Errors
+///
+/// Returns the first [`Mismatch`], see [`check_range`].
+pub fn run<F, P>(chunk_size: NonZeroU32, mut check: F, mut progress: P) -> Result<(), Mismatch>
+where
+    F: FnMut(&Reference) -> Result<(), &'static str>,
+    P: FnMut(Progress),
+{
+    let mut checked = 0;
+
+    for chunk in chunks(chunk_size) {
+        let days = (chunk.end() - chunk.start() + 1).cast_unsigned();
+
+        check_range(chunk, &mut check)?;
+
+        checked += days;
+        progress(Progress {
+            checked,
+            total: TOTAL_DAYS,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_cover_the_range() {
+        let size = NonZeroU32::new(1_000_003).unwrap();
+        let mut expected_start = JulianDay::MIN_INT;
+
+        for chunk in chunks(size) {
+            assert_eq!(*chunk.start(), expected_start);
+            assert!(chunk.end() - chunk.start() < 1_000_003);
+            expected_start = chunk.end() + 1;
+        }
+
+        assert_eq!(expected_start, JulianDay::MAX_INT + 1);
+        assert_eq!(chunks(NonZeroU32::MAX).count(), 1);
+    }
+
+    #[test]
+    fn reports_wrapper_mismatches() {
+        let last = JulianDay::MAX_INT - 10..=JulianDay::MAX_INT;
+
+        let result = check_range(last, |reference| {
+            if reference.date == Date::MAX {
+                Err("wrapper broke")
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(
+            result,
+            Err(Mismatch {
+                julian_day: JulianDay::MAX_INT,
+                reason: "wrapper broke"
+            })
+        );
+
+        assert!(check_day(JulianDay::MAX_INT + 1).is_err());
+    }
+}
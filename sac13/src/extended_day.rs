@@ -0,0 +1,184 @@
+use core::fmt::Display;
+
+use crate::{
+    Date, GregorianDate,
+    date_sac13::raw_date::YearOrdinal,
+    scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay},
+    traits::CalendarDate,
+};
+
+/// Julian Day Number without the SAC13 range limits.
+///
+/// All other calendar types of this crate are limited to the SAC13 range (A000-01-01 to Z999-13-29).
+/// `ExtendedDay` can represent any day in the range of an [`i64`], so data from e.g.
+/// scientific timelines can be carried around and checked or [clamped](Self::clamp_into())
+/// as late as possible.
+///
+/// Every calendar type converts into an `ExtendedDay` with [`From`], and back with [`TryFrom`]
+/// which fails with [`DayOutOfRange`] if the day is outside the SAC13 range.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::day_counts::{DayOutOfRange, ExtendedDay};
+///
+/// let big_bang = ExtendedDay::new(-5_040_000_000_000);
+///
+/// assert_eq!(Date::try_from(big_bang), Err(DayOutOfRange::BeforeMin));
+/// assert_eq!(big_bang.clamp_into::<Date>(), Date::MIN);
+///
+/// let day = ExtendedDay::from(date!(M020 - 01 - 01));
+/// assert_eq!(Date::try_from(day), Ok(date!(M020 - 01 - 01)));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct ExtendedDay(i64);
+
+/// The day is outside the SAC13 range and can't be converted into a bounded calendar type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DayOutOfRange {
+    /// The day is before A000-01-01.
+    BeforeMin,
+
+    /// The day is after Z999-13-29.
+    AfterMax,
+}
+
+impl ExtendedDay {
+    /// Creates a new day from its Julian Day Number.
+    #[must_use]
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the Julian Day Number.
+    #[must_use]
+    pub const fn value(self) -> i64 {
+        self.0
+    }
+
+    /// Adds the given (possibly negative) number of days.
+    ///
+    /// Returns `None` on overflow.
+    #[must_use]
+    pub const fn checked_add_days(self, days: i64) -> Option<Self> {
+        Some(Self(ok!(self.0.checked_add(days))))
+    }
+
+    /// Returns `true` if the day is within the SAC13 range.
+    #[must_use]
+    pub fn is_in_range(self) -> bool {
+        self.try_into_calendar::<JulianDay>().is_ok()
+    }
+
+    /// Converts the day into a bounded calendar type.
+    ///
+    /// Same as the [`TryFrom`] implementations, but also works for user types that implement [`CalendarDate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns whether the day is before or after the SAC13 range.
+    pub fn try_into_calendar<T: CalendarDate>(self) -> Result<T, DayOutOfRange> {
+        let error = if self.0 < i64::from(JulianDay::MIN_INT) {
+            DayOutOfRange::BeforeMin
+        } else {
+            DayOutOfRange::AfterMax
+        };
+
+        i32::try_from(self.0)
+            .ok()
+            .and_then(T::from_julian)
+            .ok_or(error)
+    }
+
+    /// Converts the day into a bounded calendar type, clamping it to the SAC13 range.
+    #[must_use]
+    pub fn clamp_into<T: CalendarDate>(self) -> T {
+        match self.try_into_calendar() {
+            Ok(date) => date,
+            Err(DayOutOfRange::BeforeMin) => T::MIN,
+            Err(DayOutOfRange::AfterMax) => T::MAX,
+        }
+    }
+}
+
+impl Display for ExtendedDay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for DayOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BeforeMin => write!(f, "the day is before the SAC13 range (A000-01-01)"),
+            Self::AfterMax => write!(f, "the day is after the SAC13 range (Z999-13-29)"),
+        }
+    }
+}
+
+impl core::error::Error for DayOutOfRange {}
+
+macro_rules! extended_day_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for ExtendedDay {
+                fn from(value: $t) -> Self {
+                    Self(value.as_julian().into())
+                }
+            }
+
+            impl TryFrom<ExtendedDay> for $t {
+                type Error = DayOutOfRange;
+
+                fn try_from(value: ExtendedDay) -> Result<Self, Self::Error> {
+                    value.try_into_calendar()
+                }
+            }
+        )*
+    };
+}
+
+extended_day_conversions!(
+    Date,
+    GregorianDate,
+    YearOrdinal,
+    JulianDay,
+    UnixDay,
+    CycleEpochDay,
+    Sac13Day,
+    ExcelDay
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_limits() {
+        let min = ExtendedDay::from(Date::MIN);
+        let max = ExtendedDay::from(Date::MAX);
+
+        assert!(min.is_in_range() && max.is_in_range());
+        assert_eq!(UnixDay::try_from(min), Ok(UnixDay::MIN));
+        assert_eq!(Sac13Day::try_from(max), Ok(Sac13Day::MAX));
+        assert_eq!(ExcelDay::try_from(max), Ok(ExcelDay::MAX));
+
+        let before = min.checked_add_days(-1).unwrap();
+        let after = max.checked_add_days(1).unwrap();
+
+        assert!(!before.is_in_range() && !after.is_in_range());
+        assert_eq!(Date::try_from(before), Err(DayOutOfRange::BeforeMin));
+        assert_eq!(Date::try_from(after), Err(DayOutOfRange::AfterMax));
+        assert_eq!(after.clamp_into::<GregorianDate>(), GregorianDate::MAX);
+    }
+
+    #[test]
+    fn far_outside_i32() {
+        let far = ExtendedDay::new(i64::MAX);
+
+        assert_eq!(far.checked_add_days(1), None);
+        assert_eq!(JulianDay::try_from(far), Err(DayOutOfRange::AfterMax));
+        assert_eq!(ExtendedDay::new(i64::MIN).clamp_into::<Date>(), Date::MIN);
+    }
+}
@@ -0,0 +1,329 @@
+//! Dates in fixed-width records, like the flat files of legacy batch systems.
+//!
+//! A [`DateField`] describes where a date is in a record and how it's written.
+//! Fields that are wider than their format are padded with trailing spaces.
+//! Nothing is allocated, so it works without `alloc`.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::date;
+//! use sac13::fixedwidth::{DateField, FieldFormat, decode_fields};
+//!
+//! const BOOKED: DateField = DateField::new(6, 10, FieldFormat::Compact);
+//! const VALUE: DateField = DateField::new(16, 10, FieldFormat::GregorianCompact);
+//!
+//! let mut record = *b"ACC001M0240521  20240610  EUR";
+//! assert_eq!(
+//!     decode_fields(&record, [BOOKED, VALUE]),
+//!     Ok([date!(M024 - 05 - 21), date!(M024 - 03 - 27)])
+//! );
+//!
+//! VALUE.encode(date!(M024 - 03 - 28), &mut record).unwrap();
+//! assert_eq!(&record, b"ACC001M0240521  20240611  EUR");
+//! ```
+
+use core::fmt::Display;
+
+use crate::{Date, GregorianDate, Year};
+
+/// How a date is written in a [`DateField`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldFormat {
+    /// `M024-05-21` (10 bytes).
+    Canonical,
+
+    /// `M0240521` (8 bytes).
+    Compact,
+
+    /// The Gregorian date as `20240801` (8 bytes). Only years 0 to 9999 fit.
+    GregorianCompact,
+}
+
+impl FieldFormat {
+    /// Number of bytes of a date in this format, without padding.
+    #[must_use]
+    pub const fn width(self) -> usize {
+        match self {
+            Self::Canonical => 10,
+            Self::Compact | Self::GregorianCompact => 8,
+        }
+    }
+}
+
+/// Position and format of a date in a fixed-width record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateField {
+    /// Index of the first byte of the field.
+    pub offset: usize,
+
+    /// Number of bytes of the field, at least the [width](FieldFormat::width()) of the format.
+    pub width: usize,
+
+    /// How the date is written.
+    pub format: FieldFormat,
+}
+
+/// Why a date couldn't be decoded or encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldError {
+    /// The field doesn't fit into the record.
+    OutOfBounds,
+
+    /// The field is narrower than its format.
+    TooNarrow,
+
+    /// The field contains only spaces.
+    Blank,
+
+    /// The field doesn't contain a valid date in its format, or there is
+    /// something other than spaces after the date.
+    Invalid,
+
+    /// The Gregorian year of the date has more than four digits.
+    YearOutOfRange,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            Self::OutOfBounds => "the field doesn't fit into the record",
+            Self::TooNarrow => "the field is narrower than its format",
+            Self::Blank => "the field is blank",
+            Self::Invalid => "the field doesn't contain a valid date",
+            Self::YearOutOfRange => "the Gregorian year doesn't have four digits",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+impl core::error::Error for FieldError {}
+
+impl DateField {
+    #[must_use]
+    pub const fn new(offset: usize, width: usize, format: FieldFormat) -> Self {
+        Self {
+            offset,
+            width,
+            format,
+        }
+    }
+
+    /// Reads the date of the field from the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is outside the record, blank or invalid.
+    pub fn decode(&self, record: &[u8]) -> Result<Date, FieldError> {
+        let field = self.bytes(record)?;
+        let (value, padding) = field.split_at(self.format.width());
+
+        if field.iter().all(|&b| b == b' ') {
+            return Err(FieldError::Blank);
+        }
+
+        if padding.iter().any(|&b| b != b' ') {
+            return Err(FieldError::Invalid);
+        }
+
+        let date = match self.format {
+            FieldFormat::Canonical => Date::from_canonical_bytes(value),
+            FieldFormat::Compact => {
+                let year = Year::try_from_ascii(&value[..4]);
+                let (month, day) = (two_digits(&value[4..6]), two_digits(&value[6..]));
+
+                year.zip(month.zip(day)).and_then(|(year, (month, day))| {
+                    Date::from_ymd_untyped(year.value(), month, day)
+                })
+            }
+            FieldFormat::GregorianCompact => {
+                let (year, month, day) = (
+                    number(&value[..4]),
+                    two_digits(&value[4..6]),
+                    two_digits(&value[6..]),
+                );
+
+                year.zip(month.zip(day)).and_then(|(year, (month, day))| {
+                    Date::from_gregorian_ymd(year.cast_signed(), month, day)
+                })
+            }
+        };
+
+        date.ok_or(FieldError::Invalid)
+    }
+
+    /// Writes the date into the field of the record, padded with spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is outside the record or too narrow, or if the
+    /// Gregorian year doesn't have four digits. The record is unchanged then.
+    pub fn encode(&self, date: Date, record: &mut [u8]) -> Result<(), FieldError> {
+        let mut value = [b' '; 10];
+
+        match self.format {
+            FieldFormat::Canonical => value = date.to_canonical_bytes(),
+            FieldFormat::Compact => {
+                let canonical = date.to_canonical_bytes();
+
+                value[..4].copy_from_slice(&canonical[..4]);
+                value[4..6].copy_from_slice(&canonical[5..7]);
+                value[6..8].copy_from_slice(&canonical[8..]);
+            }
+            FieldFormat::GregorianCompact => {
+                let gregorian = GregorianDate::from_sac13(date);
+                let year = u16::try_from(gregorian.year())
+                    .ok()
+                    .filter(|&year| year <= 9999)
+                    .ok_or(FieldError::YearOutOfRange)?;
+
+                write_digits(&mut value[..4], year);
+                write_digits(&mut value[4..6], gregorian.month().into());
+                write_digits(&mut value[6..8], gregorian.day().into());
+            }
+        }
+
+        let field = self.bytes_mut(record)?;
+        let (target, padding) = field.split_at_mut(self.format.width());
+
+        target.copy_from_slice(&value[..self.format.width()]);
+        padding.fill(b' ');
+
+        Ok(())
+    }
+
+    fn bytes<'a>(&self, record: &'a [u8]) -> Result<&'a [u8], FieldError> {
+        if self.width < self.format.width() {
+            return Err(FieldError::TooNarrow);
+        }
+
+        self.offset
+            .checked_add(self.width)
+            .and_then(|end| record.get(self.offset..end))
+            .ok_or(FieldError::OutOfBounds)
+    }
+
+    fn bytes_mut<'a>(&self, record: &'a mut [u8]) -> Result<&'a mut [u8], FieldError> {
+        if self.width < self.format.width() {
+            return Err(FieldError::TooNarrow);
+        }
+
+        self.offset
+            .checked_add(self.width)
+            .and_then(|end| record.get_mut(self.offset..end))
+            .ok_or(FieldError::OutOfBounds)
+    }
+}
+
+/// Decodes several fields of the same record.
+///
+/// # Errors
+///
+/// Returns the index of the first field that couldn't be decoded and the error.
+pub fn decode_fields<const N: usize>(
+    record: &[u8],
+    fields: [DateField; N],
+) -> Result<[Date; N], (usize, FieldError)> {
+    let mut dates = [Date::SAC13_EPOCH; N];
+
+    for (i, (field, date)) in fields.iter().zip(&mut dates).enumerate() {
+        *date = field.decode(record).map_err(|error| (i, error))?;
+    }
+
+    Ok(dates)
+}
+
+/// The value of ASCII digits, or `None` if there is another character.
+fn number(digits: &[u8]) -> Option<u16> {
+    digits.iter().try_fold(0, |acc: u16, &b| {
+        b.is_ascii_digit().then(|| acc * 10 + u16::from(b - b'0'))
+    })
+}
+
+/// The value of two ASCII digits.
+fn two_digits(digits: &[u8]) -> Option<u8> {
+    number(digits).and_then(|value| u8::try_from(value).ok())
+}
+
+/// Writes the value zero-padded to the width of the target.
+fn write_digits(target: &mut [u8], mut value: u16) {
+    for b in target.iter_mut().rev() {
+        *b = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    #[test]
+    fn round_trips() {
+        for format in [
+            FieldFormat::Canonical,
+            FieldFormat::Compact,
+            FieldFormat::GregorianCompact,
+        ] {
+            let field = DateField::new(2, 12, format);
+
+            for date in [
+                date!(K000 - 01 - 01),
+                date!(M021 - 06 - 29),
+                date!(T998 - 13 - 29),
+            ] {
+                let mut record = [b'x'; 16];
+
+                field.encode(date, &mut record).unwrap();
+                assert_eq!(&record[..2], b"xx");
+                assert_eq!(&record[14..], b"xx");
+                assert!(record[2 + format.width()..14].iter().all(|&b| b == b' '));
+                assert_eq!(field.decode(&record), Ok(date));
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let field = DateField::new(0, 8, FieldFormat::Compact);
+
+        assert_eq!(field.decode(b"M02405"), Err(FieldError::OutOfBounds));
+        assert_eq!(field.decode(b"        "), Err(FieldError::Blank));
+        assert_eq!(field.decode(b"M0240529"), Err(FieldError::Invalid));
+        assert_eq!(field.decode(b"M024-5-1"), Err(FieldError::Invalid));
+        assert_eq!(
+            DateField::new(0, 9, FieldFormat::Compact).decode(b"M0240521x"),
+            Err(FieldError::Invalid)
+        );
+        assert_eq!(
+            DateField::new(usize::MAX, 8, FieldFormat::Compact).decode(b"M0240521"),
+            Err(FieldError::OutOfBounds)
+        );
+
+        let mut record = *b"0123456789";
+        let gregorian = DateField::new(0, 8, FieldFormat::GregorianCompact);
+
+        assert_eq!(
+            gregorian.encode(Date::MIN, &mut record),
+            Err(FieldError::YearOutOfRange)
+        );
+        assert_eq!(
+            gregorian.encode(Date::MAX, &mut record),
+            Err(FieldError::YearOutOfRange)
+        );
+        assert_eq!(
+            DateField::new(0, 9, FieldFormat::Canonical).encode(Date::MAX, &mut record),
+            Err(FieldError::TooNarrow)
+        );
+        assert_eq!(&record, b"0123456789");
+
+        assert_eq!(
+            decode_fields(
+                b"M0240521????????",
+                [field, DateField::new(8, 8, FieldFormat::Compact)]
+            ),
+            Err((1, FieldError::Invalid))
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use core::fmt::Display;
+
+use crate::{ComponentOrder, Date, Separator};
+
+/// Case of the millennium letter of SAC13 years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LetterCase {
+    /// `M020`, the canonical form.
+    #[default]
+    Upper,
+
+    /// `m020`.
+    Lower,
+}
+
+/// What happens if a number needs more digits than its field is wide, see [`DateFormat::overflow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// The field gets as wide as necessary, so the output is always correct.
+    #[default]
+    Expand,
+
+    /// Only the lowest digits that fit into the field are written (e.g. `M20` for M020
+    /// with a year width of 2), so the layout is never broken.
+    Truncate,
+
+    /// [`Date::try_format()`] fails, [`Date::format()`] expands the field instead.
+    Error,
+}
+
+/// The numeric field that doesn't fit into its width with [`Overflow::Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldOverflow {
+    /// The digits of the year after the millennium letter.
+    Year,
+
+    /// The month without [padding](DateFormat::pad_month) is 10 or higher.
+    Month,
+
+    /// The day without [padding](DateFormat::pad_day) is 10 or higher.
+    Day,
+}
+
+impl Display for FieldOverflow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let field = match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Day => "day",
+        };
+
+        write!(f, "the {field} doesn't fit into its field width")
+    }
+}
+
+impl core::error::Error for FieldOverflow {}
+
+/// Declarative description of how a [`Date`] is written, for house styles that differ
+/// from the canonical `M020-05-03`.
+///
+/// The default format is the canonical one. Use [`Date::format()`] to display a date.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{ComponentOrder, DateFormat, LetterCase, Separator, date};
+///
+/// let house_style = DateFormat::new()
+///     .letter_case(LetterCase::Lower)
+///     .separator(Separator::Slash)
+///     .pad_month(false)
+///     .pad_day(false);
+///
+/// assert_eq!(date!(M020 - 05 - 03).format(house_style).to_string(), "m020/5/3");
+///
+/// let european = DateFormat::new()
+///     .order(ComponentOrder::DMY)
+///     .separator(Separator::Dot)
+///     .year_width(1);
+///
+/// assert_eq!(date!(M005 - 11 - 24).format(european).to_string(), "24.11.M5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct DateFormat {
+    /// Order of year, month and day.
+    pub order: ComponentOrder,
+
+    /// Separator between the components.
+    pub separator: Separator,
+
+    /// Case of the millennium letter.
+    pub letter_case: LetterCase,
+
+    /// Minimum number of digits after the millennium letter (zero-padded), usually 3.
+    ///
+    /// Widths below 3 drop leading zeros (`M5` instead of `M005`), wider ones add more.
+    pub year_width: u8,
+
+    /// Write months with two digits (`05` instead of `5`).
+    pub pad_month: bool,
+
+    /// Write days with two digits (`03` instead of `3`).
+    pub pad_day: bool,
+
+    /// What happens if a number doesn't fit into its field. The width of a field is
+    /// [`year_width`](Self::year_width) for the year and 2 (padded) or 1 (unpadded)
+    /// for the month and the day.
+    ///
+    /// Fixed-width outputs (like banking files or fixed-column reports) should use
+    /// [`Overflow::Truncate`] or [`Overflow::Error`], so they never widen silently.
+    pub overflow: Overflow,
+}
+
+impl DateFormat {
+    /// The canonical format (`M020-05-03`).
+    pub const CANONICAL: Self = Self {
+        order: ComponentOrder::YMD,
+        separator: Separator::Dash,
+        letter_case: LetterCase::Upper,
+        year_width: 3,
+        pad_month: true,
+        pad_day: true,
+        overflow: Overflow::Expand,
+    };
+
+    /// The canonical format.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::CANONICAL
+    }
+
+    /// Sets [`order`](Self::order).
+    #[must_use]
+    pub const fn order(mut self, value: ComponentOrder) -> Self {
+        self.order = value;
+        self
+    }
+
+    /// Sets [`separator`](Self::separator).
+    #[must_use]
+    pub const fn separator(mut self, value: Separator) -> Self {
+        self.separator = value;
+        self
+    }
+
+    /// Sets [`letter_case`](Self::letter_case).
+    #[must_use]
+    pub const fn letter_case(mut self, value: LetterCase) -> Self {
+        self.letter_case = value;
+        self
+    }
+
+    /// Sets [`year_width`](Self::year_width).
+    #[must_use]
+    pub const fn year_width(mut self, value: u8) -> Self {
+        self.year_width = value;
+        self
+    }
+
+    /// Sets [`pad_month`](Self::pad_month).
+    #[must_use]
+    pub const fn pad_month(mut self, value: bool) -> Self {
+        self.pad_month = value;
+        self
+    }
+
+    /// Sets [`pad_day`](Self::pad_day).
+    #[must_use]
+    pub const fn pad_day(mut self, value: bool) -> Self {
+        self.pad_day = value;
+        self
+    }
+
+    /// Sets [`overflow`](Self::overflow).
+    #[must_use]
+    pub const fn overflow(mut self, value: Overflow) -> Self {
+        self.overflow = value;
+        self
+    }
+
+    /// The value and the width of the numeric fields in the order of [`order`](Self::order).
+    const fn fields(self, date: Date) -> [(FieldOverflow, u16, u8); 3] {
+        let year = (
+            FieldOverflow::Year,
+            date.year().value() % 1000,
+            self.year_width,
+        );
+        let month = (
+            FieldOverflow::Month,
+            date.month().ord() as u16,
+            pad(self.pad_month),
+        );
+        let day = (FieldOverflow::Day, date.day() as u16, pad(self.pad_day));
+
+        match self.order {
+            ComponentOrder::YMD => [year, month, day],
+            ComponentOrder::DMY => [day, month, year],
+            ComponentOrder::MDY => [month, day, year],
+        }
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self::CANONICAL
+    }
+}
+
+impl Date {
+    /// Displays the date in the given [format](DateFormat).
+    ///
+    /// Formatting never fails, with [`Overflow::Error`] a field that doesn't fit is
+    /// expanded like with [`Overflow::Expand`]. Use [`try_format()`](Self::try_format())
+    /// to get the error instead.
+    #[must_use]
+    pub const fn format(&self, format: DateFormat) -> impl Display + use<> {
+        Formatted {
+            date: *self,
+            format,
+        }
+    }
+
+    /// Like [`format()`](Self::format()), but checks upfront that every numeric field fits
+    /// into its width if the format uses [`Overflow::Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first field (in output order) that doesn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{DateFormat, FieldOverflow, Overflow, date};
+    ///
+    /// let fixed = DateFormat::new().year_width(2).overflow(Overflow::Error);
+    ///
+    /// assert_eq!(date!(M005 - 11 - 24).try_format(fixed).unwrap().to_string(), "M05-11-24");
+    /// assert_eq!(date!(M105 - 11 - 24).try_format(fixed).err(), Some(FieldOverflow::Year));
+    ///
+    /// let truncated = fixed.overflow(Overflow::Truncate);
+    /// assert_eq!(date!(M105 - 11 - 24).format(truncated).to_string(), "M05-11-24");
+    /// ```
+    pub fn try_format(&self, format: DateFormat) -> Result<impl Display + use<>, FieldOverflow> {
+        if matches!(format.overflow, Overflow::Error) {
+            for (field, value, width) in format.fields(*self) {
+                if fitted(value, width, Overflow::Error).is_none() {
+                    return Err(field);
+                }
+            }
+        }
+
+        Ok(self.format(format))
+    }
+}
+
+/// Writes the dates in the canonical format (e.g. `M020-05-21`) with the separator
+/// between them, e.g. for the rows of large reports.
+///
+/// The output is collected in a buffer on the stack and written in large chunks, and the
+/// year is only formatted again when it changes, so sorted dates are especially cheap.
+///
+/// # Errors
+///
+/// Returns the error of the writer.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, format_all};
+///
+/// let dates = [date!(M020 - 13 - 29), date!(M021 - 01 - 01), date!(M021 - 06 - 29)];
+/// let mut output = String::new();
+///
+/// format_all(&dates, "\n", &mut output).unwrap();
+/// assert_eq!(output, "M020-13-29\nM021-01-01\nM021-06-29");
+/// ```
+pub fn format_all<W: core::fmt::Write>(
+    dates: &[Date],
+    separator: &str,
+    output: &mut W,
+) -> core::fmt::Result {
+    const CAPACITY: usize = 1024;
+
+    let mut buffer = [0; CAPACITY];
+    let mut len = 0;
+    let mut year = None;
+    let mut year_ascii = [0; 4];
+
+    // only called with whole dates and separators, so the buffer is always valid UTF-8
+    let flush = |buffer: &[u8], output: &mut W| {
+        let Ok(chunk) = core::str::from_utf8(buffer) else {
+            unreachable!()
+        };
+
+        output.write_str(chunk)
+    };
+
+    for (i, date) in dates.iter().enumerate() {
+        if i != 0 {
+            if separator.len() > CAPACITY - len {
+                flush(&buffer[..len], output)?;
+                len = 0;
+            }
+
+            if separator.len() > CAPACITY {
+                output.write_str(separator)?;
+            } else {
+                buffer[len..len + separator.len()].copy_from_slice(separator.as_bytes());
+                len += separator.len();
+            }
+        }
+
+        if CAPACITY - len < Date::CANONICAL_FORMAT.len() {
+            flush(&buffer[..len], output)?;
+            len = 0;
+        }
+
+        if year != Some(date.year()) {
+            year = Some(date.year());
+            year_ascii = date.year().to_ascii();
+        }
+
+        let [m0, m1] = two_digits(date.month().ord());
+        let [d0, d1] = two_digits(date.day());
+        let [y0, y1, y2, y3] = year_ascii;
+
+        buffer[len..len + 10].copy_from_slice(&[y0, y1, y2, y3, b'-', m0, m1, b'-', d0, d1]);
+        len += 10;
+    }
+
+    flush(&buffer[..len], output)
+}
+
+/// The two ASCII digits of a month or day.
+const fn two_digits(value: u8) -> [u8; 2] {
+    [b'0' + value / 10, b'0' + value % 10]
+}
+
+struct Formatted {
+    date: Date,
+    format: DateFormat,
+}
+
+impl Display for Formatted {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self { date, format } = self;
+
+        for (i, (field, value, width)) in format.fields(*date).into_iter().enumerate() {
+            if i != 0 {
+                write!(f, "{}", format.separator)?;
+            }
+
+            if matches!(field, FieldOverflow::Year) {
+                let letter = char::from(b'A' + (date.year().value() / 1000) as u8);
+                let letter = match format.letter_case {
+                    LetterCase::Upper => letter,
+                    LetterCase::Lower => letter.to_ascii_lowercase(),
+                };
+
+                write!(f, "{letter}")?;
+            }
+
+            // only `try_format()` reports fields that don't fit, `format()` must not fail
+            let overflow = match format.overflow {
+                Overflow::Error => Overflow::Expand,
+                overflow => overflow,
+            };
+
+            let Some(value) = fitted(value, width, overflow) else {
+                unreachable!()
+            };
+
+            // with truncation, a width of zero leaves no digits at all
+            if width > 0 || !matches!(format.overflow, Overflow::Truncate) {
+                write!(f, "{value:0width$}", width = usize::from(width))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const fn pad(padded: bool) -> u8 {
+    if padded { 2 } else { 1 }
+}
+
+/// The value to write into a field of the given width, or `None` if it doesn't fit
+/// and the overflow is an error.
+const fn fitted(value: u16, width: u8, overflow: Overflow) -> Option<u16> {
+    // at least one digit is written, even for zero
+    let limit = match 10u16.checked_pow(width as u32) {
+        Some(limit) => limit,
+        None => u16::MAX,
+    };
+
+    if value < limit && (width > 0 || !matches!(overflow, Overflow::Error)) {
+        return Some(value);
+    }
+
+    match overflow {
+        Overflow::Expand => Some(value),
+        Overflow::Truncate => Some(value % limit),
+        Overflow::Error => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DateRange, traits::CalendarDate};
+
+    #[test]
+    fn format_all_matches_display() {
+        use core::fmt::Write;
+        use std::string::String;
+
+        let dates: std::vec::Vec<Date> =
+            DateRange::new(date!(M019 - 13 - 01), date!(M021 - 02 - 01))
+                .unwrap()
+                .iter()
+                .collect();
+
+        for separator in ["", ",", "\r\n", "→", &"x".repeat(1500)] {
+            let mut expected = String::new();
+
+            for (i, date) in dates.iter().enumerate() {
+                if i != 0 {
+                    expected.push_str(separator);
+                }
+
+                write!(expected, "{date}").unwrap();
+            }
+
+            let mut output = String::new();
+            format_all(&dates, separator, &mut output).unwrap();
+
+            assert_eq!(output, expected);
+        }
+
+        let mut output = String::new();
+        format_all(&[], ",", &mut output).unwrap();
+        assert_eq!(output, "");
+    }
+
+    #[test]
+    fn canonical_format_matches_display() {
+        for date in [Date::MIN, date!(M021 - 06 - 29), Date::MAX] {
+            assert_eq!(
+                format!("{}", date.format(DateFormat::default())),
+                format!("{date}")
+            );
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let date = date!(Z999 - 13 - 29);
+        let narrow = DateFormat::new()
+            .year_width(1)
+            .pad_month(false)
+            .pad_day(false);
+
+        assert_eq!(format!("{}", date.format(narrow)), "Z999-13-29");
+        assert_eq!(
+            format!("{}", date.format(narrow.overflow(Overflow::Truncate))),
+            "Z9-3-9"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                date.format(narrow.year_width(0).overflow(Overflow::Truncate))
+            ),
+            "Z-3-9"
+        );
+
+        let error = narrow.overflow(Overflow::Error);
+        assert_eq!(format!("{}", date.format(error)), "Z999-13-29");
+
+        assert_eq!(date.try_format(error).err(), Some(FieldOverflow::Year));
+        assert_eq!(
+            date.try_format(error.order(ComponentOrder::DMY)).err(),
+            Some(FieldOverflow::Day)
+        );
+        assert_eq!(
+            date!(A000 - 01 - 01).try_format(error.year_width(0)).err(),
+            Some(FieldOverflow::Year)
+        );
+        assert!(date!(A009 - 09 - 09).try_format(error).is_ok());
+        assert!(
+            date.try_format(DateFormat::new().year_width(5).overflow(Overflow::Error))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn modifiers() {
+        let date = date!(A007 - 01 - 09);
+        let format = DateFormat::new()
+            .order(ComponentOrder::MDY)
+            .separator(Separator::Compact)
+            .letter_case(LetterCase::Lower)
+            .year_width(5);
+
+        assert_eq!(format!("{}", date.format(format)), "0109a00007");
+        assert_eq!(
+            format!("{}", date.format(format.year_width(0).pad_day(false))),
+            "019a7"
+        );
+        assert_eq!(
+            format!("{}", date!(Z999 - 13 - 29).format(format.year_width(0))),
+            "1329z999"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Date, Month, traits::CalendarDate};
+
+/// Size of a calendar period used for grouping, bucketing and truncating dates.
+///
+/// All periods are aligned to the SAC13 year:
+///
+/// - A [`Week`](Self::Week) starts on day 1, 8, 15 or 22 of a month.
+///   Synchronization days (day 29) belong to the fourth week of their month.
+/// - A [`Quarter`](Self::Quarter) consists of 13 weeks. The quarters start on
+///   March 1st, June 8th, September 15th and December 22nd. The leap day
+///   belongs to the second and the year day to the fourth quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// Month and day the quarters start on.
+const QUARTER_STARTS: [(Month, u8); 4] = [
+    (Month::March, 1),
+    (Month::June, 8),
+    (Month::September, 15),
+    (Month::December, 22),
+];
+
+impl Granularity {
+    /// First day of the period the date belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::{date, Granularity};
+    ///
+    /// assert_eq!(Granularity::Month.period_start(date!(M020 - 05 - 21)), date!(M020 - 05 - 01));
+    /// assert_eq!(Granularity::Quarter.period_start(date!(M020 - 05 - 21)), date!(M020 - 04 - 08));
+    /// ```
+    #[must_use]
+    pub const fn period_start(self, date: Date) -> Date {
+        match self {
+            Self::Day => date,
+            Self::Week => date.align_to_week_start(),
+            Self::Month => with_month_day(date, date.month(), 1),
+            Self::Quarter => {
+                let (month, day) = QUARTER_STARTS[((date.week_of_year() - 1) / 13) as usize];
+                with_month_day(date, month, day)
+            }
+            Self::Year => with_month_day(date, Month::March, 1),
+        }
+    }
+
+    /// First day of the period following the one the date belongs to.
+    ///
+    /// Returns `None` if the following period is outside the SAC13 range.
+    #[must_use]
+    pub fn next_period_start(self, date: Date) -> Option<Date> {
+        match self {
+            Self::Day => date.tomorrow(),
+            Self::Week if date.week_of_month() < 4 => {
+                let start = date.align_to_week_start();
+                Date::from_ymd(date.year(), date.month(), start.day() + 7)
+            }
+            Self::Week | Self::Month => {
+                let month = date.month().next();
+
+                let year = if matches!(month, Month::March) {
+                    date.year().next()?
+                } else {
+                    date.year()
+                };
+
+                Date::from_ymd(year, month, 1)
+            }
+            Self::Quarter => {
+                let quarter = ((date.week_of_year() - 1) / 13) as usize;
+
+                if quarter == 3 {
+                    Date::from_ymd(date.year().next()?, Month::March, 1)
+                } else {
+                    let (month, day) = QUARTER_STARTS[quarter + 1];
+                    Date::from_ymd(date.year(), month, day)
+                }
+            }
+            Self::Year => Date::from_ymd(date.year().next()?, Month::March, 1),
+        }
+    }
+}
+
+impl Granularity {
+    /// Consecutive number of the period the date belongs to, to count periods.
+    pub(crate) fn period_index(self, date: Date) -> i32 {
+        let year = i32::from(date.year().value());
+
+        match self {
+            Self::Day => date.as_julian(),
+            Self::Week => year * 52 + i32::from(date.week_of_year()),
+            Self::Month => year * 13 + i32::from(date.month().ord()),
+            Self::Quarter => year * 4 + i32::from((date.week_of_year() - 1) / 13),
+            Self::Year => year,
+        }
+    }
+}
+
+/// Same year as the given date, but with a different month and day.
+const fn with_month_day(date: Date, month: Month, day: u8) -> Date {
+    match Date::from_ymd(date.year(), month, day) {
+        Some(d) => d,
+        None => panic!("Period start must be a valid date. This is a bug!"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quarters_are_thirteen_weeks() {
+        let q = Granularity::Quarter;
+
+        assert_eq!(q.period_start(date!(M021 - 06 - 29)), date!(M021 - 04 - 08));
+        assert_eq!(q.period_start(date!(M021 - 07 - 14)), date!(M021 - 04 - 08));
+        assert_eq!(q.period_start(date!(M021 - 07 - 15)), date!(M021 - 07 - 15));
+        assert_eq!(q.period_start(date!(M021 - 13 - 29)), date!(M021 - 10 - 22));
+
+        assert_eq!(
+            q.next_period_start(date!(M021 - 13 - 29)),
+            Some(date!(M022 - 01 - 01))
+        );
+        assert_eq!(
+            q.next_period_start(date!(M021 - 01 - 01)),
+            Some(date!(M021 - 04 - 08))
+        );
+    }
+
+    #[test]
+    fn next_week_skips_sync_day() {
+        let w = Granularity::Week;
+
+        assert_eq!(
+            w.next_period_start(date!(M021 - 06 - 22)),
+            Some(date!(M021 - 07 - 01))
+        );
+        assert_eq!(
+            w.next_period_start(date!(M021 - 06 - 03)),
+            Some(date!(M021 - 06 - 08))
+        );
+    }
+
+    #[test]
+    fn next_period_at_the_end_of_time() {
+        for g in [
+            Granularity::Day,
+            Granularity::Week,
+            Granularity::Month,
+            Granularity::Quarter,
+            Granularity::Year,
+        ] {
+            assert_eq!(g.next_period_start(Date::MAX), None);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Grouping and bucketing of dates, e.g. for reports and dashboards.
+//!
+//! The `group_by_*` functions expect the dates to be sorted and yield
+//! consecutive sub-slices, so they don't need to allocate. Use [`validate_monotonic()`]
+//! or [`is_sorted_by_day()`] to check the order of ingested data first.
+//! [`bucket_counts()`] requires the `alloc` feature.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::iter::FusedIterator;
+
+use crate::{Date, Month, Year};
+
+#[cfg(feature = "alloc")]
+use crate::{DateRange, Granularity, traits::CalendarDate};
+
+/// Splits a sorted slice of dates into groups of the same year.
+///
+/// Unsorted input doesn't cause a panic, but the same year might be yielded multiple times.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::grouping::group_by_year;
+///
+/// let dates = [date!(M020 - 01 - 01), date!(M020 - 05 - 05), date!(M021 - 01 - 01)];
+/// let mut groups = group_by_year(&dates);
+///
+/// assert_eq!(groups.next(), Some((year!(M020), &dates[..2])));
+/// assert_eq!(groups.next(), Some((year!(M021), &dates[2..])));
+/// assert_eq!(groups.next(), None);
+/// ```
+#[must_use]
+pub fn group_by_year(
+    dates: &[Date],
+) -> impl DoubleEndedIterator<Item = (Year, &[Date])> + FusedIterator {
+    dates
+        .chunk_by(|a, b| a.year() == b.year())
+        .map(|group| (group[0].year(), group))
+}
+
+/// Splits a sorted slice of dates into groups of the same month (of the same year).
+///
+/// Unsorted input doesn't cause a panic, but the same month might be yielded multiple times.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::grouping::group_by_month;
+///
+/// let dates = [date!(M020 - 01 - 01), date!(M020 - 01 - 05), date!(M020 - 02 - 01)];
+/// let mut groups = group_by_month(&dates);
+///
+/// assert_eq!(groups.next(), Some(((year!(M020), Month::March), &dates[..2])));
+/// assert_eq!(groups.next(), Some(((year!(M020), Month::April), &dates[2..])));
+/// assert_eq!(groups.next(), None);
+/// ```
+#[must_use]
+pub fn group_by_month(
+    dates: &[Date],
+) -> impl DoubleEndedIterator<Item = ((Year, Month), &[Date])> + FusedIterator {
+    dates
+        .chunk_by(|a, b| a.year() == b.year() && a.month() == b.month())
+        .map(|group| ((group[0].year(), group[0].month()), group))
+}
+
+/// Checks that the dates never decrease (repeated dates are fine).
+///
+/// # Errors
+///
+/// Returns the index of the first date that is earlier than its predecessor,
+/// together with the predecessor and the date itself.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::grouping::validate_monotonic;
+///
+/// let dates = [date!(M020 - 01 - 01), date!(M020 - 01 - 01), date!(M020 - 01 - 03)];
+/// assert_eq!(validate_monotonic(dates), Ok(()));
+///
+/// let dates = [date!(M020 - 01 - 01), date!(M020 - 01 - 05), date!(M020 - 01 - 03)];
+/// assert_eq!(
+///     validate_monotonic(dates),
+///     Err((2, date!(M020 - 01 - 05), date!(M020 - 01 - 03)))
+/// );
+/// ```
+pub fn validate_monotonic(
+    dates: impl IntoIterator<Item = Date>,
+) -> Result<(), (usize, Date, Date)> {
+    let mut dates = dates.into_iter();
+
+    let Some(mut previous) = dates.next() else {
+        return Ok(());
+    };
+
+    for (i, date) in dates.enumerate() {
+        if date < previous {
+            return Err((i + 1, previous, date));
+        }
+
+        previous = date;
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the days of the items never decrease, e.g. for records with a
+/// timestamp where only the day matters.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::DateTime;
+/// use sac13::grouping::is_sorted_by_day;
+///
+/// let readings = [
+///     (DateTime::from_hms(date!(M020 - 01 - 01), 18, 0, 0).unwrap(), 3.5),
+///     (DateTime::from_hms(date!(M020 - 01 - 01), 6, 0, 0).unwrap(), 2.0),
+///     (DateTime::from_hms(date!(M020 - 01 - 02), 6, 0, 0).unwrap(), 2.5),
+/// ];
+///
+/// assert!(is_sorted_by_day(&readings, |(time, _)| time.date()));
+/// assert!(!readings.is_sorted_by_key(|(time, _)| *time));
+/// ```
+pub fn is_sorted_by_day<T>(
+    items: impl IntoIterator<Item = T>,
+    mut day: impl FnMut(&T) -> Date,
+) -> bool {
+    validate_monotonic(items.into_iter().map(|item| day(&item))).is_ok()
+}
+
+/// Counts how many of the given dates fall into each period of the range.
+///
+/// Every period that overlaps with the range gets a bucket, even if the count is zero.
+/// The first and the last bucket are clipped to the range, so they might be shorter than
+/// a full period. Dates outside the range are ignored. The dates don't have to be sorted.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::{DateRange, Granularity, grouping::bucket_counts};
+///
+/// let range = DateRange::new(date!(M020 - 01 - 05), date!(M020 - 01 - 20)).unwrap();
+/// let dates = [date!(M020 - 01 - 06), date!(M020 - 01 - 07), date!(M020 - 01 - 15)];
+///
+/// let counts: Vec<_> = bucket_counts(&dates, range, Granularity::Week)
+///     .into_iter()
+///     .map(|(bucket, count)| (bucket.start(), count))
+///     .collect();
+///
+/// assert_eq!(
+///     counts,
+///     [
+///         (date!(M020 - 01 - 05), 2),
+///         (date!(M020 - 01 - 08), 0),
+///         (date!(M020 - 01 - 15), 1),
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn bucket_counts(
+    dates: &[Date],
+    range: DateRange,
+    granularity: Granularity,
+) -> Vec<(DateRange, usize)> {
+    let mut buckets = Vec::new();
+    let mut start = range.start();
+
+    loop {
+        let next = granularity
+            .next_period_start(start)
+            .filter(|&next| next <= range.end());
+
+        let end = next
+            .and_then(Date::yesterday)
+            .unwrap_or_else(|| range.end());
+        buckets.extend(DateRange::new(start, end).map(|bucket| (bucket, 0)));
+
+        let Some(next) = next else {
+            break;
+        };
+
+        start = next;
+    }
+
+    for &date in dates.iter().filter(|&&d| range.contains(d)) {
+        let i = buckets.partition_point(|(bucket, _)| bucket.end() < date);
+        buckets[i].1 += 1;
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn monotonic_checks() {
+        assert_eq!(validate_monotonic([]), Ok(()));
+        let [early, late] = [date!(A000 - 01 - 01), date!(Z999 - 13 - 29)];
+
+        assert_eq!(validate_monotonic([late]), Ok(()));
+        assert_eq!(
+            validate_monotonic([late, early, late, early]),
+            Err((1, late, early))
+        );
+
+        assert!(is_sorted_by_day([3, 1, 2], |_| early));
+        assert!(!is_sorted_by_day(
+            [date!(M020 - 01 - 02), date!(M020 - 01 - 01)],
+            |&d| d
+        ));
+    }
+
+    #[test]
+    fn group_by_year_handles_empty_and_single() {
+        assert_eq!(group_by_year(&[]).count(), 0);
+
+        let dates = [date!(M020 - 01 - 01)];
+        assert_eq!(group_by_year(&dates).count(), 1);
+    }
+
+    #[test]
+    fn group_by_month_separates_years() {
+        let dates = [date!(M020 - 01 - 01), date!(M021 - 01 - 01)];
+        assert_eq!(group_by_month(&dates).count(), 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bucket_counts_up_to_max() {
+        let range = DateRange::new(date!(Z999 - 13 - 01), Date::MAX).unwrap();
+        let buckets = bucket_counts(&[Date::MAX, Date::MIN], range, Granularity::Year);
+
+        assert_eq!(buckets, [(range, 1)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bucket_counts_per_month() {
+        let range = DateRange::new(date!(M020 - 12 - 15), date!(M021 - 01 - 15)).unwrap();
+        let dates = [
+            date!(M021 - 01 - 16),
+            date!(M020 - 13 - 29),
+            date!(M020 - 12 - 15),
+            date!(M020 - 12 - 14),
+        ];
+
+        let buckets = bucket_counts(&dates, range, Granularity::Month);
+
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets.iter().map(|(_, c)| c).sum::<usize>(), 2);
+        assert_eq!(buckets[1].0.duration_days(), 29);
+    }
+}
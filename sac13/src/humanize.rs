@@ -0,0 +1,136 @@
+use core::fmt::Display;
+
+use crate::Granularity;
+
+/// Approximate, human-friendly description of how far a date is from another one.
+///
+/// Created by [`Date::humanize_relative_to()`](crate::Date::humanize_relative_to()).
+/// The phrase uses the largest unit that fits at least once into the distance,
+/// but never a unit smaller than the configured [granularity](Self::granularity()).
+/// The units have a fixed length: a week is 7 days, a month 28, a quarter 91
+/// and a year 365 days, so the result is only an approximation.
+///
+/// | Distance    | Day granularity | Month granularity |
+/// |-------------|-----------------|-------------------|
+/// | 0 days      | today           | this month        |
+/// | +1 day      | tomorrow        | this month        |
+/// | -3 days     | 3 days ago      | this month        |
+/// | +20 days    | in 2 weeks      | this month        |
+/// | -60 days    | 2 months ago    | 2 months ago      |
+/// | +800 days   | in 2 years      | in 2 years        |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HumanizedRelative {
+    days: i32,
+    granularity: Granularity,
+}
+
+impl HumanizedRelative {
+    pub(crate) const fn new(days: i32) -> Self {
+        Self {
+            days,
+            granularity: Granularity::Day,
+        }
+    }
+
+    /// Sets the smallest unit used in the phrase. The default is [`Granularity::Day`].
+    #[must_use]
+    pub const fn granularity(self, granularity: Granularity) -> Self {
+        Self {
+            granularity,
+            ..self
+        }
+    }
+
+    /// Signed distance in days. Positive values are in the future.
+    #[must_use]
+    pub const fn days(&self) -> i32 {
+        self.days
+    }
+}
+
+/// Length in days, singular and plural name of a unit.
+const fn unit(granularity: Granularity) -> (u32, &'static str, &'static str) {
+    match granularity {
+        Granularity::Day => (1, "day", "days"),
+        Granularity::Week => (7, "week", "weeks"),
+        Granularity::Month => (28, "month", "months"),
+        Granularity::Quarter => (91, "quarter", "quarters"),
+        Granularity::Year => (365, "year", "years"),
+    }
+}
+
+impl Display for HumanizedRelative {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const UNITS: [Granularity; 5] = [
+            Granularity::Year,
+            Granularity::Quarter,
+            Granularity::Month,
+            Granularity::Week,
+            Granularity::Day,
+        ];
+
+        let distance = self.days.unsigned_abs();
+
+        let granularity = UNITS
+            .into_iter()
+            .filter(|&g| g >= self.granularity)
+            .find(|&g| distance >= unit(g).0)
+            .unwrap_or(self.granularity);
+
+        let (len, singular, plural) = unit(granularity);
+        let count = distance / len;
+
+        if count == 0 {
+            return match granularity {
+                Granularity::Day => write!(f, "today"),
+                _ => write!(f, "this {singular}"),
+            };
+        }
+
+        if granularity == Granularity::Day && count == 1 {
+            return if self.days > 0 {
+                write!(f, "tomorrow")
+            } else {
+                write!(f, "yesterday")
+            };
+        }
+
+        let name = if count == 1 { singular } else { plural };
+
+        if self.days > 0 {
+            write!(f, "in {count} {name}")
+        } else {
+            write!(f, "{count} {name} ago")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(days: i32, granularity: Granularity) -> std::string::String {
+        format!("{}", HumanizedRelative::new(days).granularity(granularity))
+    }
+
+    #[test]
+    fn day_granularity() {
+        assert_eq!(phrase(0, Granularity::Day), "today");
+        assert_eq!(phrase(1, Granularity::Day), "tomorrow");
+        assert_eq!(phrase(-1, Granularity::Day), "yesterday");
+        assert_eq!(phrase(-3, Granularity::Day), "3 days ago");
+        assert_eq!(phrase(7, Granularity::Day), "in 1 week");
+        assert_eq!(phrase(20, Granularity::Day), "in 2 weeks");
+        assert_eq!(phrase(-60, Granularity::Day), "2 months ago");
+        assert_eq!(phrase(100, Granularity::Day), "in 1 quarter");
+        assert_eq!(phrase(800, Granularity::Day), "in 2 years");
+    }
+
+    #[test]
+    fn coarse_granularity() {
+        assert_eq!(phrase(20, Granularity::Month), "this month");
+        assert_eq!(phrase(-60, Granularity::Month), "2 months ago");
+        assert_eq!(phrase(-364, Granularity::Year), "this year");
+        assert_eq!(phrase(i32::MIN, Granularity::Year), "5883516 years ago");
+    }
+}
@@ -0,0 +1,31 @@
+//! Conversions from and to the types of other date and time libraries.
+//!
+//! Every library has its own submodule behind a feature of the same name, with
+//! functions named after the library: `from_<library>()` and `to_<library>()` for
+//! dates and `from_<library>_datetime()` and `to_<library>_datetime()` for dates with
+//! a time of day.
+//!
+//! | Module | Feature | Date | Date and time |
+//! |---|---|---|---|
+//! | `chrono` | `chrono` | `NaiveDate` | `NaiveDateTime` |
+//! | `time` | `time` | `Date` | `PrimitiveDateTime` |
+//! | `jiff` | `jiff` | `civil::Date` | `civil::DateTime` |
+//! | `js` | `wasm` | | `js_sys::Date` (UTC) |
+//!
+//! [`DateTime`](crate::DateTime) has a resolution of one second, so fractions of a second
+//! are truncated. Conversions into libraries with a smaller range than SAC13
+//! return `None` for dates outside of it.
+//!
+//! See also [`compat`](crate::compat) for the time stamps of .NET, Windows, NTP and GPS.
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+#[cfg(feature = "jiff")]
+pub mod jiff;
+
+#[cfg(feature = "wasm")]
+pub mod js;
+
+#[cfg(feature = "time")]
+pub mod time;
@@ -0,0 +1,86 @@
+//! Conversions from and to the naive (time zone independent) types of `chrono`.
+//!
+//! `chrono` covers the whole SAC13 range, so only the conversions into SAC13 can fail.
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::NaiveDate;
+//! use sac13::date;
+//! use sac13::interop::chrono::{from_chrono, to_chrono};
+//!
+//! let naive = NaiveDate::from_ymd_opt(2024, 5, 21).unwrap();
+//!
+//! assert_eq!(from_chrono(naive), Some(date!(M024 - 03 - 07)));
+//! assert_eq!(to_chrono(date!(M024 - 03 - 07)), naive);
+//! ```
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::{Date, DateTime, compat::chrono_like::ChronoLike};
+
+/// The SAC13 date of a `NaiveDate`.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub fn from_chrono(date: NaiveDate) -> Option<Date> {
+    Date::from_num_days_from_ce_opt(chrono::Datelike::num_days_from_ce(&date))
+}
+
+/// The `NaiveDate` of the SAC13 date.
+#[must_use]
+pub fn to_chrono(date: Date) -> NaiveDate {
+    let Some(date) = NaiveDate::from_num_days_from_ce_opt(date.num_days_from_ce()) else {
+        unreachable!()
+    };
+
+    date
+}
+
+/// The SAC13 date and time of a `NaiveDateTime`, without the fraction of a second.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub fn from_chrono_datetime(datetime: NaiveDateTime) -> Option<DateTime> {
+    DateTime::new(
+        from_chrono(datetime.date())?,
+        datetime.time().num_seconds_from_midnight(),
+    )
+}
+
+/// The `NaiveDateTime` of the SAC13 date and time.
+#[must_use]
+pub fn to_chrono_datetime(datetime: DateTime) -> NaiveDateTime {
+    let Some(time) = NaiveTime::from_num_seconds_from_midnight_opt(datetime.seconds_of_day(), 0)
+    else {
+        unreachable!()
+    };
+
+    to_chrono(datetime.date()).and_time(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CalendarDate;
+
+    #[test]
+    fn round_trips() {
+        for date in [Date::MIN, Date::MAX, date!(M024 - 03 - 07)] {
+            assert_eq!(from_chrono(to_chrono(date)), Some(date));
+        }
+
+        assert_eq!(
+            to_chrono(Date::MIN),
+            NaiveDate::from_ymd_opt(-10_000, 3, 22).unwrap()
+        );
+        assert_eq!(from_chrono(NaiveDate::MAX), None);
+        assert_eq!(from_chrono(NaiveDate::MIN), None);
+
+        let datetime = DateTime::from_hms(Date::MAX, 23, 59, 59).unwrap();
+        let naive = to_chrono_datetime(datetime);
+
+        assert_eq!(format!("{naive}"), "+16000-03-17 23:59:59");
+        assert_eq!(from_chrono_datetime(naive), Some(datetime));
+    }
+}
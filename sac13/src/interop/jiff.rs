@@ -0,0 +1,96 @@
+//! Conversions from and to the civil (time zone independent) types of `jiff`.
+//!
+//! `jiff` only supports the years -9999 to 9999 (Gregorian), so the conversions
+//! into `jiff` return `None` for dates outside of it.
+//!
+//! # Examples
+//!
+//! ```
+//! use jiff::civil::date;
+//! use sac13::interop::jiff::{from_jiff, to_jiff};
+//!
+//! assert_eq!(from_jiff(date(2024, 5, 21)), Some(sac13::date!(M024 - 03 - 07)));
+//! assert_eq!(to_jiff(sac13::date!(M024 - 03 - 07)), Some(date(2024, 5, 21)));
+//! ```
+
+use crate::{Date, DateTime, GregorianDate, traits::CalendarDate};
+
+/// The SAC13 date of a `jiff::civil::Date`.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+#[allow(clippy::cast_sign_loss)] // months and days are positive
+pub fn from_jiff(date: jiff::civil::Date) -> Option<Date> {
+    Date::from_gregorian_ymd(date.year(), date.month() as u8, date.day() as u8)
+}
+
+/// The `jiff::civil::Date` of the SAC13 date.
+///
+/// Returns `None` if the date is outside the range of `jiff`.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)] // months and days are small
+pub fn to_jiff(date: Date) -> Option<jiff::civil::Date> {
+    let gregorian: GregorianDate = date.convert();
+
+    jiff::civil::Date::new(
+        gregorian.year(),
+        gregorian.month() as i8,
+        gregorian.day() as i8,
+    )
+    .ok()
+}
+
+/// The SAC13 date and time of a `jiff::civil::DateTime`, without the fraction of a second.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+#[allow(clippy::cast_sign_loss)] // hours, minutes and seconds are positive
+pub fn from_jiff_datetime(datetime: jiff::civil::DateTime) -> Option<DateTime> {
+    DateTime::from_hms(
+        from_jiff(datetime.date())?,
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+    )
+}
+
+/// The `jiff::civil::DateTime` of the SAC13 date and time.
+///
+/// Returns `None` if the date is outside the range of `jiff`.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)] // hours, minutes and seconds are small
+pub fn to_jiff_datetime(datetime: DateTime) -> Option<jiff::civil::DateTime> {
+    Some(to_jiff(datetime.date())?.at(
+        datetime.hour() as i8,
+        datetime.minute() as i8,
+        datetime.second() as i8,
+        0,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let first = date_greg!(-9999 - 01 - 01).convert();
+        let last = date_greg!(9999 - 12 - 31).convert();
+
+        for date in [first, last, date!(M024 - 03 - 07)] {
+            assert_eq!(to_jiff(date).and_then(from_jiff), Some(date));
+        }
+
+        assert_eq!(to_jiff(first), Some(jiff::civil::Date::MIN));
+        assert_eq!(to_jiff(last), Some(jiff::civil::Date::MAX));
+        assert_eq!(first.yesterday().and_then(to_jiff), None);
+        assert_eq!(last.tomorrow().and_then(to_jiff), None);
+
+        let datetime = DateTime::from_hms(date!(M024 - 03 - 07), 13, 30, 5).unwrap();
+        let civil = to_jiff_datetime(datetime).unwrap();
+
+        assert_eq!(civil, jiff::civil::date(2024, 5, 21).at(13, 30, 5, 0));
+        assert_eq!(from_jiff_datetime(civil), Some(datetime));
+        assert_eq!(to_jiff_datetime(DateTime::new(Date::MAX, 0).unwrap()), None);
+    }
+}
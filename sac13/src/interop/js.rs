@@ -0,0 +1,80 @@
+//! Conversions from and to the JavaScript `Date` of `js-sys`.
+//!
+//! A JavaScript `Date` is a UTC time stamp in milliseconds since the Unix epoch,
+//! so the conversions use the UTC date and time. They only work on `wasm32` targets
+//! with a JavaScript host.
+
+use crate::{
+    DateTime,
+    compat::{UNIX_EPOCH_JULIAN, from_units, to_units},
+};
+
+/// The SAC13 date and time (UTC) of a JavaScript `Date`, without the fraction of a second.
+///
+/// Returns `None` for an invalid `Date` (`NaN`) or if the date is outside the SAC13 range.
+#[must_use]
+pub fn from_js(date: &js_sys::Date) -> Option<DateTime> {
+    from_milliseconds(date.get_time())
+}
+
+/// The JavaScript `Date` of the SAC13 date and time (UTC).
+#[must_use]
+pub fn to_js(datetime: DateTime) -> js_sys::Date {
+    js_sys::Date::new(&to_milliseconds(datetime).into())
+}
+
+/// The date and time of a JavaScript time value (milliseconds since the Unix epoch).
+#[allow(clippy::cast_possible_truncation)] // range checked
+fn from_milliseconds(milliseconds: f64) -> Option<DateTime> {
+    // JavaScript dates are limited to ±100'000'000 days around the epoch
+    if !milliseconds.is_finite() || milliseconds.abs() > 8.64e15 {
+        return None;
+    }
+
+    from_units(milliseconds.floor() as i64, 1000, UNIX_EPOCH_JULIAN)
+}
+
+/// The JavaScript time value of the date and time.
+#[allow(clippy::cast_precision_loss)] // exact, the time stamps are far below 2^53
+const fn to_milliseconds(datetime: DateTime) -> f64 {
+    to_units(datetime, 1000, UNIX_EPOCH_JULIAN) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CalendarDate, Date};
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn time_values() {
+        // 2024-05-21 13:30:00 UTC
+        let datetime = DateTime::from_hms(date!(M024 - 03 - 07), 13, 30, 0).unwrap();
+
+        assert_eq!(to_milliseconds(datetime), 1_716_298_200_000.0);
+        assert_eq!(from_milliseconds(1_716_298_200_000.0), Some(datetime));
+        assert_eq!(from_milliseconds(1_716_298_200_999.9), Some(datetime));
+
+        // fractions before the epoch round down to the previous second
+        let before_epoch = from_milliseconds(-1000.5).unwrap();
+        assert_eq!(
+            before_epoch.date(),
+            Date::UNIX_EPOCH_DATE.yesterday().unwrap()
+        );
+        assert_eq!(before_epoch.seconds_of_day(), DateTime::SECONDS_PER_DAY - 2);
+
+        for datetime in [
+            DateTime::new(Date::MIN, 0).unwrap(),
+            DateTime::new(Date::MAX, DateTime::SECONDS_PER_DAY - 1).unwrap(),
+        ] {
+            assert_eq!(from_milliseconds(to_milliseconds(datetime)), Some(datetime));
+        }
+
+        let after_max = to_milliseconds(DateTime::new(Date::MAX, 0).unwrap()) + 86_400_000.0;
+        assert_eq!(from_milliseconds(after_max), None);
+
+        for invalid in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 8.64e15 + 1.0] {
+            assert_eq!(from_milliseconds(invalid), None);
+        }
+    }
+}
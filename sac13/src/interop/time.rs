@@ -0,0 +1,87 @@
+//! Conversions from and to the types of `time`.
+//!
+//! Without its `large-dates` feature `time` only supports the years -9999 to 9999
+//! (Gregorian), so the conversions into `time` return `None` for dates outside of it.
+//!
+//! # Examples
+//!
+//! ```
+//! use sac13::date;
+//! use sac13::interop::time::{from_time, to_time};
+//! use time::{Date, Month};
+//!
+//! let date = Date::from_calendar_date(2024, Month::May, 21).unwrap();
+//!
+//! assert_eq!(from_time(date), Some(date!(M024 - 03 - 07)));
+//! assert_eq!(to_time(date!(M024 - 03 - 07)), Some(date));
+//! ```
+
+use time::{PrimitiveDateTime, Time};
+
+use crate::{Date, DateTime, traits::CalendarDate};
+
+/// The SAC13 date of a `time::Date`.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub fn from_time(date: time::Date) -> Option<Date> {
+    Date::from_julian(date.to_julian_day())
+}
+
+/// The `time::Date` of the SAC13 date.
+///
+/// Returns `None` if the date is outside the range of `time`.
+#[must_use]
+pub fn to_time(date: Date) -> Option<time::Date> {
+    time::Date::from_julian_day(date.as_julian()).ok()
+}
+
+/// The SAC13 date and time of a `PrimitiveDateTime`, without the fraction of a second.
+///
+/// Returns `None` if the date is outside the SAC13 range.
+#[must_use]
+pub fn from_time_datetime(datetime: PrimitiveDateTime) -> Option<DateTime> {
+    DateTime::from_hms(
+        from_time(datetime.date())?,
+        datetime.hour(),
+        datetime.minute(),
+        datetime.second(),
+    )
+}
+
+/// The `PrimitiveDateTime` of the SAC13 date and time.
+///
+/// Returns `None` if the date is outside the range of `time`.
+#[must_use]
+pub fn to_time_datetime(datetime: DateTime) -> Option<PrimitiveDateTime> {
+    let time = Time::from_hms(datetime.hour(), datetime.minute(), datetime.second()).ok()?;
+
+    Some(PrimitiveDateTime::new(to_time(datetime.date())?, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let first = date_greg!(-9999 - 01 - 01).convert();
+        let last = date_greg!(9999 - 12 - 31).convert();
+
+        for date in [first, last, date!(M024 - 03 - 07)] {
+            assert_eq!(to_time(date).and_then(from_time), Some(date));
+        }
+
+        assert_eq!(to_time(first), Some(time::Date::MIN));
+        assert_eq!(to_time(last), Some(time::Date::MAX));
+        assert_eq!(first.yesterday().and_then(to_time), None);
+        assert_eq!(last.tomorrow().and_then(to_time), None);
+
+        let datetime = DateTime::from_hms(date!(M024 - 03 - 07), 13, 30, 5).unwrap();
+        let primitive = to_time_datetime(datetime).unwrap();
+
+        assert_eq!(format!("{primitive}"), "2024-05-21 13:30:05.0");
+        assert_eq!(from_time_datetime(primitive), Some(datetime));
+        assert_eq!(to_time_datetime(DateTime::new(Date::MAX, 0).unwrap()), None);
+    }
+}
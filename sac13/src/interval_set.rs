@@ -0,0 +1,238 @@
+use alloc::vec::Vec;
+
+use crate::{Date, DateRange};
+
+/// A collection of (possibly overlapping) date ranges with fast lookups by date.
+///
+/// The ranges are kept sorted by their start together with the running maximum of
+/// their ends, so [`containing()`](Self::containing()) ("which bookings contain this day?")
+/// and [`overlapping()`](Self::overlapping()) only look at ranges that can match.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateIntervalSet, DateRange, date};
+///
+/// let mut bookings = DateIntervalSet::new();
+///
+/// let a = DateRange::new(date!(M024 - 03 - 10), date!(M024 - 03 - 16)).unwrap();
+/// let b = DateRange::new(date!(M024 - 03 - 15), date!(M024 - 03 - 20)).unwrap();
+/// let c = DateRange::new(date!(M024 - 03 - 21), date!(M024 - 03 - 28)).unwrap();
+///
+/// bookings.extend([c, a, b]);
+///
+/// assert_eq!(bookings.containing(date!(M024 - 03 - 15)).collect::<Vec<_>>(), [&a, &b]);
+/// assert!(!bookings.contains(date!(M024 - 04 - 01)));
+///
+/// bookings.coalesce();
+///
+/// assert_eq!(
+///     bookings.iter().collect::<Vec<_>>(),
+///     [&DateRange::new(date!(M024 - 03 - 10), date!(M024 - 03 - 28)).unwrap()]
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DateIntervalSet {
+    /// Sorted by start (and end).
+    ranges: Vec<DateRange>,
+
+    /// `max_ends[i]` is the latest end of `ranges[..=i]`.
+    max_ends: Vec<Date>,
+}
+
+impl DateIntervalSet {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ranges: Vec::new(),
+            max_ends: Vec::new(),
+        }
+    }
+
+    /// Number of ranges in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if the set doesn't contain any ranges.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds the range. The same range can be added multiple times.
+    pub fn insert(&mut self, range: DateRange) {
+        let index = self.ranges.partition_point(|r| *r <= range);
+
+        self.ranges.insert(index, range);
+        self.update_max_ends(index);
+    }
+
+    /// Removes one instance of the range. Returns `true` if the range was part of the set.
+    pub fn remove(&mut self, range: &DateRange) -> bool {
+        let Ok(index) = self.ranges.binary_search(range) else {
+            return false;
+        };
+
+        self.ranges.remove(index);
+        self.update_max_ends(index);
+        true
+    }
+
+    /// Merges overlapping and adjacent ranges, so every day is covered by at most one range.
+    pub fn coalesce(&mut self) {
+        let mut merged: Vec<DateRange> = Vec::with_capacity(self.ranges.len());
+
+        for range in &self.ranges {
+            if let Some(last) = merged.last_mut()
+                && let Some(union) = last.union(range)
+            {
+                *last = union;
+            } else {
+                merged.push(*range);
+            }
+        }
+
+        self.ranges = merged;
+        self.update_max_ends(0);
+    }
+
+    /// Iterates over the ranges, sorted by their start.
+    #[must_use]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &DateRange> {
+        self.ranges.iter()
+    }
+
+    /// Returns `true` if any range contains the date.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.containing(date).next().is_some()
+    }
+
+    /// All ranges that contain the date (a stabbing query), sorted by their start.
+    pub fn containing(&self, date: Date) -> impl Iterator<Item = &DateRange> {
+        self.candidates(date, date)
+            .filter(move |range| range.contains(date))
+    }
+
+    /// All ranges that have at least one day in common with `range`, sorted by their start.
+    pub fn overlapping(&self, range: DateRange) -> impl Iterator<Item = &DateRange> {
+        self.candidates(range.start(), range.end())
+            .filter(move |r| r.overlaps(&range))
+    }
+
+    /// Ranges that might overlap `first..=last`: the ones starting on or before `last`,
+    /// without the leading ones that all end before `first`.
+    fn candidates(&self, first: Date, last: Date) -> impl Iterator<Item = &DateRange> {
+        let from = self.max_ends.partition_point(|end| *end < first);
+        let to = self.ranges.partition_point(|r| r.start() <= last);
+
+        self.ranges[from..to.max(from)].iter()
+    }
+
+    fn update_max_ends(&mut self, from: usize) {
+        self.max_ends.truncate(from);
+
+        for range in &self.ranges[from..] {
+            let end = match self.max_ends.last() {
+                Some(&max) => max.max(range.end()),
+                None => range.end(),
+            };
+
+            self.max_ends.push(end);
+        }
+    }
+}
+
+impl Extend<DateRange> for DateIntervalSet {
+    fn extend<T: IntoIterator<Item = DateRange>>(&mut self, iter: T) {
+        self.ranges.extend(iter);
+        self.ranges.sort_unstable();
+        self.update_max_ends(0);
+    }
+}
+
+impl FromIterator<DateRange> for DateIntervalSet {
+    fn from_iter<T: IntoIterator<Item = DateRange>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    fn range(start: Date, end: Date) -> DateRange {
+        DateRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn queries_match_a_linear_scan() {
+        let long = range(date!(M020 - 01 - 01), date!(M020 - 13 - 29));
+        let ranges = [
+            range(date!(M020 - 02 - 01), date!(M020 - 02 - 07)),
+            long,
+            range(date!(M020 - 05 - 10), date!(M020 - 05 - 12)),
+            range(date!(M020 - 05 - 11), date!(M021 - 01 - 03)),
+            range(date!(M020 - 05 - 11), date!(M021 - 01 - 03)),
+            DateRange::single(date!(M021 - 01 - 05)),
+        ];
+
+        let mut set: DateIntervalSet = ranges[..3].iter().copied().collect();
+        for r in &ranges[3..] {
+            set.insert(*r);
+        }
+
+        let first = date!(M019 - 13 - 25).as_julian();
+        let last = date!(M021 - 01 - 10).as_julian();
+
+        for date in (first..=last).map(|j| Date::from_julian(j).unwrap()) {
+            let mut expected: Vec<_> = ranges.iter().filter(|r| r.contains(date)).collect();
+            expected.sort();
+
+            assert_eq!(set.containing(date).collect::<Vec<_>>(), expected, "{date}");
+
+            let week = range(date, date.add_days(6).unwrap());
+            assert_eq!(
+                set.overlapping(week).count(),
+                ranges.iter().filter(|r| r.overlaps(&week)).count(),
+                "{date}"
+            );
+        }
+
+        assert!(set.remove(&long));
+        assert!(!set.remove(&long));
+        assert!(!set.contains(date!(M020 - 01 - 01)));
+        assert_eq!(set.len(), 5);
+    }
+
+    #[test]
+    fn coalesce_merges_adjacent_ranges() {
+        let mut set: DateIntervalSet = [
+            range(date!(M020 - 01 - 08), date!(M020 - 01 - 14)),
+            range(date!(M020 - 01 - 01), date!(M020 - 01 - 07)),
+            range(date!(M020 - 01 - 03), date!(M020 - 01 - 04)),
+            range(date!(M020 - 01 - 16), date!(M020 - 01 - 20)),
+        ]
+        .into_iter()
+        .collect();
+
+        set.coalesce();
+
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [
+                range(date!(M020 - 01 - 01), date!(M020 - 01 - 14)),
+                range(date!(M020 - 01 - 16), date!(M020 - 01 - 20)),
+            ]
+        );
+        assert!(set.contains(date!(M020 - 01 - 20)));
+        assert!(!set.contains(date!(M020 - 01 - 15)));
+    }
+}
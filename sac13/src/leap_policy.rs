@@ -0,0 +1,40 @@
+/// How to handle a day that doesn't exist in the target month, after moving a date
+/// to a different month or year.
+///
+/// In SAC13 this only affects the 29th: August 29th (the leap day) moved to a common year,
+/// or Addenduary 29th (the year day) moved to a 28-day month. All methods that move a date
+/// while keeping the day, like [`Date::add_months_with()`](crate::Date::add_months_with())
+/// and [`Date::anniversary()`](crate::Date::anniversary()), take a `LeapPolicy`, so the
+/// handling is consistent across the crate.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{LeapPolicy, date, year};
+///
+/// let leap_day = date!(M021 - 06 - 29);
+///
+/// assert_eq!(leap_day.anniversary(year!(M022), LeapPolicy::Reject), None);
+/// assert_eq!(
+///     leap_day.anniversary(year!(M022), LeapPolicy::ClampToPrevious),
+///     Some(date!(M022 - 06 - 28))
+/// );
+/// assert_eq!(
+///     leap_day.anniversary(year!(M022), LeapPolicy::RollToNext),
+///     Some(date!(M022 - 07 - 01))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LeapPolicy {
+    /// The operation fails (returns `None`).
+    Reject,
+
+    /// The day is clamped to the last day of the month (the 28th).
+    /// This is the behavior of [`Date::add_months()`](crate::Date::add_months())
+    /// and [`Date::add_years()`](crate::Date::add_years()).
+    #[default]
+    ClampToPrevious,
+
+    /// The date rolls over to the first day of the following month.
+    RollToNext,
+}
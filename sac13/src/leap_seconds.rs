@@ -0,0 +1,171 @@
+use crate::{Date, DateTime};
+
+/// Time scale of the seconds of a [`DateTime`].
+///
+/// [`DateTime`] itself has no notion of time scales: every day has exactly 86'400 seconds.
+/// That's exact for TAI and GPS time, but UTC days with a leap second have 86'401 seconds.
+/// Use [`DateTime::convert_time_scale()`] to convert between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, with leap seconds.
+    Utc,
+
+    /// International Atomic Time.
+    Tai,
+
+    /// GPS time, a constant 19 seconds behind TAI.
+    Gps,
+}
+
+impl TimeScale {
+    /// Seconds to add to GPS time to get TAI.
+    pub const TAI_MINUS_GPS: i32 = 19;
+}
+
+/// Source of the difference between TAI and UTC (the accumulated leap seconds).
+///
+/// The crate doesn't ship a leap second table, since it changes independently of releases.
+/// Implement this trait for an up-to-date source, or use a slice of
+/// `(first UTC date, TAI - UTC)` entries sorted by date (e.g. from IERS Bulletin C).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Date, DateTime, LeapSeconds, TimeScale, date};
+///
+/// // the leap second at the end of 2016-12-31
+/// let table: &[(Date, i32)] = &[(date!(M016 - 11 - 07), 36), (date!(M016 - 11 - 08), 37)];
+///
+/// assert_eq!(table.tai_minus_utc(date!(M016 - 11 - 07)), Some(36));
+/// assert_eq!(table.tai_minus_utc(date!(M020 - 01 - 01)), Some(37));
+/// assert_eq!(table.tai_minus_utc(date!(M010 - 01 - 01)), None);
+///
+/// let utc = DateTime::from_hms(date!(M020 - 01 - 01), 12, 0, 0).unwrap();
+/// let tai = utc.convert_time_scale(TimeScale::Utc, TimeScale::Tai, table).unwrap();
+///
+/// assert_eq!((tai.hour(), tai.minute(), tai.second()), (12, 0, 37));
+/// ```
+pub trait LeapSeconds {
+    /// TAI - UTC in seconds during the given UTC date, or `None` if it's unknown.
+    fn tai_minus_utc(&self, date: Date) -> Option<i32>;
+}
+
+impl LeapSeconds for [(Date, i32)] {
+    /// The value of the last entry starting on or before the date.
+    fn tai_minus_utc(&self, date: Date) -> Option<i32> {
+        let index = self.partition_point(|(start, _)| *start <= date);
+        index.checked_sub(1).map(|i| self[i].1)
+    }
+}
+
+impl DateTime {
+    /// Converts the date and time from one [time scale](TimeScale) to another.
+    ///
+    /// Conversions between UTC and the other time scales use the user-supplied
+    /// [leap seconds](LeapSeconds).
+    ///
+    /// Returns `None` if the result is outside the SAC13 range, if the leap seconds are
+    /// unknown for the date, or if the instant is an inserted leap second (`23:59:60` UTC),
+    /// which can't be represented.
+    #[must_use]
+    pub fn convert_time_scale<L: LeapSeconds + ?Sized>(
+        self,
+        from: TimeScale,
+        to: TimeScale,
+        leap_seconds: &L,
+    ) -> Option<Self> {
+        if from == to {
+            return Some(self);
+        }
+
+        let tai = match from {
+            TimeScale::Tai => self,
+            TimeScale::Gps => self.add_seconds(TimeScale::TAI_MINUS_GPS)?,
+            TimeScale::Utc => self.add_seconds(leap_seconds.tai_minus_utc(self.date())?)?,
+        };
+
+        match to {
+            TimeScale::Tai => Some(tai),
+            TimeScale::Gps => tai.add_seconds(-TimeScale::TAI_MINUS_GPS),
+            TimeScale::Utc => {
+                // the UTC date determines the offset, but it's unknown yet
+                let guess = tai.add_seconds(-leap_seconds.tai_minus_utc(tai.date())?)?;
+                let utc = tai.add_seconds(-leap_seconds.tai_minus_utc(guess.date())?)?;
+
+                // instants within inserted leap seconds don't round trip
+                (utc.add_seconds(leap_seconds.tai_minus_utc(utc.date())?) == Some(tai))
+                    .then_some(utc)
+            }
+        }
+    }
+
+    fn add_seconds(self, seconds: i32) -> Option<Self> {
+        let seconds_per_day = Self::SECONDS_PER_DAY.cast_signed();
+        let total = self.seconds_of_day().cast_signed() + seconds;
+
+        let date = self.date().add_days(total.div_euclid(seconds_per_day))?;
+        Self::new(date, total.rem_euclid(seconds_per_day).cast_unsigned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    const TABLE: &[(Date, i32)] = &[(date!(M016 - 11 - 07), 36), (date!(M016 - 11 - 08), 37)];
+
+    fn hms(date: Date, h: u8, m: u8, s: u8) -> DateTime {
+        DateTime::from_hms(date, h, m, s).unwrap()
+    }
+
+    #[test]
+    fn inserted_leap_second() {
+        assert_eq!(Date::from_gregorian_ymd(2017, 1, 1), Some(TABLE[1].0));
+
+        let convert = |dt: DateTime, from, to| dt.convert_time_scale(from, to, TABLE);
+
+        let before = hms(date!(M016 - 11 - 07), 23, 59, 59);
+        let after = hms(date!(M016 - 11 - 08), 0, 0, 0);
+
+        let tai_before = convert(before, TimeScale::Utc, TimeScale::Tai).unwrap();
+        let tai_after = convert(after, TimeScale::Utc, TimeScale::Tai).unwrap();
+
+        assert_eq!(tai_before, hms(date!(M016 - 11 - 08), 0, 0, 35));
+        assert_eq!(tai_after, hms(date!(M016 - 11 - 08), 0, 0, 37));
+
+        // 23:59:60 UTC
+        let leap_second = hms(date!(M016 - 11 - 08), 0, 0, 36);
+        assert_eq!(convert(leap_second, TimeScale::Tai, TimeScale::Utc), None);
+
+        for (utc, tai) in [(before, tai_before), (after, tai_after)] {
+            assert_eq!(convert(tai, TimeScale::Tai, TimeScale::Utc), Some(utc));
+
+            let gps = convert(utc, TimeScale::Utc, TimeScale::Gps).unwrap();
+            assert_eq!(convert(gps, TimeScale::Gps, TimeScale::Tai), Some(tai));
+            assert_eq!(convert(gps, TimeScale::Gps, TimeScale::Utc), Some(utc));
+        }
+    }
+
+    #[test]
+    fn limits_and_unknown_dates() {
+        let max = hms(Date::MAX, 23, 59, 59);
+
+        assert_eq!(
+            max.convert_time_scale(TimeScale::Utc, TimeScale::Tai, TABLE),
+            None
+        );
+        assert_eq!(
+            hms(date!(M000 - 01 - 01), 0, 0, 0).convert_time_scale(
+                TimeScale::Utc,
+                TimeScale::Gps,
+                TABLE
+            ),
+            None
+        );
+        assert_eq!(
+            max.convert_time_scale(TimeScale::Gps, TimeScale::Gps, TABLE),
+            Some(max)
+        );
+    }
+}
@@ -22,6 +22,9 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // TODO: features: serde, std, alloc, macros, formatting, wasm?, chrono, time
 // TODO: maybe more const?
 // TODO: difference between dates (in days)
@@ -43,19 +46,57 @@ extern crate std;
 /// let year_zero = year!(A000);
 /// let last_year = year!(Z999);
 ///
-/// // The following lines are invalid years (or format) and would fail during compilation:
-/// // let year = year!(m020);
-/// // let year = year!(20020);
+/// // The millennium letter can also be lowercase and the year can be a plain number:
+/// assert_eq!(year!(m020), year);
+/// assert_eq!(year!(12020), year);
+///
+/// // The following lines are invalid years (or format) and would fail during compilation
+/// // with an error that lists the accepted forms:
+/// // let year = year!(M20);
+/// // let year = year!(M0200);
+/// // let year = year!(26000);
 /// // let year = year!(-100);
-/// // let year = year!(20);
 /// ```
+///
+/// Note that a plain number is the SAC13 year, so `year!(2020)` is C020 and not the
+/// year that contains the Gregorian year 2020 (M020).
 #[macro_export]
 macro_rules! year {
-    ($year:ident) => {
-        const {
-            $crate::Year::try_from_str(core::stringify!($year))
-                .expect(concat!("Invalid SAC13 year: ", stringify!($year)))
-        }
+    ($year:ident) => {{
+        const YEAR: $crate::Year = match $crate::Year::__from_macro_ident(stringify!($year)) {
+            Some(year) => year,
+            None => ::core::panic!(concat!(
+                "Invalid SAC13 year `",
+                stringify!($year),
+                "`: expected a millennium letter and three digits like `M020` or `m020`"
+            )),
+        };
+
+        YEAR
+    }};
+    ($year:literal) => {{
+        const YEAR: $crate::Year = {
+            #[allow(clippy::zero_prefixed_literal)]
+            let y: i64 = $year;
+
+            match $crate::Year::__from_macro_number(y) {
+                Some(year) => year,
+                None => ::core::panic!(concat!(
+                    "Invalid SAC13 year `",
+                    stringify!($year),
+                    "`: plain numbers must be from 0 to 25999 (e.g. `12020` for M020)"
+                )),
+            }
+        };
+
+        YEAR
+    }};
+    ($($input:tt)*) => {
+        compile_error!(concat!(
+            "Invalid `year!(",
+            stringify!($($input)*),
+            ")`: expected `year!(M020)`, `year!(m020)` or `year!(12020)`"
+        ))
     };
 }
 
@@ -106,34 +147,180 @@ macro_rules! date_greg {
 /// let date = date!(M020 - 13 - 29); // year day
 /// let date = date!(M021 - 06 - 29); // leap day
 ///
+/// // the month can also be given by its name
+/// assert_eq!(date!(M020 - Addenduary - 29), date!(M020 - 13 - 29));
+///
+/// // and the date by its day of the year (starting with 1)
+/// assert_eq!(date!(M020 ~ 365), date!(M020 - 13 - 29));
+/// assert_eq!(date!(M020 ~ 123), date!(M020 - 05 - 11));
+///
 /// // the following lines would not compile
 ///
 /// // date = date!(M022 - 06 - 29); // M022 is not a leap year
 /// // date = date!(M022 - 04 - 29); // No month except August on leap years and Addenduary have more than 28 days
+/// // date = date!(M022 - Addendary - 01); // Unknown month name
+/// // date = date!(M022 ~ 366); // M022 is not a leap year
 ///
 /// ```
 #[macro_export]
 macro_rules! date {
-    ($year:ident - $month:literal - $day:literal) => {
-        const {
-            let y = $crate::year!($year);
-
+    ($year:ident - $month:literal - $day:literal) => {{
+        const DATE: $crate::Date = {
             #[allow(clippy::zero_prefixed_literal)]
             let m = $month;
 
+            let Some(m) = $crate::Month::new(m) else {
+                ::core::panic!(concat!(
+                    "Invalid SAC13 month `",
+                    stringify!($month),
+                    "`: expected a number from 1 to 13 or a name like `Addenduary`"
+                ));
+            };
+
+            $crate::__date_from_ymd!($year, m, $day, $year - $month - $day)
+        };
+
+        DATE
+    }};
+    ($year:ident - $month:ident - $day:literal) => {{
+        const DATE: $crate::Date =
+            $crate::__date_from_ymd!($year, $crate::Month::$month, $day, $year - $month - $day);
+
+        DATE
+    }};
+    ($year:ident ~ $ordinal:literal) => {{
+        const DATE: $crate::Date = {
+            let y = $crate::year!($year);
+
             #[allow(clippy::zero_prefixed_literal)]
-            let d = $day;
+            let o = $ordinal;
+
+            let Some(ordinal) = $crate::day_counts::YearOrdinal::from_ordinal(y, o) else {
+                ::core::panic!(concat!(
+                    "Invalid SAC13 day of the year `",
+                    stringify!($ordinal),
+                    "`: expected 1 to 365 (366 in leap years) for `",
+                    stringify!($year),
+                    "`"
+                ));
+            };
 
-            let m = $crate::Month::new(m).expect(concat!(
-                "Month must be a value from 1 - 13. Given: ",
-                $month
-            ));
+            ordinal.to_date()
+        };
 
-            $crate::Date::from_ymd(y, m, d).expect("The given input was not a valid SAC13 date")
+        DATE
+    }};
+    ($($input:tt)*) => {
+        compile_error!(concat!(
+            "Invalid `date!(",
+            stringify!($($input)*),
+            ")`: expected `date!(M020 - 05 - 21)`, `date!(M020 - May - 21)` or `date!(M020 ~ 123)`"
+        ))
+    };
+}
+
+/// Used by `date!()` to check the day and create the date with a descriptive error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __date_from_ymd {
+    ($year:ident, $month:expr, $day:literal, $($given:tt)*) => {{
+        let y = $crate::year!($year);
+        let m = $month;
+
+        #[allow(clippy::zero_prefixed_literal)]
+        let d = $day;
+
+        match $crate::Date::from_ymd(y, m, d) {
+            Some(date) => date,
+            None if d == 0 || d > 29 => ::core::panic!(concat!(
+                "Invalid SAC13 day `",
+                stringify!($day),
+                "`: expected a number from 1 to 29"
+            )),
+            None if matches!(m, $crate::Month::August) => ::core::panic!(concat!(
+                "Invalid SAC13 date `",
+                stringify!($($given)*),
+                "`: `",
+                stringify!($year),
+                "` isn't a leap year, so August has no 29th day"
+            )),
+            None => ::core::panic!(concat!(
+                "Invalid SAC13 date `",
+                stringify!($($given)*),
+                "`: only Addenduary and August (in leap years) have a 29th day"
+            )),
+        }
+    }};
+}
+
+/// An array of [SAC13 dates](Date), parsed at compile time from strings in the canonical format.
+///
+/// Every string must be accepted by [`Date::from_canonical_str()`], otherwise compilation fails.
+/// This allows embedding tables of dates (e.g. holidays) as text.
+///
+/// # Example
+///
+/// ```
+/// use sac13::{Date, date, parse_table};
+///
+/// const HOLIDAYS: [Date; 3] = parse_table!["M020-01-01", "M020-06-15", "M020-13-29"];
+///
+/// assert_eq!(HOLIDAYS[1], date!(M020 - 06 - 15));
+///
+/// // the following line would not compile
+///
+/// // const INVALID: [Date; 1] = parse_table!["M022-06-29"];
+/// ```
+#[macro_export]
+macro_rules! parse_table {
+    ($($date:literal),* $(,)?) => {
+        const {
+            [$(
+                match $crate::Date::from_canonical_str($date) {
+                    ::core::option::Option::Some(date) => date,
+                    ::core::option::Option::None => {
+                        ::core::panic!(concat!("not a SAC13 date in the canonical format: ", $date))
+                    }
+                }
+            ),*]
         }
     };
 }
 
+/// The Gregorian [weekday](Weekday) of a statically known [SAC13 date](Date).
+///
+/// Accepts the same input as [`date!`] and evaluates at compile-time, so it can be used
+/// to build constant tables.
+///
+/// # Example
+///
+/// ```
+/// use sac13::{Weekday, date, weekday};
+///
+/// const OPENING_DAYS: [(sac13::Date, Weekday); 2] = [
+///     (date!(M000 - 03 - 01), weekday!(M000 - 03 - 01)),
+///     (date!(M000 - 03 - 07), weekday!(M000 - 03 - 07)),
+/// ];
+///
+/// assert_eq!(OPENING_DAYS[0].1, Weekday::Monday);
+/// assert_eq!(OPENING_DAYS[1].1, Weekday::Sunday);
+/// ```
+///
+/// Month names and day-of-year ordinals work as well:
+///
+/// ```
+/// use sac13::{Weekday, weekday};
+///
+/// assert_eq!(weekday!(M000 - May - 07), Weekday::Sunday);
+/// assert_eq!(weekday!(M000 ~ 63), Weekday::Sunday);
+/// ```
+#[macro_export]
+macro_rules! weekday {
+    ($($input:tt)*) => {
+        const { $crate::date!($($input)*).weekday() }
+    };
+}
+
 macro_rules! ok {
     ($opt:expr) => {
         match $opt {
@@ -152,35 +339,187 @@ pub enum YearType {
     Leap,
 }
 
+impl YearType {
+    /// Number of days of a year of this type (365 or 366).
+    #[must_use]
+    pub const fn days(self) -> u16 {
+        match self {
+            Self::Common => 365,
+            Self::Leap => 366,
+        }
+    }
+
+    /// Lowercase english name of the year type ("common" or "leap").
+    #[cfg(feature = "names")]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Common => "common",
+            Self::Leap => "leap",
+        }
+    }
+}
+
+#[cfg(feature = "names")]
+impl core::fmt::Display for YearType {
+    /// Displays the lowercase english name of the year type.
+    ///
+    /// ```
+    /// use sac13::year;
+    ///
+    /// assert_eq!(format!("{} year", year!(M021).year_type()), "leap year");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(feature = "astro")]
+mod astro;
+
+mod builder;
+pub mod compat;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
 mod date_gregorian;
 mod date_sac13;
-mod epoch_day;
+mod date_set;
+mod datetime;
+mod day_of_month;
+pub mod daycount;
+mod dual;
+mod duration;
+
+#[cfg(any(test, feature = "exhaustive"))]
+pub mod exhaustive;
+
+#[cfg(feature = "extended")]
+mod extended_day;
+
+pub mod fixedwidth;
+mod format;
+mod granularity;
+pub mod grouping;
+
+#[cfg(feature = "names")]
+mod humanize;
+
+pub mod interop;
+
+#[cfg(feature = "alloc")]
+mod interval_set;
+
+mod leap_policy;
+mod leap_seconds;
+
 mod month;
+mod month_day_set;
 mod parse;
+mod pay_periods;
+mod period;
+mod range;
+
+#[cfg(feature = "relative")]
+mod relative;
+
 mod scalars;
+mod spec_checks;
+mod sync_days;
+
+#[cfg(feature = "wasm")]
+pub mod temporal;
+
+#[cfg(feature = "tzdb")]
+mod time_zone;
 
 #[cfg(all(test, feature = "std"))]
 mod tests;
 
 mod traits;
+mod utc_offset;
 mod weekday;
+mod weekend;
+mod year_table;
 
 pub mod prelude;
 
 /// Primitive types for linear day counts like the [Julian Day Number](crate::scalars::JulianDay).
 pub mod day_counts {
     pub use crate::date_sac13::raw_date::YearOrdinal;
-    pub use crate::scalars::{CycleEpochDay, JulianDay, Sac13Day, UnixDay};
+    pub use crate::scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay};
+
+    #[cfg(feature = "extended")]
+    pub use crate::extended_day::{DayOutOfRange, ExtendedDay};
 }
 
 pub use parse::ComponentOrder;
+pub use parse::DateInterpretation;
 pub use parse::GregorianOrSac13;
+pub use parse::ParseError;
+pub use parse::ParseErrorKind;
+pub use parse::ParseOptions;
 pub use parse::ParsedDate;
+pub use parse::ParsedDateTime;
+pub use parse::ParsedTime;
+pub use parse::Separator;
+pub use parse::parse_date_bytes;
 pub use parse::parse_date_str;
+pub use parse::parse_date_str_with;
+pub use parse::parse_datetime_str;
+pub use parse::parse_datetime_str_with;
+pub use parse::try_interpret_date_str;
+pub use parse::try_parse_date_str_with;
+pub use parse::try_parse_datetime_str_with;
 
-pub use date_gregorian::GregorianDate;
+#[cfg(feature = "relative")]
+pub use relative::parse_relative;
+
+pub use builder::{DateBuilder, DateBuilderError};
+pub use date_gregorian::{
+    GregorianDate, GregorianYearFormat, gregorian_month_span, sac13_month_span_in_gregorian,
+};
 pub use date_sac13::Date;
-pub use scalars::Year;
+pub use date_set::YearDateSet;
+
+#[cfg(feature = "alloc")]
+pub use date_set::DateSet;
+
+pub use datetime::DateTime;
+pub use day_of_month::DayOfMonth;
+pub use dual::Dual;
+pub use duration::CalendarDuration;
+pub use format::{DateFormat, FieldOverflow, LetterCase, Overflow, format_all};
+pub use granularity::Granularity;
+
+#[cfg(feature = "alloc")]
+pub use interval_set::DateIntervalSet;
+
+#[cfg(feature = "names")]
+pub use humanize::HumanizedRelative;
+
+pub use leap_policy::LeapPolicy;
+pub use leap_seconds::{LeapSeconds, TimeScale};
+pub use pay_periods::{PayFrequency, SyncDayPeriod, pay_periods};
+pub use period::{FiscalYear, Quarter, Week, YearMonth};
+pub use range::{DateChunks, DateRange};
+pub use scalars::{RangeStatus, Year};
+pub use sync_days::SyncDays;
 pub use traits::CalendarDate;
+pub use traits::DateIter;
+pub use traits::Datelike;
+pub use utc_offset::UtcOffset;
+pub use year_table::{YearStartTable, year_start_table};
+
+/// The time zone database used by [`DateTime::from_timestamp_in()`].
+#[cfg(feature = "tzdb")]
+pub use tzdb;
 
 pub use month::Month;
+pub use month_day_set::MonthDaySet;
+pub use weekday::Weekday;
+pub use weekend::WeekendDef;
+
+#[cfg(feature = "names")]
+pub use weekday::ParseWeekdayError;
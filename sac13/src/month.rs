@@ -1,5 +1,8 @@
+#[cfg(feature = "names")]
 use core::fmt::Display;
 
+use crate::YearType;
+
 /// Represents a month on the SAC13 calendar.
 ///
 /// Months are practically the same as in the Gregorian Calendar.
@@ -60,6 +63,52 @@ impl Month {
         self as u8
     }
 
+    /// Displays the ordinal number of the month, zero-padded to two digits (e.g. `05` for July).
+    ///
+    /// Also available without the `names` feature, e.g. for custom numeric layouts.
+    ///
+    /// ```
+    /// use sac13::Month;
+    ///
+    /// assert_eq!(format!("{}", Month::July.as_number()), "05");
+    /// assert_eq!(format!("{}", Month::Addenduary.as_number()), "13");
+    /// ```
+    #[must_use]
+    pub const fn as_number(self) -> impl core::fmt::Display + use<> {
+        struct Number(u8);
+
+        impl core::fmt::Display for Number {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:02}", self.0)
+            }
+        }
+
+        Number(self.ord())
+    }
+
+    /// Number of days of the year before the first day of the month.
+    ///
+    /// Every month before adds 28 days, plus one for the leap day (August 29th) in leap years.
+    ///
+    /// ```
+    /// use sac13::{Month, YearType};
+    ///
+    /// assert_eq!(Month::March.cumulative_days_before(YearType::Leap), 0);
+    /// assert_eq!(Month::August.cumulative_days_before(YearType::Leap), 140);
+    /// assert_eq!(Month::September.cumulative_days_before(YearType::Common), 168);
+    /// assert_eq!(Month::September.cumulative_days_before(YearType::Leap), 169);
+    /// ```
+    #[must_use]
+    pub const fn cumulative_days_before(self, year_type: YearType) -> u16 {
+        let days = (self.ord() as u16 - 1) * 28;
+
+        if matches!(year_type, YearType::Leap) && self.ord() > Self::August.ord() {
+            days + 1
+        } else {
+            days
+        }
+    }
+
     #[must_use]
     pub const fn next(self) -> Self {
         use Month::*;
@@ -105,6 +154,7 @@ impl Month {
     /// Full name of the month _(international, english)_.
     ///
     /// March, April, May, ...
+    #[cfg(feature = "names")]
     #[must_use]
     pub const fn name(self) -> &'static str {
         use Month::*;
@@ -133,6 +183,14 @@ impl Month {
     // }
 }
 
+impl Default for Month {
+    /// The first month of the SAC13 year ([`Month::March`]).
+    fn default() -> Self {
+        Self::March
+    }
+}
+
+#[cfg(feature = "names")]
 impl Display for Month {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.name())
@@ -0,0 +1,247 @@
+use core::iter::FusedIterator;
+
+use crate::{Date, Month};
+
+/// A set of recurring days of the year (month and day), e.g. fixed public holidays.
+///
+/// The set is a fixed bitset of 13 × 29 bits without any allocation, so tables can be
+/// built in const contexts (and stored in flash on embedded targets). Lookups are O(1).
+///
+/// Day 29 can only be added for August (the leap day, which only matches in leap years)
+/// and Addenduary (the year day).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Month, MonthDaySet, date};
+///
+/// const HOLIDAYS: MonthDaySet = MonthDaySet::EMPTY
+///     .with(Month::March, 1)
+///     .with(Month::December, 25)
+///     .with(Month::Addenduary, 29);
+///
+/// assert!(HOLIDAYS.contains(date!(M020 - 10 - 25)));
+/// assert!(HOLIDAYS.contains(date!(M021 - 13 - 29)));
+/// assert!(!HOLIDAYS.contains(date!(M021 - 13 - 28)));
+/// assert_eq!(HOLIDAYS.len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MonthDaySet {
+    /// Bit `day - 1` of `months[month - 1]`.
+    months: [u32; 13],
+}
+
+impl MonthDaySet {
+    /// The set without any days.
+    pub const EMPTY: Self = Self { months: [0; 13] };
+
+    /// Every day of the year (including both synchronization days).
+    pub const ALL: Self = {
+        let mut months = [0; 13];
+        let mut i = 0;
+
+        while i < 13 {
+            months[i] = if i == 5 || i == 12 {
+                0x1fff_ffff
+            } else {
+                0x0fff_ffff
+            };
+            i += 1;
+        }
+
+        Self { months }
+    };
+
+    /// Returns the set with the given day added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the day doesn't exist in the month (in any year). In const contexts
+    /// this is a compile time error.
+    #[must_use]
+    pub const fn with(mut self, month: Month, day: u8) -> Self {
+        assert!(
+            day >= 1 && day <= max_day(month),
+            "the day doesn't exist in the month"
+        );
+
+        self.months[month.ord() as usize - 1] |= 1 << (day - 1);
+        self
+    }
+
+    /// Returns the set with the given day removed.
+    #[must_use]
+    pub const fn without(mut self, month: Month, day: u8) -> Self {
+        if day >= 1 && day <= 29 {
+            self.months[month.ord() as usize - 1] &= !(1 << (day - 1));
+        }
+
+        self
+    }
+
+    /// Adds the day to the set. Returns `false` if the day doesn't exist in the month.
+    pub const fn insert(&mut self, month: Month, day: u8) -> bool {
+        if day == 0 || day > max_day(month) {
+            return false;
+        }
+
+        self.months[month.ord() as usize - 1] |= 1 << (day - 1);
+        true
+    }
+
+    /// Returns `true` if the set contains the month and day.
+    #[must_use]
+    pub const fn contains_month_day(&self, month: Month, day: u8) -> bool {
+        day >= 1 && day <= 29 && self.months[month.ord() as usize - 1] & (1 << (day - 1)) != 0
+    }
+
+    /// Returns `true` if the set contains the month and day of the date.
+    #[must_use]
+    pub const fn contains(&self, date: Date) -> bool {
+        self.contains_month_day(date.month(), date.day())
+    }
+
+    /// Days contained in either set.
+    #[must_use]
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut i = 0;
+
+        while i < 13 {
+            self.months[i] |= other.months[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Days contained in both sets.
+    #[must_use]
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut i = 0;
+
+        while i < 13 {
+            self.months[i] &= other.months[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Days of this set that aren't contained in `other`.
+    #[must_use]
+    pub const fn difference(mut self, other: Self) -> Self {
+        let mut i = 0;
+
+        while i < 13 {
+            self.months[i] &= !other.months[i];
+            i += 1;
+        }
+
+        self
+    }
+
+    /// Number of days in the set.
+    #[must_use]
+    pub const fn len(&self) -> u32 {
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < 13 {
+            len += self.months[i].count_ones();
+            i += 1;
+        }
+
+        len
+    }
+
+    /// Returns `true` if the set doesn't contain any days.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the month and day pairs in calendar order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Month, u8)> + FusedIterator + use<> {
+        let months = self.months;
+
+        (1..=13).filter_map(Month::new).flat_map(move |month| {
+            let bits = months[month.ord() as usize - 1];
+            (1..=29)
+                .filter(move |day| bits & (1 << (day - 1)) != 0)
+                .map(move |day| (month, day))
+        })
+    }
+}
+
+impl FromIterator<(Month, u8)> for MonthDaySet {
+    /// Collects the month and day pairs into a set. Days that don't exist in their month are ignored.
+    fn from_iter<T: IntoIterator<Item = (Month, u8)>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+
+        for (month, day) in iter {
+            set.insert(month, day);
+        }
+
+        set
+    }
+}
+
+/// Number of days of the month in a leap year.
+const fn max_day(month: Month) -> u8 {
+    match month {
+        Month::August | Month::Addenduary => 29,
+        _ => 28,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    #[test]
+    fn every_day_of_a_leap_year() {
+        let leap_year = date!(M021 - 01 - 01).as_julian()..=date!(M021 - 13 - 29).as_julian();
+
+        assert_eq!(MonthDaySet::ALL.len(), 366);
+        assert!(
+            leap_year
+                .map(|j| Date::from_julian(j).unwrap())
+                .all(|date| MonthDaySet::ALL.contains(date))
+        );
+        assert_eq!(MonthDaySet::ALL.iter().count(), 366);
+        assert_eq!(
+            MonthDaySet::ALL.iter().collect::<MonthDaySet>(),
+            MonthDaySet::ALL
+        );
+        assert!(MonthDaySet::ALL.difference(MonthDaySet::ALL).is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = MonthDaySet::EMPTY.with(Month::March, 1).with(Month::May, 5);
+        let b = MonthDaySet::EMPTY
+            .with(Month::May, 5)
+            .with(Month::August, 29);
+
+        assert_eq!(a.union(b).len(), 3);
+        assert_eq!(a.intersection(b), MonthDaySet::EMPTY.with(Month::May, 5));
+        assert_eq!(a.difference(b), MonthDaySet::EMPTY.with(Month::March, 1));
+        assert_eq!(
+            b.without(Month::August, 29).without(Month::May, 5),
+            MonthDaySet::EMPTY
+        );
+
+        let mut c = MonthDaySet::EMPTY;
+        assert!(!c.insert(Month::March, 29));
+        assert!(!c.insert(Month::March, 0));
+        assert!(c.insert(Month::August, 29));
+
+        assert!(!c.contains(date!(M020 - 07 - 01)));
+        assert!(c.contains(date!(M021 - 06 - 29)));
+        assert_eq!(
+            a.union(b).iter().collect::<std::vec::Vec<_>>(),
+            [(Month::March, 1), (Month::May, 5), (Month::August, 29)]
+        );
+    }
+}
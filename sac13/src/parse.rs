@@ -1,14 +1,76 @@
-use core::{fmt::Display, iter::Peekable};
+use core::{fmt::Display, ops::Range};
 
-use crate::{Date, GregorianDate};
+use crate::{Date, DateTime, GregorianDate, Month, Year, traits::CalendarDate};
 
+mod annotation;
+mod error;
+mod lenient;
+mod time;
+
+pub use annotation::{CALENDAR_ANNOTATION, strip_calendar_annotation};
+
+pub use error::{ParseError, ParseErrorKind};
+pub use time::ParsedTime;
+
+/// A date of either calendar, e.g. the result of [`parse_date_str()`].
+///
+/// Equality, ordering and hashing are based on the day, not the calendar, so the same
+/// day in both calendars is considered equal. This allows sorting and deduplicating
+/// parsed input of mixed formats.
+///
+/// ```
+/// use sac13::{GregorianOrSac13, date, date_greg};
+///
+/// let greg = GregorianOrSac13::GregorianDate(date_greg!(2000 - 03 - 20));
+/// let sac13 = GregorianOrSac13::Sac13Date(date!(M000 - 01 - 01));
+///
+/// assert_eq!(greg, sac13);
+/// assert!(matches!(greg.normalize_to_sac13(), GregorianOrSac13::Sac13Date(_)));
+/// ```
 #[derive(Debug, Clone)]
 pub enum GregorianOrSac13 {
     GregorianDate(GregorianDate),
     Sac13Date(Date),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl GregorianOrSac13 {
+    /// The same day, but always as [`Sac13Date`](Self::Sac13Date).
+    #[must_use]
+    pub fn normalize_to_sac13(self) -> Self {
+        match self {
+            Self::GregorianDate(x) => Self::Sac13Date(x.convert()),
+            sac13 @ Self::Sac13Date(_) => sac13,
+        }
+    }
+}
+
+impl PartialEq for GregorianOrSac13 {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_julian() == other.as_julian()
+    }
+}
+
+impl Eq for GregorianOrSac13 {}
+
+impl PartialOrd for GregorianOrSac13 {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GregorianOrSac13 {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.cmp_by_day(other)
+    }
+}
+
+impl core::hash::Hash for GregorianOrSac13 {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_julian().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ComponentOrder {
     YMD,
     DMY,
@@ -21,9 +83,175 @@ pub struct ParsedDate {
     pub format: ParsedFormat,
 }
 
+impl ParsedDate {
+    /// The parsed date as SAC13 date, converted if necessary.
+    ///
+    /// ```
+    /// use sac13::{date, parse_date_str};
+    ///
+    /// assert_eq!(parse_date_str("2000-03-20").unwrap().to_sac13(), date!(M000 - 01 - 01));
+    /// ```
+    #[must_use]
+    pub fn to_sac13(&self) -> Date {
+        match self.date {
+            GregorianOrSac13::GregorianDate(x) => x.convert(),
+            GregorianOrSac13::Sac13Date(x) => x,
+        }
+    }
+
+    /// The parsed date as Gregorian date, converted if necessary.
+    #[must_use]
+    pub fn to_gregorian(&self) -> GregorianDate {
+        match self.date {
+            GregorianOrSac13::GregorianDate(x) => x,
+            GregorianOrSac13::Sac13Date(x) => x.convert(),
+        }
+    }
+
+    /// Julian Day Number of the parsed date.
+    #[must_use]
+    pub fn as_julian(&self) -> i32 {
+        self.date.as_julian()
+    }
+
+    /// Displays the parsed date (in its original calendar) in a different format,
+    /// e.g. one that was detected when parsing other input.
+    ///
+    /// Day, month and year are padded with leading zeros to the lengths of the format.
+    /// SAC13 years are written with millennium letter, unless the format has a
+    /// five-digit year (see [`ParseOptions::plain_sac13_years`]).
+    ///
+    /// ```
+    /// use sac13::parse_date_str;
+    ///
+    /// let german = parse_date_str("21.3.2020").unwrap();
+    /// let iso = parse_date_str("2024-05-06").unwrap();
+    ///
+    /// assert_eq!(iso.reformat(&german.format).to_string(), "06.5.2024");
+    /// assert_eq!(german.reformat(&iso.format).to_string(), "2020-03-21");
+    /// ```
+    #[must_use]
+    pub fn reformat<'a>(&'a self, format: &'a ParsedFormat) -> impl Display + 'a {
+        Reformatted {
+            date: &self.date,
+            format,
+        }
+    }
+}
+
+/// Result of [`parse_datetime_str()`]: a parsed date and the time of day, if there was one.
+#[derive(Debug, Clone)]
+pub struct ParsedDateTime {
+    pub date: ParsedDate,
+    pub time: Option<ParsedTime>,
+}
+
+impl ParsedDateTime {
+    /// The SAC13 date and time of day (midnight if there was no time).
+    ///
+    /// The UTC offset is ignored, so this is the local date and time as written in the input.
+    #[must_use]
+    pub fn to_sac13_datetime(&self) -> DateTime {
+        let seconds = self.time.map_or(0, |time| time.seconds_of_day());
+
+        let Some(datetime) = DateTime::new(self.date.to_sac13(), seconds) else {
+            unreachable!()
+        };
+
+        datetime
+    }
+}
+
+struct Reformatted<'a> {
+    date: &'a GregorianOrSac13,
+    format: &'a ParsedFormat,
+}
+
+impl Display for Reformatted<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (month, day) = match self.date {
+            GregorianOrSac13::GregorianDate(x) => (x.month(), x.day()),
+            GregorianOrSac13::Sac13Date(x) => (x.month().ord(), x.day()),
+        };
+
+        for (i, &(c, count)) in self.format.components().iter().enumerate() {
+            if i != 0 {
+                write!(f, "{}", self.format.separator)?;
+            }
+
+            let width = usize::from(count);
+
+            match (c, self.date) {
+                ('D', _) => write!(f, "{day:0width$}")?,
+                ('M', _) => write!(f, "{month:0width$}")?,
+                // the sign goes in front of the padded digits, like `-0044`
+                (_, GregorianOrSac13::GregorianDate(x)) if x.year() < 0 => {
+                    write!(f, "-{:0width$}", -x.year())?;
+                }
+                (_, GregorianOrSac13::GregorianDate(x)) => write!(f, "{:0width$}", x.year())?,
+                (_, GregorianOrSac13::Sac13Date(x)) if count == 5 => {
+                    write!(f, "{}", x.year().display_plain())?;
+                }
+                (_, GregorianOrSac13::Sac13Date(x)) => write!(f, "{}", x.year())?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Separator between the components of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// `-`, like in `2020-03-21`.
+    Dash,
+
+    /// `.`, like in `21.03.2020`.
+    Dot,
+
+    /// `/`, like in `03/21/2020`.
+    Slash,
+
+    /// A single space, like in `21 03 2020`.
+    Space,
+
+    /// No separator at all, like in `20200321`.
+    Compact,
+}
+
+impl Separator {
+    /// The separator character, or `None` for [`Compact`](Self::Compact).
+    #[must_use]
+    pub const fn as_char(self) -> Option<char> {
+        match self {
+            Self::Dash => Some('-'),
+            Self::Dot => Some('.'),
+            Self::Slash => Some('/'),
+            Self::Space => Some(' '),
+            Self::Compact => None,
+        }
+    }
+
+    const fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            b'-' => Some(Self::Dash),
+            b'.' => Some(Self::Dot),
+            b'/' => Some(Self::Slash),
+            b' ' => Some(Self::Space),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Separator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_char().map_or(Ok(()), |c| write!(f, "{c}"))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ParsedFormat {
-    pub separator: u8,
+    pub separator: Separator,
     pub component_order: ComponentOrder,
     pub len_day: u8,
     pub len_month: u8,
@@ -33,69 +261,156 @@ pub struct ParsedFormat {
 #[derive(Debug)]
 struct ComponentParse {
     letter: bool,
+    negative: bool,
     char_cnt: u8,
     value: i16,
     end: u8,
+    start: usize,
 }
 
 impl ComponentParse {
-    pub fn parse<T>(i: &mut Peekable<T>) -> Option<Self>
-    where
-        T: Iterator<Item = u8>,
-    {
+    /// Parses the component starting at `pos` and moves `pos` behind its separator.
+    fn parse(input: &[u8], pos: &mut usize) -> Result<Self, ParseError> {
+        let start = *pos;
+
         let mut result = Self {
             letter: false,
+            negative: false,
             value: 0,
             end: 0,
             char_cnt: 0,
+            start,
+        };
+
+        let Some(&first) = input.get(start) else {
+            return Err(ParseError::new(
+                ParseErrorKind::MissingComponent,
+                start..start,
+            ));
         };
 
-        let invert = *i.peek()? == b'-';
+        let invert = first == b'-';
 
         if invert {
-            // consume peeked negative sign
-            _ = i.next();
+            // consume negative sign
+            *pos += 1;
             result.char_cnt += 1;
+            result.negative = true;
         }
 
-        if (b'A'..=b'Z').contains(i.peek()?) {
+        if let Some(&letter @ b'A'..=b'Z') = input.get(*pos) {
             if invert {
                 // negative SAC13 years are not allowed
-                return None;
+                return Err(ParseError::new(
+                    ParseErrorKind::InvalidYear,
+                    start..*pos + 1,
+                ));
             }
 
             // consume and process prefix letter
-            result.value = (i.next().unwrap() - b'A') as i16;
+            *pos += 1;
+            result.value = i16::from(letter - b'A');
             result.letter = true;
             result.char_cnt += 1;
         }
 
-        loop {
-            let x = i.next();
+        while let Some(&x) = input.get(*pos) {
+            *pos += 1;
 
-            let (exit, x) = match x {
-                None => (true, 0),
-                Some(x) => ([b'.', b'/', b'-'].contains(&x), x),
-            };
-
-            if exit {
+            if Separator::from_byte(x).is_some() {
                 result.end = x;
-
-                if invert {
-                    result.value = result.value.checked_mul(-1)?;
-                }
-
-                return Some(result);
+                break;
             }
 
-            if !((b'0'..=b'9').contains(&x)) {
-                return None;
+            if !x.is_ascii_digit() {
+                return Err(ParseError::invalid_character(input, *pos - 1));
             }
 
-            result.char_cnt += 1;
-            result.value = result.value.checked_mul(10)?;
-            result.value = result.value.checked_add((x - b'0') as i16)?;
+            let too_long = || ParseError::new(ParseErrorKind::InvalidLength, start..*pos);
+
+            // leading zeros don't overflow the value, but the count
+            result.char_cnt = result.char_cnt.checked_add(1).ok_or_else(too_long)?;
+            result.value = result
+                .value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(i16::from(x - b'0')))
+                .ok_or_else(too_long)?;
         }
+
+        if invert {
+            result.value = -result.value;
+        }
+
+        Ok(result)
+    }
+
+    /// Byte range of the component (without separator).
+    fn span(&self) -> Range<usize> {
+        self.start..self.start + usize::from(self.char_cnt)
+    }
+}
+
+/// Options to enable non-default behavior of [`parse_date_str_with()`].
+///
+/// The default options behave exactly like [`parse_date_str()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Interpret years with exactly five digits and without millennium letter
+    /// (e.g. `12020-05-21`) as SAC13 years instead of Gregorian years.
+    ///
+    /// Some datasets store the SAC13 year as plain number. Note that with this
+    /// option Gregorian years with five digits can't be parsed anymore.
+    pub plain_sac13_years: bool,
+
+    /// Reject Gregorian dates with the year last whose day and month could be swapped
+    /// (e.g. `04/05/2020`) with [`ParseErrorKind::Ambiguous`] instead of reading them as
+    /// month-day-year (with `/`) or day-month-year (with other separators).
+    ///
+    /// Use [`try_interpret_date_str()`] to get both readings instead, e.g. to ask the user.
+    pub reject_ambiguous: bool,
+
+    /// Accept look-alike characters that word processors and other documents produce
+    /// and replace them with their ASCII counterparts before parsing:
+    ///
+    /// - dashes and minus signs (e.g. `–`, `−`) as `-`
+    /// - no-break and other wide spaces as a regular space
+    /// - full-width digits, letters, `.`, `/`, `-` and `:` (e.g. `２０２０`)
+    ///
+    /// The [span](ParseError::span) of an error still refers to the original input.
+    pub lenient_unicode: bool,
+}
+
+impl ParseOptions {
+    /// The default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            plain_sac13_years: false,
+            reject_ambiguous: false,
+            lenient_unicode: false,
+        }
+    }
+
+    /// Sets [`plain_sac13_years`](Self::plain_sac13_years).
+    #[must_use]
+    pub const fn plain_sac13_years(mut self, value: bool) -> Self {
+        self.plain_sac13_years = value;
+        self
+    }
+
+    /// Sets [`reject_ambiguous`](Self::reject_ambiguous).
+    #[must_use]
+    pub const fn reject_ambiguous(mut self, value: bool) -> Self {
+        self.reject_ambiguous = value;
+        self
+    }
+
+    /// Sets [`lenient_unicode`](Self::lenient_unicode).
+    #[must_use]
+    pub const fn lenient_unicode(mut self, value: bool) -> Self {
+        self.lenient_unicode = value;
+        self
     }
 }
 
@@ -108,93 +423,590 @@ impl ComponentParse {
 ///
 /// - YYYY-MM-DD
 /// - DD-MM-YYYY
+/// - MM/DD/YYYY (Gregorian only)
+/// - YYYYMMDD (compact form without separator, the year must have four characters)
+///
+/// The components can be separated by `-`, `.`, `/` or a single space (see [`Separator`]).
 pub fn parse_date_str(input: &str) -> Option<ParsedDate> {
+    parse_date_str_with(input, ParseOptions::new())
+}
+
+/// Like [`parse_date_str()`] but with [options](ParseOptions) for non-default formats.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{GregorianOrSac13, ParseOptions, date, parse_date_str_with};
+///
+/// let options = ParseOptions::new().plain_sac13_years(true);
+/// let parsed = parse_date_str_with("12020-05-21", options).unwrap();
+///
+/// assert!(matches!(parsed.date, GregorianOrSac13::Sac13Date(d) if d == date!(M020 - 05 - 21)));
+/// ```
+#[must_use]
+pub fn parse_date_str_with(input: &str, options: ParseOptions) -> Option<ParsedDate> {
+    try_parse_date_str_with(input, options).ok()
+}
+
+/// Like [`parse_date_str_with()`], but returns the reason and position of the
+/// problem if the input can't be parsed.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] with the [kind](ParseErrorKind) of the problem and the
+/// byte range of the offending part of the input.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{ParseErrorKind, ParseOptions, try_parse_date_str_with};
+///
+/// let input = "M020-05-2x";
+/// let error = try_parse_date_str_with(input, ParseOptions::new()).unwrap_err();
+///
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidCharacter);
+/// assert_eq!(error.span(), 9..10);
+/// ```
+pub fn try_parse_date_str_with(
+    input: &str,
+    options: ParseOptions,
+) -> Result<ParsedDate, ParseError> {
+    try_parse_datetime_str_with(input, options).map(|parsed| parsed.date)
+}
+
+/// The reading(s) of a date, see [`try_interpret_date_str()`].
+#[derive(Debug, Clone)]
+pub enum DateInterpretation {
+    /// The input can only be read one way.
+    Unambiguous(ParsedDate),
+
+    /// The day and month of a Gregorian date could be swapped (e.g. `04/05/2020`).
+    Ambiguous {
+        /// The day-month-year reading (4 May 2020).
+        dmy: ParsedDate,
+
+        /// The month-day-year reading (5 April 2020).
+        mdy: ParsedDate,
+    },
+}
+
+/// Like [`try_parse_date_str_with()`], but returns both readings of Gregorian dates
+/// whose day and month could be swapped instead of silently picking one, so the
+/// application can ask the user.
+///
+/// A date is ambiguous if the year is the last component and the other two are
+/// different numbers from 1 to 12. SAC13 dates are never ambiguous, because they're
+/// always written day-month-year when the year is last.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] like [`try_parse_date_str_with()`]. The
+/// [`reject_ambiguous`](ParseOptions::reject_ambiguous) option is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateInterpretation, ParseOptions, date_greg, try_interpret_date_str};
+///
+/// let Ok(DateInterpretation::Ambiguous { dmy, mdy }) =
+///     try_interpret_date_str("04/05/2020", ParseOptions::new())
+/// else {
+///     panic!("expected two readings");
+/// };
+///
+/// assert_eq!(dmy.to_gregorian(), date_greg!(2020 - 05 - 04));
+/// assert_eq!(mdy.to_gregorian(), date_greg!(2020 - 04 - 05));
+///
+/// assert!(matches!(
+///     try_interpret_date_str("13.05.2020", ParseOptions::new()),
+///     Ok(DateInterpretation::Unambiguous(_))
+/// ));
+/// ```
+pub fn try_interpret_date_str(
+    input: &str,
+    options: ParseOptions,
+) -> Result<DateInterpretation, ParseError> {
+    if options.lenient_unicode && !input.is_ascii() {
+        return lenient::with_normalized(input, |normalized| {
+            try_interpret_date_str(normalized, options.lenient_unicode(false))
+        });
+    }
+
+    let input = input.as_bytes();
+
+    if let Some(parsed) = parse_canonical(input) {
+        return Ok(DateInterpretation::Unambiguous(parsed));
+    }
+
+    Ok(match parse_date_readings(input, options)? {
+        (parsed, None) => DateInterpretation::Unambiguous(parsed),
+        (mdy, Some(dmy)) if matches!(mdy.format.component_order, ComponentOrder::MDY) => {
+            DateInterpretation::Ambiguous { dmy, mdy }
+        }
+        (dmy, Some(mdy)) => DateInterpretation::Ambiguous { dmy, mdy },
+    })
+}
+
+/// Like [`parse_date_str()`], but also captures the time of day if there is one.
+///
+/// The time follows the date after a `T` or a single space, in the form `HH:MM`,
+/// optionally followed by seconds (`:SS`), fractional seconds (`.fff`, ignored)
+/// and a UTC offset (`Z`, `+HH:MM`, `-HHMM` or `+HH`).
+/// [`parse_date_str()`] accepts the same input, but ignores the time.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, parse_datetime_str};
+///
+/// let parsed = parse_datetime_str("M020-05-21T14:05:30Z").unwrap();
+/// let time = parsed.time.unwrap();
+///
+/// assert_eq!((time.hour, time.minute, time.second), (14, 5, 30));
+/// assert_eq!(time.utc_offset_minutes, Some(0));
+/// assert_eq!(parsed.to_sac13_datetime().date(), date!(M020 - 05 - 21));
+///
+/// assert!(parse_datetime_str("2020-03-21 14:05").is_some());
+/// assert!(parse_datetime_str("2020-03-21").unwrap().time.is_none());
+/// ```
+#[must_use]
+pub fn parse_datetime_str(input: &str) -> Option<ParsedDateTime> {
+    parse_datetime_str_with(input, ParseOptions::new())
+}
+
+/// Like [`parse_datetime_str()`] but with [options](ParseOptions) for non-default formats.
+#[must_use]
+pub fn parse_datetime_str_with(input: &str, options: ParseOptions) -> Option<ParsedDateTime> {
+    try_parse_datetime_str_with(input, options).ok()
+}
+
+/// Like [`parse_datetime_str_with()`], but returns the reason and position of the
+/// problem if the input can't be parsed.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] with the [kind](ParseErrorKind) of the problem and the
+/// byte range of the offending part of the input.
+pub fn try_parse_datetime_str_with(
+    input: &str,
+    options: ParseOptions,
+) -> Result<ParsedDateTime, ParseError> {
+    if options.lenient_unicode && !input.is_ascii() {
+        return lenient::with_normalized(input, |normalized| {
+            try_parse_datetime_str_with(normalized, options.lenient_unicode(false))
+        });
+    }
+
+    let Some(colon) = input.find(':') else {
+        return Ok(ParsedDateTime {
+            date: parse_date_only(input.as_bytes(), options)?,
+            time: None,
+        });
+    };
+
+    // the time starts after the last `T` or space before the first colon
+    let Some(split) = input[..colon].rfind(['T', ' ']) else {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidCharacter,
+            colon..colon + 1,
+        ));
+    };
+
+    let date = parse_date_only(&input.as_bytes()[..split], options)?;
+
+    let time = ParsedTime::parse(&input[split + 1..])
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidTime, split + 1..input.len()))?;
+
+    Ok(ParsedDateTime {
+        date,
+        time: Some(time),
+    })
+}
+
+/// Like [`parse_date_str()`], but for bytes that aren't known to be UTF-8,
+/// e.g. fields of network protocols or records stored in flash.
+///
+/// The parser works on bytes anyway, so there's no need to validate the input with
+/// [`str::from_utf8()`] first. Only dates are supported, without a time of day.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, parse_date_bytes};
+///
+/// assert_eq!(parse_date_bytes(b"M020-05-21").unwrap().to_sac13(), date!(M020 - 05 - 21));
+/// assert_eq!(parse_date_bytes(b"21.05.2020").unwrap().to_gregorian().day(), 21);
+/// assert!(parse_date_bytes(b"M020-05-2\xff").is_none());
+/// ```
+#[must_use]
+pub fn parse_date_bytes(input: &[u8]) -> Option<ParsedDate> {
+    parse_date_only(input, ParseOptions::new()).ok()
+}
+
+fn parse_date_only(input: &[u8], options: ParseOptions) -> Result<ParsedDate, ParseError> {
+    parse_canonical(input).map_or_else(|| parse_date_general(input, options), Ok)
+}
+
+/// Parses all supported layouts, see [`parse_date_str()`].
+fn parse_date_general(input: &[u8], options: ParseOptions) -> Result<ParsedDate, ParseError> {
+    let (parsed, alternative) = parse_date_readings(input, options)?;
+
+    if options.reject_ambiguous && alternative.is_some() {
+        return Err(ParseError::new(ParseErrorKind::Ambiguous, 0..input.len()));
+    }
+
+    Ok(parsed)
+}
+
+/// Parses the date and, if the day and month could be swapped (e.g. `04/05/2020`),
+/// also the other reading.
+fn parse_date_readings(
+    input: &[u8],
+    options: ParseOptions,
+) -> Result<(ParsedDate, Option<ParsedDate>), ParseError> {
     const MIN_YEAR_LENGTH: u8 = 4;
 
-    let mut input = input.as_bytes().iter().copied().peekable();
+    if !input.iter().any(|&b| Separator::from_byte(b).is_some()) {
+        return Ok((parse_compact(input, options)?, None));
+    }
 
-    let c1 = ComponentParse::parse(&mut input)?;
-    let c2 = ComponentParse::parse(&mut input)?;
-    let c3: ComponentParse = ComponentParse::parse(&mut input)?;
+    let mut pos = 0;
 
-    if c1.char_cnt == 3 || c2.char_cnt == 3 || c1.char_cnt == 3 {
-        // No component is allowed to be three digits.
-        // Days and months must be 1 or 2, and years must be 4 or more.
-        return None;
+    let c1 = ComponentParse::parse(input, &mut pos)?;
+    let c2 = ComponentParse::parse(input, &mut pos)?;
+    let c3 = ComponentParse::parse(input, &mut pos)?;
+
+    for c in [&c1, &c2, &c3] {
+        if c.char_cnt == 3 {
+            // No component is allowed to be three digits.
+            // Days and months must be 1 or 2, and years must be 4 or more.
+            return Err(ParseError::new(ParseErrorKind::InvalidLength, c.span()));
+        }
     }
 
     if c1.end != c2.end {
         // different delimiters in same date are not allowed
-        return None;
+        let at = c2.span().end;
+        return Err(ParseError::new(ParseErrorKind::MixedSeparators, at..at + 1));
     }
 
-    let separator = c1.end;
-
     if c3.end != 0 {
         // c3 must be the last component (delimiter zero)
-        return None;
+        return Err(ParseError::new(
+            ParseErrorKind::TrailingInput,
+            c3.span().end..input.len(),
+        ));
     }
 
+    // c2 was found, so c1 ended with a separator
+    let separator = Separator::from_byte(c1.end).ok_or_else(|| {
+        ParseError::new(ParseErrorKind::MissingComponent, input.len()..input.len())
+    })?;
+
     let year_first = c1.char_cnt >= MIN_YEAR_LENGTH;
     let year_last = c3.char_cnt >= MIN_YEAR_LENGTH;
 
     if c2.char_cnt >= MIN_YEAR_LENGTH {
         // middle part is never allowed to be a year
-        return None;
+        return Err(ParseError::new(ParseErrorKind::YearPosition, c2.span()));
     }
 
     if year_first == year_last {
         // either both ends or neither seem to be a year which is not allowed
-        return None;
+        let span = if year_first {
+            c3.span()
+        } else {
+            0..input.len()
+        };
+        return Err(ParseError::new(ParseErrorKind::YearPosition, span));
     }
 
     // determine sort order
     let (year, month, day, order) = if year_first {
-        (c1, c2, c3, ComponentOrder::YMD)
+        (&c1, &c2, &c3, ComponentOrder::YMD)
     } else if c1.end == b'/' && !c3.letter {
         // US format only for Gregorian (no SAC13 millennium indicator letter)
-        (c3, c1, c2, ComponentOrder::MDY)
+        (&c3, &c1, &c2, ComponentOrder::MDY)
     } else {
-        (c3, c2, c1, ComponentOrder::DMY)
+        (&c3, &c2, &c1, ComponentOrder::DMY)
     };
 
-    if day.letter || month.letter {
-        return None;
-    }
+    let parsed = to_parsed_date([year, month, day], order, separator, options)?;
 
-    if !(1..=31).contains(&day.value) || !(1..=13).contains(&month.value) {
-        return None;
+    // Gregorian dates with the year last could be read both ways if both numbers are
+    // valid months, SAC13 dates are always day-month-year
+    let ambiguous = !year_first
+        && !c3.letter
+        && !c1.letter
+        && !c2.letter
+        && c1.value != c2.value
+        && (1..=12).contains(&c1.value)
+        && (1..=12).contains(&c2.value);
+
+    let alternative = if !ambiguous {
+        None
+    } else if matches!(order, ComponentOrder::MDY) {
+        Some(to_parsed_date(
+            [year, day, month],
+            ComponentOrder::DMY,
+            separator,
+            options,
+        )?)
+    } else {
+        Some(to_parsed_date(
+            [year, day, month],
+            ComponentOrder::MDY,
+            separator,
+            options,
+        )?)
+    };
+
+    Ok((parsed, alternative))
+}
+
+/// The date of the components in the order year, month, day.
+fn to_parsed_date(
+    [year, month, day]: [&ComponentParse; 3],
+    order: ComponentOrder,
+    separator: Separator,
+    options: ParseOptions,
+) -> Result<ParsedDate, ParseError> {
+    const PLAIN_SAC13_YEAR_LENGTH: u8 = 5;
+    const SAC13_YEAR_LENGTH: u8 = 4;
+
+    for c in [day, month] {
+        if c.letter {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidCharacter,
+                c.start..c.start + 1,
+            ));
+        }
     }
 
+    let Ok(day_value @ 1..=31) = u8::try_from(day.value) else {
+        return Err(ParseError::new(ParseErrorKind::InvalidDay, day.span()));
+    };
+
+    let Ok(month_value @ 1..=13) = u8::try_from(month.value) else {
+        return Err(ParseError::new(ParseErrorKind::InvalidMonth, month.span()));
+    };
+
     let format = ParsedFormat {
         separator,
         component_order: order,
         len_day: day.char_cnt,
         len_month: month.char_cnt,
-        len_year: year.char_cnt,
+        // without the sign, which is written in front of the padded digits
+        len_year: year.char_cnt - u8::from(year.negative),
     };
 
-    let day = day.value as u8;
-    let month = month.value as u8;
+    let spans = [year.span(), month.span(), day.span()];
 
-    let date = if year.letter {
-        if year.value < 0 {
-            return None;
+    let plain_sac13_year =
+        options.plain_sac13_years && year.char_cnt == PLAIN_SAC13_YEAR_LENGTH && year.value >= 0;
+
+    if year.letter && year.char_cnt != SAC13_YEAR_LENGTH {
+        // the millennium letter is followed by exactly three digits
+        return Err(ParseError::new(ParseErrorKind::InvalidLength, year.span()));
+    }
+
+    let date = if year.letter || plain_sac13_year {
+        let Ok(year_value) = u16::try_from(year.value) else {
+            return Err(ParseError::new(ParseErrorKind::InvalidYear, year.span()));
+        };
+
+        sac13_date(year_value, month_value, day_value, spans)?
+    } else {
+        gregorian_date(year.value, month_value, day_value, spans)?
+    };
+
+    Ok(ParsedDate { date, format })
+}
+
+/// Checks that the input matches a fixed layout like `Lyyy-mm-dd`: `L` stands for an
+/// uppercase letter, other lowercase letters for a digit, everything else for itself.
+///
+/// Reports non-ASCII characters first, then a wrong length and then the first character
+/// that doesn't match, so strict parsers of fixed formats agree on their errors.
+pub fn check_layout(input: &[u8], layout: &[u8]) -> Result<(), ParseError> {
+    if let Some(i) = input.iter().position(|b| !b.is_ascii()) {
+        return Err(ParseError::invalid_character(input, i));
+    }
+
+    if input.len() != layout.len() {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidLength,
+            0..input.len(),
+        ));
+    }
+
+    for (i, (&b, &expected)) in input.iter().zip(layout).enumerate() {
+        let valid = match expected {
+            b'L' => b.is_ascii_uppercase(),
+            b'a'..=b'z' => b.is_ascii_digit(),
+            _ => b == expected,
+        };
+
+        if !valid {
+            return Err(ParseError::invalid_character(input, i));
         }
+    }
+
+    Ok(())
+}
+
+/// Fast path for the most common layouts, the canonical SAC13 format (`M020-05-21`)
+/// and ISO 8601 Gregorian dates (`2020-05-21`), with fixed positions.
+///
+/// Returns `None` for everything else (including invalid dates), which is then
+/// handled by the general parser, so the results and errors are the same either way.
+fn parse_canonical(input: &[u8]) -> Option<ParsedDate> {
+    let &[y0, y1, y2, y3, b'-', m0, m1, b'-', d0, d1] = input else {
+        return None;
+    };
+
+    let format = ParsedFormat {
+        separator: Separator::Dash,
+        component_order: ComponentOrder::YMD,
+        len_day: 2,
+        len_month: 2,
+        len_year: 4,
+    };
 
-        GregorianOrSac13::Sac13Date(Date::from_ymd_untyped(year.value as u16, month, day)?)
+    let date = if y0.is_ascii_uppercase() {
+        GregorianOrSac13::Sac13Date(Date::from_canonical_bytes(input)?)
     } else {
-        GregorianOrSac13::GregorianDate(GregorianDate::from_ymd(year.value, month, day)?)
+        let digits = [y0, y1, y2, y3, m0, m1, d0, d1];
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let [y0, y1, y2, y3, m0, m1, d0, d1] = digits.map(|b| b - b'0');
+        let year = i16::from(y0) * 1000 + i16::from(y1) * 100 + i16::from(y2) * 10 + i16::from(y3);
+
+        GregorianOrSac13::GregorianDate(GregorianDate::from_ymd(year, m0 * 10 + m1, d0 * 10 + d1)?)
     };
 
     Some(ParsedDate { date, format })
 }
 
-impl Display for ParsedFormat {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let separator = self.separator as char;
+/// SAC13 date from validated components, with the error pointing to the component that's invalid.
+fn sac13_date(
+    year: u16,
+    month: u8,
+    day: u8,
+    [year_span, month_span, day_span]: [Range<usize>; 3],
+) -> Result<GregorianOrSac13, ParseError> {
+    let Some(year) = Year::new(year) else {
+        return Err(ParseError::new(ParseErrorKind::OutOfRange, year_span));
+    };
+
+    let Some(month) = Month::new(month) else {
+        return Err(ParseError::new(ParseErrorKind::InvalidMonth, month_span));
+    };
+
+    Date::from_ymd(year, month, day)
+        .map(GregorianOrSac13::Sac13Date)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidDay, day_span))
+}
+
+/// Gregorian date from validated components, with the error pointing to the component that's invalid.
+fn gregorian_date(
+    year: i16,
+    month: u8,
+    day: u8,
+    [year_span, month_span, day_span]: [Range<usize>; 3],
+) -> Result<GregorianOrSac13, ParseError> {
+    let Some(month_len) = GregorianDate::month_len(year, month) else {
+        return Err(ParseError::new(ParseErrorKind::InvalidMonth, month_span));
+    };
+
+    if day > month_len {
+        return Err(ParseError::new(ParseErrorKind::InvalidDay, day_span));
+    }
+
+    let whole = year_span.start.min(day_span.start)..year_span.end.max(day_span.end);
+
+    GregorianDate::from_ymd(year, month, day)
+        .map(GregorianOrSac13::GregorianDate)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::OutOfRange, whole))
+}
+
+/// Parses the compact form without separators (`YYYYMMDD`, `LyyyMMDD` or, with
+/// [`plain_sac13_years`](ParseOptions::plain_sac13_years), `YYYYYMMDD`).
+fn parse_compact(input: &[u8], options: ParseOptions) -> Result<ParsedDate, ParseError> {
+    // the components are sliced by position, so reject non-ASCII characters
+    // before they could be split up
+    if let Some(i) = input.iter().position(|b| !b.is_ascii()) {
+        return Err(ParseError::invalid_character(input, i));
+    }
+
+    let digits = |span: Range<usize>| {
+        let mut value: u16 = 0;
+
+        for i in span.clone() {
+            let b = input[i];
+
+            if !b.is_ascii_digit() {
+                return Err(ParseError::invalid_character(input, i));
+            }
+
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(u16::from(b - b'0')))
+                .ok_or_else(|| ParseError::new(ParseErrorKind::OutOfRange, span.clone()))?;
+        }
+
+        Ok(value)
+    };
+
+    let len_year: u8 = match input.len().checked_sub(4) {
+        Some(4) => 4,
+        Some(5) if options.plain_sac13_years => 5,
+        _ => {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidLength,
+                0..input.len(),
+            ));
+        }
+    };
+
+    let y = usize::from(len_year);
+    let spans = [0..y, y..y + 2, y + 2..input.len()];
+
+    // two digits always fit
+    let month = u8::try_from(digits(spans[1].clone())?).unwrap_or(u8::MAX);
+    let day = u8::try_from(digits(spans[2].clone())?).unwrap_or(u8::MAX);
+
+    let date = match input[0] {
+        letter @ b'A'..=b'Z' if len_year == 4 => {
+            let year = u16::from(letter - b'A') * 1000 + digits(1..4)?;
+            sac13_date(year, month, day, spans)?
+        }
+        _ if len_year == 5 => sac13_date(digits(0..5)?, month, day, spans)?,
+        _ => {
+            // four digits always fit
+            let year = i16::try_from(digits(0..4)?).unwrap_or(i16::MAX);
+            gregorian_date(year, month, day, spans)?
+        }
+    };
 
-        let format_order = match self.component_order {
+    let format = ParsedFormat {
+        separator: Separator::Compact,
+        component_order: ComponentOrder::YMD,
+        len_day: 2,
+        len_month: 2,
+        len_year,
+    };
+
+    Ok(ParsedDate { date, format })
+}
+
+impl ParsedFormat {
+    /// Component letters (`Y`, `M`, `D`) and their lengths in the order of the format.
+    const fn components(&self) -> [(char, u8); 3] {
+        match self.component_order {
             ComponentOrder::YMD => [
                 ('Y', self.len_year),
                 ('M', self.len_month),
@@ -210,11 +1022,15 @@ impl Display for ParsedFormat {
                 ('D', self.len_day),
                 ('Y', self.len_year),
             ],
-        };
+        }
+    }
+}
 
-        for (i, &(c, count)) in format_order.iter().enumerate() {
+impl Display for ParsedFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, &(c, count)) in self.components().iter().enumerate() {
             if i != 0 {
-                write!(f, "{}", separator)?;
+                write!(f, "{}", self.separator)?;
             }
 
             for _ in 0..count {
@@ -235,10 +1051,221 @@ impl Display for GregorianOrSac13 {
     }
 }
 
+impl CalendarDate for GregorianOrSac13 {
+    const MIN: Self = Self::Sac13Date(Date::MIN);
+    const MAX: Self = Self::Sac13Date(Date::MAX);
+
+    fn as_julian(&self) -> i32 {
+        match self {
+            Self::GregorianDate(x) => x.as_julian(),
+            Self::Sac13Date(x) => x.as_julian(),
+        }
+    }
+
+    /// Always returns a [`Sac13Date`](Self::Sac13Date).
+    fn from_julian(value: i32) -> Option<Self> {
+        Date::from_julian(value).map(Self::Sac13Date)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn sort_and_dedup_mixed_calendars() {
+        let mut dates = [
+            "2020-12-31",
+            "M020-05-01",
+            "2019-12-01",
+            "M019-13-29",
+            "2020-01-05",
+        ]
+        .map(|s| parse_date_str(s).unwrap().date);
+
+        dates.sort();
+
+        assert!(dates.windows(2).all(|w| w[0].cmp_by_day(&w[1]).is_lt()));
+        assert_eq!(
+            dates.map(|d| format!("{d}")),
+            [
+                "2019-12-01",
+                "2020-01-05",
+                "M019-13-29",
+                "M020-05-01",
+                "2020-12-31"
+            ]
+        );
+    }
+
+    #[test]
+    fn same_day_in_both_calendars_is_equal() {
+        let greg = parse_date_str("2000-03-20").unwrap().date;
+        let sac13 = parse_date_str("M000-01-01").unwrap().date;
+
+        let set: std::collections::HashSet<_> = [greg.clone(), sac13.clone()].into();
+
+        assert_eq!(set.len(), 1);
+        assert_eq!(greg.clone().normalize_to_sac13(), sac13);
+        assert!(matches!(
+            greg.normalize_to_sac13(),
+            GregorianOrSac13::Sac13Date(d) if d == date!(M000 - 01 - 01)
+        ));
+    }
+
+    #[test]
+    fn parsed_date_accessors() {
+        let greg = parse_date_str("20.03.2000").unwrap();
+        let sac13 = parse_date_str("M000-01-01").unwrap();
+
+        assert_eq!(greg.to_sac13(), sac13.to_sac13());
+        assert_eq!(greg.to_gregorian(), sac13.to_gregorian());
+        assert_eq!(greg.as_julian(), sac13.as_julian());
+
+        assert_eq!(format!("{}", sac13.reformat(&greg.format)), "01.01.M000");
+        assert_eq!(format!("{}", greg.reformat(&sac13.format)), "2000-03-20");
+
+        let plain = parse_date_str_with("12000/1/1", ParseOptions::new().plain_sac13_years(true));
+        assert_eq!(
+            format!("{}", sac13.reformat(&plain.unwrap().format)),
+            "12000/1/1"
+        );
+
+        let negative = parse_date_str("-0044-03-15").unwrap();
+        assert_eq!(
+            format!("{}", negative.reformat(&greg.format)),
+            "15.03.-0044"
+        );
+        assert_eq!(
+            format!("{}", negative.reformat(&negative.format)),
+            "-0044-03-15"
+        );
+    }
+
+    #[test]
+    fn space_and_compact_separators() {
+        let spaced = parse_date_str("21 03 2020").unwrap();
+        assert_eq!(spaced.format.separator, Separator::Space);
+        assert_eq!(format!("{}", spaced.format), "DD MM YYYY");
+
+        let compact = parse_date_str("20200321").unwrap();
+        assert_eq!(compact.format.separator, Separator::Compact);
+        assert_eq!(format!("{}", compact.format), "YYYYMMDD");
+        assert_eq!(compact.to_gregorian(), spaced.to_gregorian());
+        assert_eq!(format!("{}", spaced.reformat(&compact.format)), "20200321");
+
+        let sac13 = parse_date_str("M0200521").unwrap();
+        assert_eq!(sac13.to_sac13(), date!(M020 - 05 - 21));
+        assert_eq!(format!("{}", sac13.reformat(&compact.format)), "M0200521");
+
+        let plain = ParseOptions::new().plain_sac13_years(true);
+        assert_eq!(
+            parse_date_str_with("120200521", plain).map(|p| p.to_sac13()),
+            Some(date!(M020 - 05 - 21))
+        );
+
+        for input in [
+            "120200521",
+            "99999999",
+            "2020032",
+            "M02005210",
+            "m0200521",
+            "2020 03-21",
+        ] {
+            assert_eq!(parse_date_str(input).map(|p| p.date), None, "{input}");
+        }
+
+        assert_eq!(
+            parse_date_str_with("999990521", plain).map(|p| p.date),
+            None
+        );
+    }
+
+    #[test]
+    fn trailing_time() {
+        for input in [
+            "2020-03-21 14:05",
+            "2020-03-21T14:05:00Z",
+            "21.03.2020 14:05:00.123",
+            "2020-03-21T14:05+02:00",
+            "20200321T14:05-0130",
+            "21 03 2020 14:05",
+        ] {
+            let parsed = parse_datetime_str(input).expect(input);
+            let time = parsed.time.unwrap();
+
+            assert_eq!(
+                parsed.date.to_gregorian(),
+                date_greg!(2020 - 03 - 21),
+                "{input}"
+            );
+            assert_eq!((time.hour, time.minute), (14, 5), "{input}");
+            assert!(parse_date_str(input).is_some(), "{input}");
+        }
+
+        let time = parse_datetime_str("T020-01-01T23:59:59-01:30")
+            .unwrap()
+            .time
+            .unwrap();
+        assert_eq!(time.utc_offset_minutes, Some(-90));
+        assert_eq!(time.seconds_of_day(), 86_399);
+        assert_eq!(format!("{time}"), "23:59:59-01:30");
+
+        for input in [
+            "2020-03-21 24:00",
+            "2020-03-21 14:60",
+            "2020-03-21 14:05:",
+            "2020-03-21 14:05.5",
+            "2020-03-21 14:05:00.",
+            "2020-03-21 14:05X",
+            "2020-03-21 14:05+2",
+            "2020-03-21  14:05",
+            "2020-03-21:14:05",
+            "14:05",
+        ] {
+            assert!(parse_datetime_str(input).is_none(), "{input}");
+        }
+    }
+
+    #[test]
+    fn error_kinds_and_spans() {
+        use ParseErrorKind::*;
+
+        for (input, kind, span) in [
+            ("", InvalidLength, 0..0),
+            ("2020-03", MissingComponent, 7..7),
+            ("2020-03-", MissingComponent, 8..8),
+            ("2020-0x-21", InvalidCharacter, 6..7),
+            ("2020-0ä-21", InvalidCharacter, 6..8),
+            ("A0303-2-01", InvalidLength, 0..5),
+            ("2020-03.21", MixedSeparators, 7..8),
+            ("2020-03-21-", TrailingInput, 10..11),
+            ("2020-03-211", InvalidLength, 8..11),
+            ("20-03-21", YearPosition, 0..8),
+            ("2020-2020-21", YearPosition, 5..9),
+            ("2020-03-32", InvalidDay, 8..10),
+            ("2021-02-29", InvalidDay, 8..10),
+            ("2020-13-01", InvalidMonth, 5..7),
+            ("M020-06-29", InvalidDay, 8..10),
+            ("M020-14-01", InvalidMonth, 5..7),
+            ("-M020-01-01", InvalidYear, 0..2),
+            ("2020-M1-01", InvalidCharacter, 5..6),
+            ("16000-12-01", OutOfRange, 0..11),
+            ("2020032x", InvalidCharacter, 7..8),
+            ("202003211", InvalidLength, 0..9),
+            ("2020-03-21 25:00", InvalidTime, 11..16),
+            ("2020:03:21", InvalidCharacter, 4..5),
+        ] {
+            let error = try_parse_date_str_with(input, ParseOptions::new()).unwrap_err();
+
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+
+        let plain = ParseOptions::new().plain_sac13_years(true);
+        let error = try_parse_date_str_with("260000101", plain).unwrap_err();
+        assert_eq!((error.kind(), error.span()), (OutOfRange, 0..5));
+    }
+
     macro_rules! assert_matches {
         ($left:expr, $right:pat) => {
             assert!(matches!($left, $right));
@@ -295,6 +1322,116 @@ mod tests {
         };
     }
 
+    #[test]
+    fn canonical_fast_path_matches_general_parser() {
+        let inputs = [
+            "M020-05-21",
+            "A000-01-01",
+            "Z999-13-29",
+            "M021-06-29",
+            "M022-06-29",
+            "M020-14-01",
+            "m020-05-21",
+            "2020-05-21",
+            "0000-03-01",
+            "9999-12-31",
+            "2021-02-29",
+            "2020-13-01",
+            "2020-00-10",
+            "2020-05-2x",
+            "20x0-05-21",
+        ];
+
+        for input in inputs {
+            let input = input.as_bytes();
+            let general = parse_date_general(input, ParseOptions::new());
+
+            match parse_canonical(input) {
+                Some(fast) => assert_eq!(format!("{fast:?}"), format!("{:?}", general.unwrap())),
+                None => assert!(general.is_err(), "{general:?}"),
+            }
+        }
+
+        assert!(parse_canonical(b"M020-05-21").is_some());
+        assert!(parse_canonical(b"2020-05-21").is_some());
+        assert!(parse_canonical(b"21.05.2020").is_none());
+    }
+
+    #[test]
+    fn lenient_unicode() {
+        let lenient = ParseOptions::new().lenient_unicode(true);
+
+        for input in [
+            "2020\u{2013}05\u{2013}21",
+            "2020\u{2212}05\u{2212}21",
+            "２０２０－０５－２１",
+            "21\u{A0}05\u{A0}2020",
+            "21．05．2020",
+        ] {
+            assert!(parse_date_str(input).is_none(), "{input}");
+            assert_eq!(
+                parse_date_str_with(input, lenient).unwrap().to_gregorian(),
+                date_greg!(2020 - 05 - 21),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            parse_date_str_with("Ｍ０２０\u{2013}05\u{2013}21", lenient)
+                .unwrap()
+                .to_sac13(),
+            date!(M020 - 05 - 21)
+        );
+
+        let parsed =
+            parse_datetime_str_with("2020\u{2013}05\u{2013}21\u{A0}14：05", lenient).unwrap();
+        assert_eq!(parsed.time.unwrap().hour, 14);
+
+        let input = "2020\u{2013}05\u{2013}3２";
+        let error = try_parse_date_str_with(input, lenient).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::InvalidDay);
+        assert_eq!(&input[error.span()], "3２");
+    }
+
+    #[test]
+    fn ambiguous_day_and_month() {
+        let greg = |input: &str| match try_interpret_date_str(input, ParseOptions::new()) {
+            Ok(DateInterpretation::Ambiguous { dmy, mdy }) => {
+                Some((dmy.to_gregorian(), mdy.to_gregorian()))
+            }
+            Ok(DateInterpretation::Unambiguous(_)) => None,
+            Err(e) => panic!("{input}: {e}"),
+        };
+
+        let readings = Some((date_greg!(2020 - 05 - 04), date_greg!(2020 - 04 - 05)));
+        assert_eq!(greg("04/05/2020"), readings);
+        assert_eq!(greg("04.05.2020"), readings);
+        assert_eq!(greg("4-5-2020"), readings);
+
+        for input in ["13.05.2020", "05/13/2020", "05/05/2020", "2020-04-05"] {
+            assert_eq!(greg(input), None, "{input}");
+        }
+
+        // SAC13 dates are always day-month-year
+        assert!(matches!(
+            try_interpret_date_str("04/05/M020", ParseOptions::new()),
+            Ok(DateInterpretation::Unambiguous(_))
+        ));
+
+        let reject = ParseOptions::new().reject_ambiguous(true);
+        assert_eq!(
+            try_parse_date_str_with("04/05/2020", reject)
+                .unwrap_err()
+                .kind(),
+            ParseErrorKind::Ambiguous
+        );
+        assert!(try_parse_date_str_with("13.05.2020", reject).is_ok());
+        assert_eq!(
+            parse_date_str("04/05/2020").unwrap().to_gregorian(),
+            date_greg!(2020 - 04 - 05)
+        );
+    }
+
     #[test]
     fn parsing_gregorian() {
         // DD-MM-YYYY
@@ -331,6 +1468,22 @@ mod tests {
         // Note: SAC13 is always YMD or DMY and never the US format MDY
     }
 
+    #[test]
+    fn plain_sac13_years_are_opt_in() {
+        let options = ParseOptions::new().plain_sac13_years(true);
+
+        let parsed = parse_date_str_with("21.05.12020", options).unwrap();
+        assert!(
+            matches!(parsed.date, GregorianOrSac13::Sac13Date(d) if d == date!(M020 - 05 - 21))
+        );
+
+        let parsed = parse_date_str_with("2020-05-21", options).unwrap();
+        assert_matches!(parsed.date, GregorianOrSac13::GregorianDate(_));
+
+        assert_greg!("12020-01-01", 12020 - 01 - 01);
+        assert!(parse_date_str_with("-12020-01-01", options).is_none());
+    }
+
     #[test]
     fn no_letter_allowed_as_month() {
         assert_parse_error!("2001-L-03");
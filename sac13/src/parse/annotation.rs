@@ -0,0 +1,61 @@
+//! Suffix annotations of the Internet Extended Date/Time Format (IXDTF, RFC 9557).
+
+use super::{ParseError, ParseErrorKind};
+
+/// Calendar annotation that marks a date as SAC13.
+pub const CALENDAR_ANNOTATION: &str = "[u-ca=sac13]";
+
+/// Splits the annotations off the input and checks that they mark it as a SAC13 date.
+///
+/// Returns the part before the first annotation. Unknown elective annotations (and a time zone)
+/// are ignored, unknown critical ones (`[!...]`) are rejected, as specified by RFC 9557.
+pub fn strip_calendar_annotation(input: &str) -> Result<&str, ParseError> {
+    let Some(start) = input.find('[') else {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidAnnotation,
+            input.len()..input.len(),
+        ));
+    };
+
+    let mut has_calendar = false;
+    let mut pos = start;
+
+    while pos < input.len() {
+        let invalid = |len| ParseError::new(ParseErrorKind::InvalidAnnotation, pos..pos + len);
+
+        let Some(len) = input[pos..].find(']').map(|end| end + 1) else {
+            return Err(invalid(input.len() - pos));
+        };
+
+        let Some(content) = input[pos..pos + len].strip_prefix('[') else {
+            return Err(invalid(len));
+        };
+
+        let content = &content[..content.len() - 1];
+        let (critical, content) = content
+            .strip_prefix('!')
+            .map_or((false, content), |content| (true, content));
+
+        match content.split_once('=') {
+            Some(("u-ca", calendar)) if calendar.eq_ignore_ascii_case("sac13") => {
+                has_calendar = true;
+            }
+            Some(("u-ca", _)) => return Err(invalid(len)),
+            // time zone (only allowed as the first annotation) or another key
+            None if pos != start => return Err(invalid(len)),
+            _ if critical => return Err(invalid(len)),
+            _ => {}
+        }
+
+        pos += len;
+    }
+
+    if has_calendar {
+        Ok(&input[..start])
+    } else {
+        Err(ParseError::new(
+            ParseErrorKind::InvalidAnnotation,
+            start..input.len(),
+        ))
+    }
+}
@@ -0,0 +1,138 @@
+use core::{fmt::Display, ops::Range};
+
+/// Error of [`try_parse_date_str_with()`](crate::try_parse_date_str_with()) and
+/// [`try_parse_datetime_str_with()`](crate::try_parse_datetime_str_with()).
+///
+/// Besides the [kind](ParseErrorKind) of the problem, it contains the byte range of
+/// the offending part of the input, e.g. to underline it in an editor or CLI.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{ParseErrorKind, ParseOptions, try_parse_date_str_with};
+///
+/// let input = "2020-13-01";
+/// let error = try_parse_date_str_with(input, ParseOptions::new()).unwrap_err();
+///
+/// assert_eq!(error.kind(), ParseErrorKind::InvalidMonth);
+/// assert_eq!(&input[error.span()], "13");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    span: Range<usize>,
+}
+
+/// The kind of a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A character that isn't allowed at this position.
+    InvalidCharacter,
+
+    /// A component has an invalid number of characters (e.g. a three digit year),
+    /// or the compact form has an invalid length.
+    InvalidLength,
+
+    /// The input ended before all three components were found.
+    MissingComponent,
+
+    /// There is more input after the last component.
+    TrailingInput,
+
+    /// The components are separated by different separators (e.g. `2020-03.21`).
+    MixedSeparators,
+
+    /// The year is neither the first nor the last component, or both could be a year.
+    YearPosition,
+
+    /// The year is negative for a SAC13 date.
+    InvalidYear,
+
+    /// The month doesn't exist in the calendar.
+    InvalidMonth,
+
+    /// The day doesn't exist in the month.
+    InvalidDay,
+
+    /// The week number is outside 1 - 52.
+    InvalidWeek,
+
+    /// The date is outside the SAC13 range.
+    OutOfRange,
+
+    /// The time after the date is invalid.
+    InvalidTime,
+
+    /// A calendar annotation (like `[u-ca=sac13]`) is missing, malformed or for another calendar.
+    InvalidAnnotation,
+
+    /// The day and month could be swapped (e.g. `04/05/2020`), see
+    /// [`ParseOptions::reject_ambiguous`](crate::ParseOptions::reject_ambiguous).
+    Ambiguous,
+}
+
+impl ParseError {
+    pub(crate) const fn new(kind: ParseErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// [`ParseErrorKind::InvalidCharacter`] for the character starting at `pos`.
+    /// The span covers all bytes of the character, so it can be used to slice the input.
+    pub(crate) fn invalid_character(input: &[u8], pos: usize) -> Self {
+        let len = input[pos + 1..]
+            .iter()
+            .take_while(|&&b| b & 0b1100_0000 == 0b1000_0000)
+            .count();
+
+        Self::new(ParseErrorKind::InvalidCharacter, pos..pos + 1 + len)
+    }
+
+    /// What the problem is.
+    #[must_use]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Byte range of the offending part of the input. Might be empty
+    /// (e.g. for [`ParseErrorKind::MissingComponent`] at the end of the input).
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let description = match self {
+            Self::InvalidCharacter => "invalid character",
+            Self::InvalidLength => "invalid length",
+            Self::MissingComponent => "missing component",
+            Self::TrailingInput => "unexpected input after the date",
+            Self::MixedSeparators => "different separators in the same date",
+            Self::YearPosition => "the year must be either the first or the last component",
+            Self::InvalidYear => "invalid year",
+            Self::InvalidMonth => "invalid month",
+            Self::InvalidDay => "invalid day",
+            Self::InvalidWeek => "invalid week",
+            Self::OutOfRange => "the date is outside the SAC13 range",
+            Self::InvalidTime => "invalid time",
+            Self::InvalidAnnotation => "missing or invalid calendar annotation",
+            Self::Ambiguous => "the day and month could be swapped",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} (at bytes {}..{})",
+            self.kind, self.span.start, self.span.end
+        )
+    }
+}
+
+impl core::error::Error for ParseError {}
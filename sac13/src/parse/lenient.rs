@@ -0,0 +1,108 @@
+//! Normalization of look-alike Unicode characters, see [`ParseOptions::lenient_unicode`].
+//!
+//! [`ParseOptions::lenient_unicode`]: super::ParseOptions::lenient_unicode
+
+use super::{ParseError, ParseErrorKind};
+
+/// Maximum length of the normalized input in bytes, far more than any supported format needs.
+const CAPACITY: usize = 64;
+
+/// The ASCII character a look-alike character is replaced with.
+const fn replacement(c: char) -> Option<u8> {
+    /// Distance between the full-width forms and ASCII.
+    const FULL_WIDTH_OFFSET: u32 = 0xFEE0;
+
+    match c {
+        // hyphen, non-breaking hyphen, figure dash, en dash, em dash, horizontal bar,
+        // minus sign, small hyphen-minus
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE63}' => Some(b'-'),
+
+        // no-break space, figure space, narrow no-break space, ideographic space
+        '\u{A0}' | '\u{2007}' | '\u{202F}' | '\u{3000}' => Some(b' '),
+
+        // full-width `-./`, digits, `:` and Latin letters
+        '\u{FF0D}'..='\u{FF1A}' | '\u{FF21}'..='\u{FF3A}' | '\u{FF41}'..='\u{FF5A}' => {
+            #[allow(clippy::cast_possible_truncation)] // all of them map to ASCII
+            Some((c as u32 - FULL_WIDTH_OFFSET) as u8)
+        }
+
+        _ => None,
+    }
+}
+
+/// Calls `parse` with the normalized input and maps the span of its error back to `input`.
+pub fn with_normalized<T>(
+    input: &str,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut bytes = [0; CAPACITY];
+    // start of the original character of each normalized byte (and the end of the input)
+    let mut origins = [0; CAPACITY + 1];
+    let mut len = 0;
+
+    for (pos, c) in input.char_indices() {
+        let mut utf8 = [0; 4];
+
+        let (encoded, replaced) = match replacement(c) {
+            Some(ascii) => {
+                utf8[0] = ascii;
+                (&utf8[..1], true)
+            }
+            None => (c.encode_utf8(&mut utf8).as_bytes(), false),
+        };
+
+        if len + encoded.len() > CAPACITY {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidLength,
+                0..input.len(),
+            ));
+        }
+
+        for (i, &b) in encoded.iter().enumerate() {
+            bytes[len] = b;
+            origins[len] = if replaced { pos } else { pos + i };
+            len += 1;
+        }
+    }
+
+    origins[len] = input.len();
+
+    // only whole characters are copied or replaced with ASCII
+    let Ok(normalized) = core::str::from_utf8(&bytes[..len]) else {
+        unreachable!()
+    };
+
+    parse(normalized).map_err(|error| {
+        let span = error.span();
+        ParseError::new(error.kind(), origins[span.start]..origins[span.end])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_spans_refer_to_the_original_input() {
+        let input = "２０２０\u{2013}05\u{2013}3x";
+
+        let error = with_normalized(input, |normalized| {
+            assert_eq!(normalized, "2020-05-3x");
+            Err::<(), _>(ParseError::new(ParseErrorKind::InvalidCharacter, 4..5))
+        })
+        .unwrap_err();
+        assert_eq!(&input[error.span()], "\u{2013}");
+
+        let error = with_normalized(input, |_| {
+            Err::<(), _>(ParseError::new(ParseErrorKind::InvalidDay, 8..10))
+        })
+        .unwrap_err();
+        assert_eq!(&input[error.span()], "3x");
+
+        let too_long = "\u{A0}".repeat(CAPACITY + 1);
+        assert_eq!(
+            with_normalized(&too_long, |_| Ok(())).unwrap_err().kind(),
+            ParseErrorKind::InvalidLength
+        );
+    }
+}
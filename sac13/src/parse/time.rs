@@ -0,0 +1,122 @@
+use core::fmt::Display;
+
+/// Time of day that followed a date, see [`parse_datetime_str()`](crate::parse_datetime_str()).
+///
+/// Fractional seconds are accepted, but not captured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+
+    /// Offset from UTC in minutes, if the input had one (`Z` is zero).
+    pub utc_offset_minutes: Option<i16>,
+}
+
+impl ParsedTime {
+    /// Seconds since midnight (ignoring the UTC offset).
+    #[must_use]
+    pub const fn seconds_of_day(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+
+    /// Parses `HH:MM[:SS[.fff]][Z|±HH[:MM]]`.
+    pub(super) fn parse(input: &str) -> Option<Self> {
+        let mut input = input.as_bytes();
+
+        let hour = two_digits(&mut input, 23)?;
+        expect(&mut input, b':')?;
+        let minute = two_digits(&mut input, 59)?;
+
+        let mut second = 0;
+
+        if expect(&mut input, b':').is_some() {
+            second = two_digits(&mut input, 59)?;
+
+            // fractional seconds
+            if let [b'.' | b',', rest @ ..] = input {
+                let len = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+
+                if len == 0 {
+                    return None;
+                }
+
+                input = &rest[len..];
+            }
+        }
+
+        let utc_offset_minutes = match input {
+            [] => None,
+            [b'Z'] => Some(0),
+            [sign @ (b'+' | b'-'), rest @ ..] => {
+                input = rest;
+
+                let hours = two_digits(&mut input, 23)?;
+                _ = expect(&mut input, b':');
+                let minutes = if input.is_empty() {
+                    0
+                } else {
+                    two_digits(&mut input, 59)?
+                };
+
+                if !input.is_empty() {
+                    return None;
+                }
+
+                let offset = i16::from(hours) * 60 + i16::from(minutes);
+                Some(if *sign == b'-' { -offset } else { offset })
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            hour,
+            minute,
+            second,
+            utc_offset_minutes,
+        })
+    }
+}
+
+impl Display for ParsedTime {
+    /// Displays the time like `14:05:00`, followed by the UTC offset (e.g. `+02:00`) if any.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        match self.utc_offset_minutes {
+            None => Ok(()),
+            Some(0) => write!(f, "Z"),
+            Some(offset) => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(f, "{sign}{:02}:{:02}", offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+/// Consumes two digits with a value up to `max`.
+fn two_digits(input: &mut &[u8], max: u8) -> Option<u8> {
+    let [a @ b'0'..=b'9', b @ b'0'..=b'9', rest @ ..] = *input else {
+        return None;
+    };
+
+    *input = rest;
+
+    let value = (a - b'0') * 10 + (b - b'0');
+    (value <= max).then_some(value)
+}
+
+/// Consumes the given byte.
+fn expect(input: &mut &[u8], byte: u8) -> Option<()> {
+    let [first, rest @ ..] = *input else {
+        return None;
+    };
+
+    if *first != byte {
+        return None;
+    }
+
+    *input = rest;
+    Some(())
+}
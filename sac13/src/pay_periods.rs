@@ -0,0 +1,257 @@
+use core::iter::FusedIterator;
+
+use crate::{Date, DateRange, Granularity, traits::CalendarDate};
+
+/// Length of recurring pay or billing periods, see [`pay_periods()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PayFrequency {
+    /// Calendar months (day 1 - 28, plus the synchronization day if there is one).
+    Monthly,
+
+    /// Four consecutive weeks, starting with the week of the first date.
+    FourWeekly,
+
+    /// Two consecutive weeks, starting with the week of the first date.
+    BiWeekly,
+
+    /// Single weeks.
+    Weekly,
+}
+
+impl PayFrequency {
+    /// Number of (seven day) weeks per period.
+    const fn weeks(self) -> u8 {
+        match self {
+            Self::Monthly | Self::FourWeekly => 4,
+            Self::BiWeekly => 2,
+            Self::Weekly => 1,
+        }
+    }
+}
+
+/// Where synchronization days (the leap day and the year day) go in [`pay_periods()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SyncDayPeriod {
+    /// Synchronization days are the last day of the period they follow,
+    /// just like they belong to the fourth week of their month.
+    #[default]
+    AttachToPreceding,
+
+    /// Synchronization days are periods of their own, e.g. for an extra payment.
+    /// Periods that would span a synchronization day end before it.
+    Standalone,
+}
+
+/// Consecutive pay or billing periods covering the given range.
+///
+/// The first period is the one containing [`range.start()`](DateRange::start()), the last one
+/// the period containing [`range.end()`](DateRange::end()). The periods aren't cut to the
+/// range, so the first one might start earlier and the last one might end later.
+///
+/// [Monthly](PayFrequency::Monthly) periods are calendar months. All other periods consist of
+/// whole SAC13 weeks and start with the week of the first date.
+///
+/// Synchronization days don't belong to the regular four-week layout. By default, they
+/// are attached to the period they follow, which is one day longer then. With
+/// [`SyncDayPeriod::Standalone`], they are periods of their own, and a period that would
+/// span a synchronization day ends before it, so the following periods restart at the
+/// beginning of the next month.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateRange, PayFrequency, SyncDayPeriod, date, pay_periods};
+///
+/// let range = DateRange::new(date!(M020 - 13 - 10), date!(M021 - 01 - 01)).unwrap();
+///
+/// let mut periods = pay_periods(range, PayFrequency::BiWeekly, SyncDayPeriod::Standalone);
+/// let expected = [
+///     (date!(M020 - 13 - 08), date!(M020 - 13 - 21)),
+///     (date!(M020 - 13 - 22), date!(M020 - 13 - 28)),
+///     (date!(M020 - 13 - 29), date!(M020 - 13 - 29)), // the year day
+///     (date!(M021 - 01 - 01), date!(M021 - 01 - 14)),
+/// ];
+///
+/// for (start, end) in expected {
+///     assert_eq!(periods.next(), DateRange::new(start, end));
+/// }
+/// assert_eq!(periods.next(), None);
+///
+/// let mut periods = pay_periods(range, PayFrequency::Monthly, SyncDayPeriod::AttachToPreceding);
+///
+/// assert_eq!(periods.next(), DateRange::new(date!(M020 - 13 - 01), date!(M020 - 13 - 29)));
+/// assert_eq!(periods.next(), DateRange::new(date!(M021 - 01 - 01), date!(M021 - 01 - 28)));
+/// assert_eq!(periods.next(), None);
+/// ```
+#[must_use]
+pub fn pay_periods(
+    range: DateRange,
+    frequency: PayFrequency,
+    sync_days: SyncDayPeriod,
+) -> impl FusedIterator<Item = DateRange> + use<> {
+    let first = range.start();
+
+    let start = if first.day() == 29 && matches!(sync_days, SyncDayPeriod::Standalone) {
+        first
+    } else if matches!(frequency, PayFrequency::Monthly) {
+        Granularity::Month.period_start(first)
+    } else {
+        first.align_to_week_start()
+    };
+
+    let mut next = Some(start);
+
+    core::iter::from_fn(move || {
+        let start = next.filter(|&start| start <= range.end())?;
+
+        let period = if start.day() == 29 {
+            DateRange::single(start)
+        } else {
+            period_from(start, frequency.weeks(), sync_days)
+        };
+
+        next = period.end().tomorrow();
+        Some(period)
+    })
+    .fuse()
+}
+
+/// The period of the given number of weeks, starting at the beginning of a week.
+fn period_from(start: Date, weeks: u8, sync_days: SyncDayPeriod) -> DateRange {
+    let mut week = Some(start);
+    let mut end = start;
+
+    for _ in 0..weeks {
+        let Some(week_start) = week else {
+            break;
+        };
+
+        // skips the synchronization day, so the day before is the synchronization day itself
+        week = Granularity::Week.next_period_start(week_start);
+        end = week.and_then(Date::yesterday).unwrap_or(Date::MAX);
+
+        if end.day() == 29 && matches!(sync_days, SyncDayPeriod::Standalone) {
+            end = end.align_to_week_start().add_days(6).unwrap_or(end);
+            break;
+        }
+    }
+
+    DateRange::new(start, end).unwrap_or_else(|| DateRange::single(start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn periods_are_contiguous() {
+        let range = DateRange::new(date!(M020 - 05 - 20), date!(M022 - 02 - 03)).unwrap();
+
+        for frequency in [
+            PayFrequency::Monthly,
+            PayFrequency::FourWeekly,
+            PayFrequency::BiWeekly,
+            PayFrequency::Weekly,
+        ] {
+            for sync_days in [SyncDayPeriod::AttachToPreceding, SyncDayPeriod::Standalone] {
+                let mut previous: Option<DateRange> = None;
+
+                for period in pay_periods(range, frequency, sync_days) {
+                    let days = period.duration_days();
+                    let weeks = u32::from(frequency.weeks());
+
+                    match previous {
+                        Some(previous) => assert!(previous.is_adjacent(&period)),
+                        None => assert!(period.contains(range.start())),
+                    }
+
+                    let sync_day_count = core::iter::successors(Some(period.start()), |d| {
+                        d.tomorrow().filter(|d| period.contains(*d))
+                    })
+                    .filter(|d| d.day() == 29)
+                    .count();
+
+                    if days == 1 {
+                        assert_eq!(period.start().day(), 29);
+                        assert_eq!(sync_days, SyncDayPeriod::Standalone);
+                    } else {
+                        let regular_days = days - u32::try_from(sync_day_count).unwrap();
+
+                        if sync_days == SyncDayPeriod::Standalone {
+                            assert_eq!(sync_day_count, 0);
+                            assert!(regular_days <= weeks * 7 && regular_days % 7 == 0);
+                        } else {
+                            assert!(sync_day_count <= 1);
+                            assert_eq!(regular_days, weeks * 7);
+                        }
+                    }
+
+                    previous = Some(period);
+                }
+
+                assert!(previous.unwrap().contains(range.end()));
+            }
+        }
+    }
+
+    #[test]
+    fn four_weekly_periods_follow_the_first_week() {
+        let range = DateRange::new(date!(M021 - 06 - 10), date!(M021 - 07 - 10)).unwrap();
+
+        let attached: [_; 2] = core::array::from_fn({
+            let mut periods = pay_periods(
+                range,
+                PayFrequency::FourWeekly,
+                SyncDayPeriod::AttachToPreceding,
+            );
+            move |_| periods.next().unwrap()
+        });
+
+        let standalone: [_; 3] = core::array::from_fn({
+            let mut periods =
+                pay_periods(range, PayFrequency::FourWeekly, SyncDayPeriod::Standalone);
+            move |_| periods.next().unwrap()
+        });
+
+        assert_eq!(
+            standalone,
+            [
+                DateRange::new(date!(M021 - 06 - 08), date!(M021 - 06 - 28)).unwrap(),
+                DateRange::single(date!(M021 - 06 - 29)),
+                DateRange::new(date!(M021 - 07 - 01), date!(M021 - 07 - 28)).unwrap(),
+            ]
+        );
+
+        assert_eq!(
+            attached,
+            [
+                DateRange::new(date!(M021 - 06 - 08), date!(M021 - 07 - 07)).unwrap(),
+                DateRange::new(date!(M021 - 07 - 08), date!(M021 - 08 - 07)).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn start_and_end_of_time() {
+        let first = DateRange::single(Date::MIN);
+        let last = DateRange::single(Date::MAX);
+
+        assert_eq!(
+            pay_periods(last, PayFrequency::Weekly, SyncDayPeriod::Standalone).next(),
+            Some(last)
+        );
+        assert_eq!(
+            pay_periods(
+                last,
+                PayFrequency::BiWeekly,
+                SyncDayPeriod::AttachToPreceding
+            )
+            .next(),
+            DateRange::new(date!(Z999 - 13 - 22), Date::MAX)
+        );
+        assert_eq!(
+            pay_periods(first, PayFrequency::Monthly, SyncDayPeriod::Standalone).next(),
+            DateRange::new(Date::MIN, date!(A000 - 01 - 28))
+        );
+    }
+}
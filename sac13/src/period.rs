@@ -0,0 +1,567 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{
+    Date, DateRange, Month, ParseError, ParseErrorKind, Year, parse::check_layout,
+    traits::CalendarDate,
+};
+
+/// A month of a specific SAC13 year (e.g. May M020).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{YearMonth, date};
+///
+/// let month = YearMonth::from(date!(M020 - 05 - 21));
+///
+/// assert_eq!(month.first_day(), date!(M020 - 05 - 01));
+/// assert!(month.contains(date!(M020 - 05 - 28)));
+/// assert!(!month.contains(date!(M021 - 05 - 28)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: Year,
+    month: Month,
+}
+
+impl YearMonth {
+    #[must_use]
+    pub const fn new(year: Year, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    #[must_use]
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    /// Number of days in the month (28 or 29).
+    #[must_use]
+    pub const fn days(self) -> u8 {
+        Date::month_len(self.year, self.month)
+    }
+
+    /// First day of the month (the 1st).
+    #[must_use]
+    pub const fn first_day(self) -> Date {
+        day_of(self.year, self.month, 1)
+    }
+
+    /// Last day of the month (the 28th or the 29th).
+    #[must_use]
+    pub const fn last_day(self) -> Date {
+        day_of(self.year, self.month, self.days())
+    }
+
+    /// Returns `true` if the date is in this month.
+    #[must_use]
+    pub const fn contains(self, date: Date) -> bool {
+        date.year().value() == self.year.value() && date.month().ord() == self.month.ord()
+    }
+}
+
+impl From<Date> for YearMonth {
+    fn from(date: Date) -> Self {
+        Self::new(date.year(), date.month())
+    }
+}
+
+/// A week of a specific SAC13 year (1 - 52).
+///
+/// Every month consists of exactly four weeks. Synchronization days (day 29) belong to the
+/// fourth week of their month, see [`Date::week_of_year()`].
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Week, date, year};
+///
+/// let week = date!(M020 - 13 - 29).in_week();
+///
+/// assert_eq!(week, Week::new(year!(M020), 52).unwrap());
+/// assert_eq!(week.first_day(), date!(M020 - 13 - 22));
+/// assert_eq!(week.last_day(), date!(M020 - 13 - 29)); // the year day
+///
+/// assert_eq!(week.to_string(), "M020-W52");
+/// assert_eq!(week.next(), Week::new(year!(M021), 1));
+/// assert_eq!("M020-W52".parse(), Ok(week));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Week {
+    year: Year,
+    week: u8,
+}
+
+impl Week {
+    /// Week of the year from its number (1 - 52).
+    ///
+    /// Returns `None` for invalid week numbers.
+    #[must_use]
+    pub const fn new(year: Year, week: u8) -> Option<Self> {
+        if week == 0 || week > 52 {
+            None
+        } else {
+            Some(Self { year, week })
+        }
+    }
+
+    #[must_use]
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    /// Number of the week in the year (1 - 52).
+    #[must_use]
+    pub const fn week(self) -> u8 {
+        self.week
+    }
+
+    /// Month the week belongs to.
+    #[must_use]
+    pub const fn month(self) -> Month {
+        let Some(month) = Month::new((self.week - 1) / 4 + 1) else {
+            unreachable!()
+        };
+
+        month
+    }
+
+    /// First day of the week (day 1, 8, 15 or 22 of the month).
+    #[must_use]
+    pub const fn first_day(self) -> Date {
+        day_of(self.year, self.month(), (self.week - 1) % 4 * 7 + 1)
+    }
+
+    /// Last day of the week, including a synchronization day at the end of the month.
+    #[must_use]
+    pub const fn last_day(self) -> Date {
+        if self.week.is_multiple_of(4) {
+            YearMonth::new(self.year, self.month()).last_day()
+        } else {
+            day_of(self.year, self.month(), (self.week - 1) % 4 * 7 + 7)
+        }
+    }
+
+    /// Returns `true` if the date is in this week.
+    #[must_use]
+    pub const fn contains(self, date: Date) -> bool {
+        date.year().value() == self.year.value() && date.week_of_year() == self.week
+    }
+
+    /// The following week, which is week 1 of the next year after week 52.
+    ///
+    /// Returns `None` after the last week of [`Year::MAX`].
+    #[must_use]
+    pub const fn next(self) -> Option<Self> {
+        self.checked_add(1)
+    }
+
+    /// The preceding week, which is week 52 of the previous year before week 1.
+    ///
+    /// Returns `None` before the first week of [`Year::MIN`].
+    #[must_use]
+    pub const fn previous(self) -> Option<Self> {
+        self.checked_add(-1)
+    }
+
+    /// The week that is the given number of weeks later (or earlier, if negative).
+    ///
+    /// Every year has 52 weeks, so adding 52 weeks gives the same week of the next year.
+    /// Returns `None` if the result is outside the SAC13 range.
+    ///
+    /// ```
+    /// use sac13::{Week, year};
+    ///
+    /// let week = Week::new(year!(M020), 50).unwrap();
+    ///
+    /// assert_eq!(week.checked_add(5), Week::new(year!(M021), 3));
+    /// assert_eq!(week.checked_add(-104), Week::new(year!(M018), 50));
+    /// assert_eq!(week.checked_add(i32::MAX), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // checked range
+    pub const fn checked_add(self, weeks: i32) -> Option<Self> {
+        let index = self.year.value() as i32 * 52 + (self.week - 1) as i32;
+        let Some(index) = index.checked_add(weeks) else {
+            return None;
+        };
+
+        if index < 0 || index / 52 > Year::MAX.value() as i32 {
+            return None;
+        }
+
+        let Some(year) = Year::new((index / 52) as u16) else {
+            unreachable!()
+        };
+
+        Some(Self {
+            year,
+            week: (index % 52) as u8 + 1,
+        })
+    }
+}
+
+impl Display for Week {
+    /// Displays the week like an ISO 8601 week (e.g. `M020-W05`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}-W{:02}", self.year, self.week)
+    }
+}
+
+impl FromStr for Week {
+    type Err = ParseError;
+
+    /// Parses exactly the [`Display`] format (e.g. `M020-W05`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.as_bytes();
+        check_layout(input, b"Lyyy-Www")?;
+
+        let Some(year) = Year::from_ascii([input[0], input[1], input[2], input[3]]) else {
+            unreachable!("every letter followed by three digits is a valid year")
+        };
+
+        let week = (input[6] - b'0') * 10 + (input[7] - b'0');
+
+        Self::new(year, week).ok_or_else(|| ParseError::new(ParseErrorKind::InvalidWeek, 6..8))
+    }
+}
+
+impl From<Date> for Week {
+    fn from(date: Date) -> Self {
+        date.in_week()
+    }
+}
+
+/// A quarter of a specific SAC13 year (1 - 4).
+///
+/// Every quarter consists of 13 weeks, see [`Granularity::Quarter`](crate::Granularity::Quarter).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{Quarter, date, year};
+///
+/// let q2 = Quarter::new(year!(M020), 2).unwrap();
+///
+/// assert_eq!(q2.first_day(), date!(M020 - 04 - 08));
+/// assert!(q2.contains(date!(M020 - 05 - 21)));
+/// assert_eq!(Quarter::from(date!(M020 - 05 - 21)), q2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quarter {
+    year: Year,
+    quarter: u8,
+}
+
+impl Quarter {
+    /// Quarter of the year from its number (1 - 4).
+    ///
+    /// Returns `None` for invalid quarter numbers.
+    #[must_use]
+    pub const fn new(year: Year, quarter: u8) -> Option<Self> {
+        if quarter == 0 || quarter > 4 {
+            None
+        } else {
+            Some(Self { year, quarter })
+        }
+    }
+
+    #[must_use]
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    /// Number of the quarter in the year (1 - 4).
+    #[must_use]
+    pub const fn quarter(self) -> u8 {
+        self.quarter
+    }
+
+    /// First day of the quarter.
+    #[must_use]
+    pub const fn first_day(self) -> Date {
+        self.week(1).first_day()
+    }
+
+    /// Last day of the quarter.
+    #[must_use]
+    pub const fn last_day(self) -> Date {
+        self.week(13).last_day()
+    }
+
+    /// Returns `true` if the date is in this quarter.
+    #[must_use]
+    pub const fn contains(self, date: Date) -> bool {
+        date.year().value() == self.year.value() && quarter_of(date) == self.quarter
+    }
+
+    /// The n-th week (1 - 13) of the quarter.
+    const fn week(self, n: u8) -> Week {
+        Week {
+            year: self.year,
+            week: (self.quarter - 1) * 13 + n,
+        }
+    }
+}
+
+impl From<Date> for Quarter {
+    fn from(date: Date) -> Self {
+        Self {
+            year: date.year(),
+            quarter: quarter_of(date),
+        }
+    }
+}
+
+/// A fiscal year that starts with a month other than March.
+///
+/// The fiscal year is named after the SAC13 year it starts in, e.g. the fiscal year M020
+/// starting in July lasts from M020-05-01 to M021-04-28. Fiscal years starting in March
+/// are the same as SAC13 years.
+///
+/// Like SAC13 quarters, fiscal quarters consist of 13 weeks, so they don't depend on
+/// month boundaries. Synchronization days belong to the quarter of the week before them.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{DateRange, FiscalYear, Month, date, year};
+///
+/// let fy = FiscalYear::new(year!(M020), Month::July);
+///
+/// assert_eq!(FiscalYear::from_date(date!(M021 - 02 - 10), Month::July), Some(fy));
+/// assert_eq!(
+///     fy.range(),
+///     DateRange::new(date!(M020 - 05 - 01), date!(M021 - 04 - 28)).unwrap()
+/// );
+/// assert_eq!(fy.quarter_of(date!(M020 - 05 - 01)), Some(1));
+/// assert_eq!(fy.quarter_of(date!(M020 - 13 - 10)), Some(3));
+/// assert_eq!(fy.quarter_of(date!(M021 - 05 - 01)), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FiscalYear {
+    year: Year,
+    start: Month,
+}
+
+impl FiscalYear {
+    /// The fiscal year starting with the given month of the SAC13 year.
+    #[must_use]
+    pub const fn new(year: Year, start: Month) -> Self {
+        Self { year, start }
+    }
+
+    /// The fiscal year starting with the given month that contains the date.
+    ///
+    /// Returns `None` for dates before the start month of [`Year::MIN`].
+    #[must_use]
+    pub const fn from_date(date: Date, start: Month) -> Option<Self> {
+        let year = if date.month().ord() >= start.ord() {
+            date.year()
+        } else {
+            match date.year().previous() {
+                Some(previous) => previous,
+                None => return None,
+            }
+        };
+
+        Some(Self { year, start })
+    }
+
+    /// SAC13 year the fiscal year starts in.
+    #[must_use]
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    /// Month the fiscal year starts with.
+    #[must_use]
+    pub const fn start_month(self) -> Month {
+        self.start
+    }
+
+    /// The days of the fiscal year.
+    ///
+    /// The last fiscal year ends with [`Date::MAX`](CalendarDate::MAX), even if it's shorter.
+    #[must_use]
+    pub fn range(self) -> DateRange {
+        let first = day_of(self.year, self.start, 1);
+
+        let last = self
+            .year
+            .next()
+            .and_then(|year| day_of(year, self.start, 1).yesterday())
+            .unwrap_or(Date::MAX);
+
+        DateRange::new(first, last).unwrap_or_else(|| unreachable!())
+    }
+
+    /// Returns `true` if the date is in this fiscal year.
+    #[must_use]
+    pub fn contains(self, date: Date) -> bool {
+        self.range().contains(date)
+    }
+
+    /// Number of the fiscal quarter (1 - 4) the date belongs to.
+    ///
+    /// Returns `None` if the date isn't in this fiscal year.
+    #[must_use]
+    pub fn quarter_of(self, date: Date) -> Option<u8> {
+        if !self.contains(date) {
+            return None;
+        }
+
+        let month = (date.month().ord() + 13 - self.start.ord()) % 13;
+        let week = month * 4 + date.week_of_month() - 1;
+
+        Some(week / 13 + 1)
+    }
+}
+
+/// Number of the quarter (1 - 4) the date belongs to.
+const fn quarter_of(date: Date) -> u8 {
+    (date.week_of_year() - 1) / 13 + 1
+}
+
+/// The day of a month that is known to exist.
+const fn day_of(year: Year, month: Month, day: u8) -> Date {
+    let Some(date) = Date::from_ymd(year, month, day) else {
+        unreachable!()
+    };
+
+    date
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    #[test]
+    fn periods_contain_their_days() {
+        for date in [
+            date!(M020 - 01 - 01),
+            date!(M020 - 13 - 29),
+            date!(M021 - 06 - 29),
+            date!(M021 - 07 - 01),
+        ] {
+            for first_day in [
+                YearMonth::from(date).first_day(),
+                date.in_week().first_day(),
+                Quarter::from(date).first_day(),
+            ] {
+                assert!(first_day <= date, "{date}");
+            }
+
+            let month = YearMonth::from(date);
+            let week = date.in_week();
+            let quarter = Quarter::from(date);
+
+            assert!(month.contains(date) && week.contains(date) && quarter.contains(date));
+            assert!(!month.contains(month.last_day().tomorrow().unwrap()));
+            assert!(!week.contains(week.last_day().tomorrow().unwrap()));
+            assert!(!quarter.contains(quarter.last_day().tomorrow().unwrap()));
+            assert!(!quarter.contains(quarter.first_day().yesterday().unwrap()));
+        }
+
+        assert_eq!(
+            date!(M021 - 06 - 29).in_week().last_day(),
+            date!(M021 - 06 - 29)
+        );
+        assert_eq!(
+            Quarter::new(year!(M021), 2).unwrap().last_day(),
+            date!(M021 - 07 - 14)
+        );
+    }
+
+    #[test]
+    fn fiscal_years() {
+        for start in [Month::March, Month::July, Month::Addenduary] {
+            let fy = FiscalYear::new(year!(M021), start);
+            let range = fy.range();
+
+            assert!(matches!(range.duration_days(), 365 | 366));
+            assert_eq!(range.start().month(), start);
+            assert_eq!(fy.quarter_of(range.start()), Some(1));
+            assert_eq!(fy.quarter_of(range.end()), Some(4));
+            assert_eq!(FiscalYear::from_date(range.end(), start), Some(fy));
+            assert_eq!(
+                FiscalYear::from_date(range.end().tomorrow().unwrap(), start),
+                Some(FiscalYear::new(year!(M022), start))
+            );
+        }
+
+        let march = FiscalYear::new(year!(M021), Month::March);
+        for date in [
+            date!(M021 - 04 - 07),
+            date!(M021 - 06 - 29),
+            date!(M021 - 13 - 29),
+        ] {
+            assert_eq!(march.quarter_of(date), Some(Quarter::from(date).quarter()));
+        }
+
+        let last = FiscalYear::new(Year::MAX, Month::July);
+        assert_eq!(last.range().end(), Date::MAX);
+        assert_eq!(FiscalYear::from_date(Date::MIN, Month::July), None);
+        assert_eq!(
+            FiscalYear::from_date(date!(A000 - 05 - 01), Month::July),
+            Some(FiscalYear::new(Year::MIN, Month::July))
+        );
+    }
+
+    #[test]
+    fn week_arithmetic() {
+        let first = Week::new(Year::MIN, 1).unwrap();
+        let last = Week::new(Year::MAX, 52).unwrap();
+
+        assert_eq!(first.previous(), None);
+        assert_eq!(last.next(), None);
+        assert_eq!(first.checked_add(26_000 * 52 - 1), Some(last));
+        assert_eq!(last.checked_add(-(26_000 * 52 - 1)), Some(first));
+        assert_eq!(first.checked_add(i32::MIN), None);
+
+        let mut week = Week::new(year!(M020), 1).unwrap();
+
+        for _ in 0..60 {
+            let next = week.next().unwrap();
+
+            assert_eq!(next.previous(), Some(week));
+            assert_eq!(next.first_day(), week.last_day().tomorrow().unwrap());
+            week = next;
+        }
+
+        assert_eq!(week, Week::new(year!(M021), 9).unwrap());
+    }
+
+    #[test]
+    fn week_display_and_parsing() {
+        for week in [
+            Week::new(Year::MIN, 1).unwrap(),
+            Week::new(year!(M020), 5).unwrap(),
+            Week::new(Year::MAX, 52).unwrap(),
+        ] {
+            assert_eq!(format!("{week}").parse(), Ok(week));
+        }
+
+        assert_eq!(
+            format!("{}", Week::new(year!(M020), 5).unwrap()),
+            "M020-W05"
+        );
+
+        for (input, kind, span) in [
+            ("M020-W53", ParseErrorKind::InvalidWeek, 6..8),
+            ("M020-W00", ParseErrorKind::InvalidWeek, 6..8),
+            ("M020-w05", ParseErrorKind::InvalidCharacter, 5..6),
+            ("M020-05", ParseErrorKind::InvalidLength, 0..7),
+            ("m020-W05", ParseErrorKind::InvalidCharacter, 0..1),
+        ] {
+            let error = input.parse::<Week>().unwrap_err();
+            assert_eq!((error.kind(), error.span()), (kind, span), "{input}");
+        }
+    }
+}
@@ -1,17 +1,31 @@
 //! Reexports for common types.
+//!
+//! `use sac13::prelude::*` brings the core date types, their constructor macros and the
+//! traits into scope. [`full`] adds periods, ranges, parsing and formatting on top.
+
+pub mod full;
 
 #[doc(no_inline)]
 pub use crate::Date;
 
+#[doc(no_inline)]
+pub use crate::DateTime;
+
 #[doc(no_inline)]
 pub use crate::Month;
 
 #[doc(no_inline)]
 pub use crate::Year;
 
+#[doc(no_inline)]
+pub use crate::Weekday;
+
 #[doc(no_inline)]
 pub use crate::GregorianDate;
 
+#[doc(no_inline)]
+pub use crate::day_counts::YearOrdinal;
+
 // Macros:
 
 #[doc(no_inline)]
@@ -23,6 +37,15 @@ pub use crate::year;
 #[doc(no_inline)]
 pub use crate::date_greg;
 
+#[doc(no_inline)]
+pub use crate::weekday;
+
 // Traits
 #[doc(no_inline)]
 pub use crate::CalendarDate;
+
+#[doc(no_inline)]
+pub use crate::Datelike;
+
+#[doc(no_inline)]
+pub use crate::LeapSeconds;
@@ -0,0 +1,46 @@
+//! Reexports for everything beyond the core types: periods, ranges, parsing and formatting.
+//!
+//! ```
+//! use sac13::prelude::full::*;
+//!
+//! let date = parse_date_str("2020-08-23").unwrap().to_sac13();
+//! let week = Week::from(date);
+//!
+//! assert_eq!(date, date!(M020 - 06 - 17));
+//! assert_eq!(week.first_day(), date!(M020 - 06 - 15));
+//! assert_eq!(DateRange::new(week.first_day(), date).unwrap().iter().len(), 3);
+//! ```
+
+#[doc(no_inline)]
+pub use super::*;
+
+// Periods and ranges
+
+#[doc(no_inline)]
+pub use crate::{
+    CalendarDuration, DateChunks, DateIter, DateRange, DayOfMonth, FiscalYear, Granularity,
+    Quarter, SyncDays, Week, YearMonth,
+};
+
+// Parsing
+
+#[doc(no_inline)]
+pub use crate::{
+    ComponentOrder, DateInterpretation, ParseError, ParseErrorKind, ParseOptions, ParsedDate,
+    ParsedDateTime, ParsedTime, Separator, parse_date_str, parse_date_str_with, parse_datetime_str,
+    parse_datetime_str_with, try_interpret_date_str, try_parse_date_str_with,
+    try_parse_datetime_str_with,
+};
+
+#[cfg(feature = "relative")]
+#[doc(no_inline)]
+pub use crate::parse_relative;
+
+// Formatting
+
+#[doc(no_inline)]
+pub use crate::{DateFormat, GregorianYearFormat, LetterCase};
+
+#[cfg(feature = "names")]
+#[doc(no_inline)]
+pub use crate::HumanizedRelative;
@@ -0,0 +1,500 @@
+use core::{fmt::Display, iter::FusedIterator, ops::RangeInclusive};
+
+use crate::{
+    Date, Granularity,
+    traits::{CalendarDate, DateIter},
+};
+
+/// An inclusive, non-empty range of SAC13 dates.
+///
+/// Both the [`start`](Self::start()) and the [`end`](Self::end()) date are part of the range.
+/// Inclusive ranges fit calendars better than half-open ones, because every range
+/// (even one that ends on [`Date::MAX`](CalendarDate::MAX)) can be represented.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+/// use sac13::DateRange;
+///
+/// let booking = DateRange::new(date!(M024 - 03 - 10), date!(M024 - 03 - 16)).unwrap();
+/// let request = DateRange::new(date!(M024 - 03 - 15), date!(M024 - 03 - 20)).unwrap();
+///
+/// assert!(booking.overlaps(&request));
+/// assert_eq!(booking.duration_days(), 7);
+/// assert_eq!(
+///     booking.intersect(&request),
+///     DateRange::new(date!(M024 - 03 - 15), date!(M024 - 03 - 16))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// Range from `start` to `end` (both inclusive).
+    ///
+    /// Returns `None` if `start` is after `end`.
+    #[must_use]
+    pub fn new(start: Date, end: Date) -> Option<Self> {
+        if start > end {
+            None
+        } else {
+            Some(Self { start, end })
+        }
+    }
+
+    /// Range that only contains a single day.
+    #[must_use]
+    pub const fn single(date: Date) -> Self {
+        Self {
+            start: date,
+            end: date,
+        }
+    }
+
+    /// First day of the range.
+    #[must_use]
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Last day of the range (inclusive).
+    #[must_use]
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Number of days in the range. Always at least one.
+    #[must_use]
+    pub fn duration_days(&self) -> u32 {
+        (self.end.as_julian() - self.start.as_julian()).unsigned_abs() + 1
+    }
+
+    /// Iterates over the days of the range in order.
+    ///
+    /// The iterator can be reversed and knows its length.
+    ///
+    /// ```
+    /// use sac13::{DateRange, date};
+    ///
+    /// let range = DateRange::new(date!(M020 - 01 - 01), date!(M020 - 01 - 28)).unwrap();
+    ///
+    /// assert_eq!(range.iter().len(), 28);
+    /// assert_eq!(range.iter().rev().nth(1), Some(date!(M020 - 01 - 27)));
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> DateIter<Date> {
+        DateIter::new(self.start.as_julian(), self.end.as_julian(), true)
+    }
+
+    /// Splits the range into sub-ranges aligned to the periods of the granularity,
+    /// e.g. for report pagination or batched queries.
+    ///
+    /// Only the first and the last chunk can be partial periods. The iterator can be
+    /// reversed and knows its length.
+    ///
+    /// ```
+    /// use sac13::{DateRange, Granularity, date};
+    ///
+    /// let range = DateRange::new(date!(M020 - 03 - 20), date!(M020 - 05 - 02)).unwrap();
+    /// let mut months = range.chunks(Granularity::Month);
+    ///
+    /// assert_eq!(months.len(), 3);
+    /// assert_eq!(months.next(), DateRange::new(date!(M020 - 03 - 20), date!(M020 - 03 - 28)));
+    /// assert_eq!(months.next(), DateRange::new(date!(M020 - 04 - 01), date!(M020 - 04 - 28)));
+    /// assert_eq!(months.next(), DateRange::new(date!(M020 - 05 - 01), date!(M020 - 05 - 02)));
+    /// assert_eq!(months.next(), None);
+    /// ```
+    #[must_use]
+    pub const fn chunks(&self, granularity: Granularity) -> DateChunks {
+        DateChunks {
+            remaining: Some(*self),
+            granularity,
+        }
+    }
+
+    /// All days of the range in order.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// use sac13::{DateRange, date};
+    ///
+    /// let range = DateRange::new(date!(M020 - 13 - 28), date!(M021 - 01 - 01)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     range.collect_vec(),
+    ///     [date!(M020 - 13 - 28), date!(M020 - 13 - 29), date!(M021 - 01 - 01)]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn collect_vec(&self) -> alloc::vec::Vec<Date> {
+        self.iter().collect()
+    }
+
+    /// Returns `true` if the date is part of the range.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// Returns `true` if both ranges have at least one day in common.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Returns `true` if the ranges don't overlap but one starts directly after the other ends.
+    #[must_use]
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        self.end.tomorrow() == Some(other.start) || other.end.tomorrow() == Some(self.start)
+    }
+
+    /// The days both ranges have in common.
+    ///
+    /// Returns `None` if the ranges don't overlap.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The range covering the days of both ranges.
+    ///
+    /// Returns `None` if the ranges neither overlap nor are adjacent,
+    /// because the result wouldn't be a contiguous range.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.overlaps(other) || self.is_adjacent(other) {
+            Some(Self {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The days of `self` that are not part of `other`.
+    ///
+    /// Because `other` might be in the middle of `self`, the result can consist of up to two ranges:
+    /// the part before `other` and the part after `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    /// use sac13::DateRange;
+    ///
+    /// let month = DateRange::new(date!(M024 - 05 - 01), date!(M024 - 05 - 28)).unwrap();
+    /// let holidays = DateRange::new(date!(M024 - 05 - 08), date!(M024 - 05 - 14)).unwrap();
+    ///
+    /// let (before, after) = month.difference(&holidays);
+    ///
+    /// assert_eq!(before, DateRange::new(date!(M024 - 05 - 01), date!(M024 - 05 - 07)));
+    /// assert_eq!(after, DateRange::new(date!(M024 - 05 - 15), date!(M024 - 05 - 28)));
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if !self.overlaps(other) {
+            return (Some(*self), None);
+        }
+
+        let before = if other.start > self.start {
+            other
+                .start
+                .yesterday()
+                .and_then(|end| Self::new(self.start, end))
+        } else {
+            None
+        };
+
+        let after = if other.end < self.end {
+            other
+                .end
+                .tomorrow()
+                .and_then(|start| Self::new(start, self.end))
+        } else {
+            None
+        };
+
+        (before, after)
+    }
+}
+
+impl From<DateRange> for RangeInclusive<Date> {
+    /// Converts into a standard range, e.g. to use it with [`CalendarDate::clamp_to()`].
+    fn from(value: DateRange) -> Self {
+        value.start..=value.end
+    }
+}
+
+/// Iterator over the sub-ranges of a [`DateRange`], see [`DateRange::chunks()`].
+#[derive(Debug, Clone)]
+pub struct DateChunks {
+    remaining: Option<DateRange>,
+    granularity: Granularity,
+}
+
+impl Iterator for DateChunks {
+    type Item = DateRange;
+
+    fn next(&mut self) -> Option<DateRange> {
+        let DateRange { start, end } = self.remaining?;
+
+        let chunk_end = self
+            .granularity
+            .next_period_start(start)
+            .and_then(Date::yesterday)
+            .map_or(end, |period_end| period_end.min(end));
+
+        self.remaining = chunk_end
+            .tomorrow()
+            .and_then(|next| DateRange::new(next, end));
+
+        Some(DateRange {
+            start,
+            end: chunk_end,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining.map_or(0, |DateRange { start, end }| {
+            let periods = self.granularity.period_index(end) - self.granularity.period_index(start);
+            periods.unsigned_abs() as usize + 1
+        });
+
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DateChunks {
+    fn next_back(&mut self) -> Option<DateRange> {
+        let DateRange { start, end } = self.remaining?;
+        let chunk_start = self.granularity.period_start(end).max(start);
+
+        self.remaining = chunk_start
+            .yesterday()
+            .and_then(|previous| DateRange::new(start, previous));
+
+        Some(DateRange {
+            start: chunk_start,
+            end,
+        })
+    }
+}
+
+impl ExactSizeIterator for DateChunks {}
+
+impl FusedIterator for DateChunks {}
+
+impl IntoIterator for DateRange {
+    type Item = Date;
+    type IntoIter = DateIter<Date>;
+
+    /// Same as [`iter()`](Self::iter()).
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &DateRange {
+    type Item = Date;
+    type IntoIter = DateIter<Date>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for DateRange {
+    /// Displays the range as `start..=end`.
+    ///
+    /// ```
+    /// use sac13::{date, DateRange};
+    ///
+    /// let range = DateRange::new(date!(M020 - 01 - 01), date!(M020 - 01 - 07)).unwrap();
+    /// assert_eq!(format!("{range}"), "M020-01-01..=M020-01-07");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+    use super::DateRange;
+    use crate::Date;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "DateRange")]
+    struct RawDateRange {
+        start: Date,
+        end: Date,
+    }
+
+    impl Serialize for DateRange {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            RawDateRange {
+                start: self.start,
+                end: self.end,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DateRange {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = RawDateRange::deserialize(deserializer)?;
+            Self::new(raw.start, raw.end).ok_or_else(|| D::Error::custom("start is after end"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: Date, end: Date) -> DateRange {
+        DateRange::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn start_after_end_is_rejected() {
+        assert_eq!(
+            DateRange::new(date!(M020 - 01 - 02), date!(M020 - 01 - 01)),
+            None
+        );
+    }
+
+    #[test]
+    fn duration_spans_leap_day() {
+        let r = range(date!(M021 - 06 - 28), date!(M021 - 07 - 01));
+        assert_eq!(r.duration_days(), 3);
+
+        let full = range(Date::MIN, Date::MAX);
+        assert_eq!(full.duration_days(), 9_496_301);
+    }
+
+    #[test]
+    fn clamp_to_range() {
+        let r = range(date!(M020 - 01 - 08), date!(M020 - 01 - 14));
+
+        assert_eq!(Date::MIN.clamp_to(r.into()), r.start());
+        assert_eq!(Date::MAX.clamp_to(r.into()), r.end());
+        assert!(date!(M020 - 01 - 10).is_within(&r.into()));
+        assert!(!Date::MAX.is_within(&r.into()));
+        assert_eq!(Date::MAX.min_date(Date::MIN), Date::MIN);
+        assert_eq!(Date::MIN.max_date(Date::MAX), Date::MAX);
+    }
+
+    #[test]
+    #[should_panic = "range start must not be after its end"]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn clamp_to_empty_range_panics() {
+        let _ = Date::MIN.clamp_to(Date::MAX..=Date::MIN);
+    }
+
+    #[test]
+    fn union_requires_contiguous_ranges() {
+        let a = range(date!(M020 - 01 - 01), date!(M020 - 01 - 07));
+        let b = range(date!(M020 - 01 - 08), date!(M020 - 01 - 14));
+        let c = range(date!(M020 - 01 - 09), date!(M020 - 01 - 14));
+
+        assert_eq!(
+            a.union(&b),
+            Some(range(date!(M020 - 01 - 01), date!(M020 - 01 - 14)))
+        );
+        assert_eq!(b.union(&a), a.union(&b));
+        assert_eq!(a.union(&c), None);
+        assert!(!a.overlaps(&b));
+    }
+
+    #[test]
+    fn difference_at_the_edges() {
+        let a = range(date!(M020 - 01 - 01), date!(M020 - 01 - 07));
+
+        assert_eq!(a.difference(&a), (None, None));
+        assert_eq!(
+            a.difference(&range(date!(M020 - 01 - 05), date!(M020 - 02 - 01))),
+            (
+                Some(range(date!(M020 - 01 - 01), date!(M020 - 01 - 04))),
+                None
+            )
+        );
+        assert_eq!(
+            a.difference(&range(date!(M019 - 01 - 01), date!(M020 - 01 - 01))),
+            (
+                None,
+                Some(range(date!(M020 - 01 - 02), date!(M020 - 01 - 07)))
+            )
+        );
+        assert_eq!(
+            a.difference(&range(date!(M021 - 01 - 01), date!(M021 - 01 - 01))),
+            (Some(a), None)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let r = range(date!(M020 - 01 - 01), date!(M020 - 01 - 07));
+        let json = serde_json::to_string(&r).unwrap();
+
+        assert_eq!(json, r#"{"start":"M020-01-01","end":"M020-01-07"}"#);
+        assert_eq!(serde_json::from_str::<DateRange>(&json).unwrap(), r);
+
+        assert!(
+            serde_json::from_str::<DateRange>(r#"{"start":"M020-01-07","end":"M020-01-01"}"#)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn chunks_cover_the_range_from_both_ends() {
+        let ranges = [
+            range(date!(M020 - 12 - 20), date!(M021 - 07 - 03)),
+            range(date!(M021 - 06 - 29), date!(M021 - 06 - 29)),
+            range(Date::MAX.add_days(-400).unwrap(), Date::MAX),
+        ];
+
+        for r in ranges {
+            for granularity in [
+                Granularity::Day,
+                Granularity::Week,
+                Granularity::Month,
+                Granularity::Quarter,
+                Granularity::Year,
+            ] {
+                let chunks: std::vec::Vec<_> = r.chunks(granularity).collect();
+                let mut reversed: std::vec::Vec<_> = r.chunks(granularity).rev().collect();
+                reversed.reverse();
+
+                assert_eq!(chunks, reversed);
+                assert_eq!(chunks.len(), r.chunks(granularity).len());
+                assert_eq!(chunks.first().map(DateRange::start), Some(r.start()));
+                assert_eq!(chunks.last().map(DateRange::end), Some(r.end()));
+
+                for (chunk, next) in chunks.iter().zip(&chunks[1..]) {
+                    assert_eq!(chunk.end().tomorrow(), Some(next.start()));
+                    assert_eq!(granularity.period_start(next.start()), next.start());
+                }
+            }
+        }
+
+        let weeks = range(date!(M021 - 06 - 20), date!(M021 - 07 - 02)).chunks(Granularity::Week);
+        assert_eq!(
+            weeks.collect::<std::vec::Vec<_>>(),
+            [
+                range(date!(M021 - 06 - 20), date!(M021 - 06 - 21)),
+                range(date!(M021 - 06 - 22), date!(M021 - 06 - 29)),
+                range(date!(M021 - 07 - 01), date!(M021 - 07 - 02)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Parsing of relative date expressions like "tomorrow" or "in 3 weeks".
+
+use crate::{Date, Granularity, Month, traits::CalendarDate};
+
+/// Unit of a relative expression.
+#[derive(Clone, Copy)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    const fn parse(token: &str) -> Option<Self> {
+        let token = Token(token);
+
+        Some(if token.is("day") || token.is("days") {
+            Self::Day
+        } else if token.is("week") || token.is("weeks") {
+            Self::Week
+        } else if token.is("month") || token.is("months") {
+            Self::Month
+        } else if token.is("year") || token.is("years") {
+            Self::Year
+        } else {
+            return None;
+        })
+    }
+
+    fn apply(self, date: Date, n: i32) -> Option<Date> {
+        match self {
+            Self::Day => date.add_days(n),
+            Self::Week => date.add_days(n.checked_mul(7)?),
+            Self::Month => date.add_months(n),
+            Self::Year => date.add_years(n),
+        }
+    }
+
+    const fn granularity(self) -> Granularity {
+        match self {
+            Self::Day => Granularity::Day,
+            Self::Week => Granularity::Week,
+            Self::Month => Granularity::Month,
+            Self::Year => Granularity::Year,
+        }
+    }
+}
+
+/// Word of the input, compared case-insensitively.
+struct Token<'a>(&'a str);
+
+impl Token<'_> {
+    const fn is(&self, word: &str) -> bool {
+        self.0.eq_ignore_ascii_case(word)
+    }
+}
+
+fn parse_count(token: &str) -> Option<i32> {
+    if token.eq_ignore_ascii_case("a") || token.eq_ignore_ascii_case("an") {
+        return Some(1);
+    }
+
+    if !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+fn parse_month(token: &str) -> Option<Month> {
+    (1..=13)
+        .filter_map(Month::new)
+        .find(|m| m.name().eq_ignore_ascii_case(token))
+}
+
+/// Parses a relative date expression, based on the given date.
+///
+/// Returns `None` if the expression isn't understood or the result is outside the SAC13 range.
+/// The comparison is case-insensitive and words can be separated by any amount of whitespace.
+///
+/// ## Supported expressions
+///
+/// | Expression                             | Result                                                  |
+/// |----------------------------------------|---------------------------------------------------------|
+/// | `today`, `tomorrow`, `yesterday`       | The base date, the day after or before.                 |
+/// | `in <n> <unit>`                        | `n` units after the base date.                          |
+/// | `<n> <unit> ago`                       | `n` units before the base date.                         |
+/// | `next <unit>`, `last <unit>`           | First day of the following or previous period.          |
+/// | `next <month>`, `last <month>`         | First day of the next or last occurrence of the month.  |
+///
+/// Units are `day`, `week`, `month` and `year` (singular or plural) and `<n>` is either
+/// a number or `a`/`an`. Months and years are added with [`Date::add_months()`] and
+/// [`Date::add_years()`], weeks are SAC13 weeks (see [`Granularity`]).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{date, parse_relative};
+///
+/// let base = date!(M020 - 05 - 21);
+///
+/// assert_eq!(parse_relative("tomorrow", base), Some(date!(M020 - 05 - 22)));
+/// assert_eq!(parse_relative("in 3 weeks", base), Some(date!(M020 - 06 - 14)));
+/// assert_eq!(parse_relative("2 months ago", base), Some(date!(M020 - 03 - 21)));
+/// assert_eq!(parse_relative("next week", base), Some(date!(M020 - 05 - 22)));
+/// assert_eq!(parse_relative("next Addenduary", base), Some(date!(M020 - 13 - 01)));
+/// ```
+#[must_use]
+pub fn parse_relative(input: &str, base: Date) -> Option<Date> {
+    let mut tokens = input.split_whitespace();
+
+    let t = [tokens.next(), tokens.next(), tokens.next()];
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    match t {
+        [Some(word), None, None] => {
+            let word = Token(word);
+
+            if word.is("today") {
+                Some(base)
+            } else if word.is("tomorrow") {
+                base.add_days(1)
+            } else if word.is("yesterday") {
+                base.add_days(-1)
+            } else {
+                None
+            }
+        }
+        [Some(direction), Some(target), None] => {
+            let direction = Token(direction);
+
+            let forward = if direction.is("next") {
+                true
+            } else if direction.is("last") {
+                false
+            } else {
+                return None;
+            };
+
+            if let Some(unit) = Unit::parse(target) {
+                let granularity = unit.granularity();
+
+                return if forward {
+                    granularity.next_period_start(base)
+                } else {
+                    let previous = granularity.period_start(base).yesterday()?;
+                    Some(granularity.period_start(previous))
+                };
+            }
+
+            let month = parse_month(target)?;
+
+            let year = if forward && month <= base.month() {
+                base.year().next()?
+            } else if !forward && month >= base.month() {
+                base.year().previous()?
+            } else {
+                base.year()
+            };
+
+            Date::from_ymd(year, month, 1)
+        }
+        [Some(first), Some(second), Some(third)] => {
+            if Token(first).is("in") {
+                Unit::parse(third)?.apply(base, parse_count(second)?)
+            } else if Token(third).is("ago") {
+                Unit::parse(second)?.apply(base, -parse_count(first)?)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_words() {
+        let base = date!(M020 - 13 - 29);
+
+        assert_eq!(parse_relative("today", base), Some(base));
+        assert_eq!(
+            parse_relative("  TOMORROW ", base),
+            Some(date!(M021 - 01 - 01))
+        );
+        assert_eq!(
+            parse_relative("Yesterday", base),
+            Some(date!(M020 - 13 - 28))
+        );
+        assert_eq!(parse_relative("", base), None);
+        assert_eq!(parse_relative("later", base), None);
+    }
+
+    #[test]
+    fn next_and_last_periods() {
+        let base = date!(M020 - 05 - 21);
+
+        assert_eq!(
+            parse_relative("last week", base),
+            Some(date!(M020 - 05 - 08))
+        );
+        assert_eq!(
+            parse_relative("last week", date!(M020 - 13 - 29)),
+            Some(date!(M020 - 13 - 15))
+        );
+        assert_eq!(
+            parse_relative("next month", base),
+            Some(date!(M020 - 06 - 01))
+        );
+        assert_eq!(
+            parse_relative("last month", base),
+            Some(date!(M020 - 04 - 01))
+        );
+        assert_eq!(
+            parse_relative("next year", base),
+            Some(date!(M021 - 01 - 01))
+        );
+        assert_eq!(
+            parse_relative("last year", base),
+            Some(date!(M019 - 01 - 01))
+        );
+    }
+
+    #[test]
+    fn next_and_last_months() {
+        let base = date!(M020 - 05 - 21);
+
+        assert_eq!(
+            parse_relative("next july", base),
+            Some(date!(M021 - 05 - 01))
+        );
+        assert_eq!(
+            parse_relative("last July", base),
+            Some(date!(M019 - 05 - 01))
+        );
+        assert_eq!(
+            parse_relative("last March", base),
+            Some(date!(M020 - 01 - 01))
+        );
+        assert_eq!(parse_relative("next Smarch", base), None);
+    }
+
+    #[test]
+    fn counted_units() {
+        let base = date!(M020 - 05 - 21);
+
+        assert_eq!(
+            parse_relative("in a day", base),
+            Some(date!(M020 - 05 - 22))
+        );
+        assert_eq!(
+            parse_relative("in 1 year", base),
+            Some(date!(M021 - 05 - 21))
+        );
+        assert_eq!(parse_relative("an hour ago", base), None);
+        assert_eq!(parse_relative("in -3 days", base), None);
+        assert_eq!(parse_relative("in 99999999999 days", base), None);
+        assert_eq!(parse_relative("in 30000 years", base), None);
+    }
+}
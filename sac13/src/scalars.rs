@@ -1,6 +1,11 @@
 //! Types in this module represent linear
 
-use crate::{traits::CalendarDate, weekday::Weekday, YearType};
+use crate::{
+    Date, Month, YearType,
+    date_sac13::raw_date::{YearOrdinal, yo_to_date},
+    traits::CalendarDate,
+    weekday::Weekday,
+};
 
 macro_rules! scalar {
     (
@@ -41,6 +46,40 @@ macro_rules! scalar {
                 }
             }
 
+            #[doc = concat!("Like [`new()`](Self::new()), but clamps invalid values to [`MIN`](Self::MIN) or [`MAX`](Self::MAX) instead of returning `None`.")]
+            ///
+            /// Useful for display-only contexts, where a date at the limit is better than none.
+            #[inline]
+            #[must_use]
+            pub const fn new_saturating(value: $t) -> Self {
+                if value < Self::MIN_INT {
+                    Self::MIN
+                } else if value > Self::MAX_INT {
+                    Self::MAX
+                } else {
+                    Self(value)
+                }
+            }
+
+            #[doc = concat!("Describes whether the value is a valid ", stringify!($name), " or how far it's outside the range.")]
+            ///
+            /// Useful for diagnostics where [`new()`](Self::new()) only returns `None`.
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // positive and below 2^32
+            pub const fn range_check(value: $t) -> RangeStatus {
+                if value < Self::MIN_INT {
+                    RangeStatus::Below {
+                        distance: (Self::MIN_INT as i64 - value as i64) as u32,
+                    }
+                } else if value > Self::MAX_INT {
+                    RangeStatus::Above {
+                        distance: (value as i64 - Self::MAX_INT as i64) as u32,
+                    }
+                } else {
+                    RangeStatus::InRange
+                }
+            }
+
             /// Returns the underlying integer value.
             #[inline(always)]
             #[must_use]
@@ -76,6 +115,45 @@ macro_rules! scalar {
     };
 }
 
+/// Result of a range check (e.g. [`JulianDay::range_check()`]).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{RangeStatus, day_counts::JulianDay};
+///
+/// assert_eq!(JulianDay::range_check(2_451_624), RangeStatus::InRange);
+/// assert_eq!(
+///     JulianDay::range_check(JulianDay::MIN_INT - 10),
+///     RangeStatus::Below { distance: 10 }
+/// );
+/// assert_eq!(
+///     JulianDay::range_check(i32::MAX),
+///     RangeStatus::Above { distance: (i32::MAX - JulianDay::MAX_INT) as u32 }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeStatus {
+    /// The value is within the SAC13 range.
+    InRange,
+
+    /// The value is before the first valid value (e.g. [`Date::MIN`](CalendarDate::MIN)),
+    /// by the given distance (in days or years).
+    Below { distance: u32 },
+
+    /// The value is after the last valid value (e.g. [`Date::MAX`](CalendarDate::MAX)),
+    /// by the given distance (in days or years).
+    Above { distance: u32 },
+}
+
+impl RangeStatus {
+    /// Returns `true` if the value is valid.
+    #[must_use]
+    pub const fn is_in_range(self) -> bool {
+        matches!(self, Self::InRange)
+    }
+}
+
 macro_rules! scalar_day {
     (
         $(#[$attr:meta])*
@@ -102,6 +180,35 @@ macro_rules! scalar_day {
 
         impl $name {
             const JULIAN_OFFSET: i32 = -1931284 - $min;
+
+            /// Julian Day Number of this day (const version of [`CalendarDate::as_julian()`]).
+            pub(crate) const fn julian_day_number(self) -> i32 {
+                (self.0 as i32) + Self::JULIAN_OFFSET
+            }
+
+            /// Const version of [`CalendarDate::from_julian()`].
+            pub(crate) const fn from_julian_day_number(value: i32) -> Option<Self> {
+                Self::new(ok!(value.checked_sub(Self::JULIAN_OFFSET)) as $t)
+            }
+
+            /// Gregorian weekday of the day.
+            #[must_use]
+            pub const fn weekday(self) -> Weekday {
+                Weekday::from_julian_day_number(self.julian_day_number())
+            }
+
+            /// Converts the day to a SAC13 [`Date`].
+            ///
+            /// Same as `convert()`, but usable in const contexts.
+            #[must_use]
+            pub const fn to_date(self) -> Date {
+                // all day counts cover the same range
+                let Some(epoch_day) = CycleEpochDay::from_julian_day_number(self.julian_day_number()) else {
+                    unreachable!()
+                };
+
+                yo_to_date(YearOrdinal::from_epoch_day(epoch_day))
+            }
         }
 
         impl CalendarDate for $name {
@@ -109,11 +216,11 @@ macro_rules! scalar_day {
             const MAX: Self = Self::MAX;
 
             fn as_julian(&self) -> i32 {
-                (self.0 as i32) + Self::JULIAN_OFFSET
+                self.julian_day_number()
             }
 
             fn from_julian(value: i32) -> Option<Self> {
-                Self::new((value - Self::JULIAN_OFFSET) as $t)
+                Self::from_julian_day_number(value)
             }
         }
     };
@@ -180,6 +287,98 @@ scalar_day!(
     min: 0;
 );
 
+scalar_day!(
+    /// Days since 1899-12-30 (Gregorian), the serial day numbers of spreadsheets.
+    ///
+    /// Excel and Lotus 1-2-3 (in their 1900 date system) count from 1900-01-01 as day 1,
+    /// but treat 1900 as a leap year, so day 60 is the nonexistent 1900-02-29. From 1900-03-01
+    /// (day 61) on, the serial numbers are the same as this day count, which is why other
+    /// spreadsheets and OLE Automation dates start counting at 1899-12-30 instead.
+    ///
+    /// [`new()`](Self::new()) takes the day count. Use [`from_serial()`](Self::from_serial())
+    /// and [`to_serial()`](Self::to_serial()) for serial numbers of the first two months of 1900.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    /// use sac13::day_counts::ExcelDay;
+    ///
+    /// let date: Date = ExcelDay::new(45_433).unwrap().convert();
+    /// assert_eq!(date, date!(M024 - 03 - 07)); // 2024-05-21
+    ///
+    /// assert_eq!(ExcelDay::from_serial(1), ExcelDay::new(2)); // 1900-01-01
+    /// assert_eq!(ExcelDay::from_serial(60), None); // 1900-02-29
+    /// ```
+    name: ExcelDay;
+    base: i32;
+    min: -4_346_303;
+);
+
+impl ExcelDay {
+    /// Offset from the [Unix Day](UnixDay): `excel_day = unix_day + UNIX_DAY_OFFSET`.
+    pub const UNIX_DAY_OFFSET: i32 = 25_569;
+
+    /// The day of a serial number in the 1900 date system of Excel.
+    ///
+    /// Returns `None` for serial numbers below 1 and for 60, the nonexistent 1900-02-29.
+    #[must_use]
+    pub const fn from_serial(serial: i32) -> Option<Self> {
+        match serial {
+            1..=59 => Self::new(serial + 1),
+            61.. => Self::new(serial),
+            _ => None,
+        }
+    }
+
+    /// The serial number of the day in the 1900 date system of Excel.
+    ///
+    /// Returns `None` for days before 1900-01-01 (serial number 1).
+    ///
+    /// ```
+    /// use sac13::day_counts::ExcelDay;
+    ///
+    /// assert_eq!(ExcelDay::new(60).unwrap().to_serial(), Some(59)); // 1900-02-28
+    /// assert_eq!(ExcelDay::new(61).unwrap().to_serial(), Some(61)); // 1900-03-01
+    /// assert_eq!(ExcelDay::new(1).unwrap().to_serial(), None); // 1899-12-31
+    /// ```
+    #[must_use]
+    pub const fn to_serial(self) -> Option<i32> {
+        match self.0 {
+            2..=60 => Some(self.0 - 1),
+            61.. => Some(self.0),
+            _ => None,
+        }
+    }
+}
+
+impl JulianDay {
+    /// The first day of the Gregorian calendar (1582-10-15, the day after 1582-10-04 in the Julian calendar).
+    pub const GREGORIAN_REFORM: Self = Self(2_299_161);
+}
+
+impl CycleEpochDay {
+    /// The first day of the SAC13 year zero (A000-01-01), the start of the SAC13 range.
+    pub const YEAR_ZERO: Self = Self::MIN;
+
+    /// Offset from the [Julian Day Number](JulianDay): `epoch_day = julian_day + JULIAN_DAY_OFFSET`.
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    /// use sac13::day_counts::{CycleEpochDay, JulianDay, UnixDay};
+    ///
+    /// let date = date!(M020 - 05 - 21);
+    /// let epoch_day = date.to_cycle_epoch_day().value() as i32;
+    ///
+    /// assert_eq!(epoch_day, date.to_julian_day().value() + CycleEpochDay::JULIAN_DAY_OFFSET);
+    /// assert_eq!(epoch_day, date.to_unix_day().value() + CycleEpochDay::UNIX_DAY_OFFSET);
+    /// ```
+    pub const JULIAN_DAY_OFFSET: i32 = 2_003_967;
+
+    /// Offset from the [Unix Day](UnixDay): `epoch_day = unix_day + UNIX_DAY_OFFSET`.
+    pub const UNIX_DAY_OFFSET: i32 = 4_444_555;
+}
+
 scalar!(
     /// SAC13 year. It roughly corresponds to the Gregorian Year + 10'000.
     /// Roughly, because the Gregorian Calendar starts its year with January and
@@ -254,6 +453,13 @@ scalar!(
     past: previous;
 );
 
+impl Default for Year {
+    /// The first representable year A000 (= 0).
+    fn default() -> Self {
+        Self::MIN
+    }
+}
+
 impl core::fmt::Display for Year {
     /// Displays the year with prefixed millennium indicator.
     ///
@@ -286,7 +492,7 @@ macro_rules! parse_digits {
 impl Year {
     /// Returns the year, given four ASCII digits
     #[inline(always)]
-    const fn parse_year_digits(d0: u8, d1: u8, d2: u8, d3: u8) -> Option<Self> {
+    pub(crate) const fn parse_year_digits(d0: u8, d1: u8, d2: u8, d3: u8) -> Option<Self> {
         let d0 = parse_digits!(d0, b'A', b'Z');
         let d1 = parse_digits!(d1, b'0', b'9');
         let d2 = parse_digits!(d2, b'0', b'9');
@@ -301,13 +507,155 @@ impl Year {
     /// TODO: details
     #[must_use]
     pub const fn try_from_str(year: &str) -> Option<Self> {
-        let year_bytes = year.as_bytes();
+        Self::try_from_ascii(year.as_bytes())
+    }
+
+    /// Used by the `year!()` macro for identifiers like `M020` and `m020`.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn __from_macro_ident(year: &str) -> Option<Self> {
+        let [letter, d1, d2, d3] = *year.as_bytes() else {
+            return None;
+        };
+
+        Self::parse_year_digits(letter.to_ascii_uppercase(), d1, d2, d3)
+    }
+
+    /// Used by the `year!()` macro for numbers like `12020`.
+    #[doc(hidden)]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // range checked
+    pub const fn __from_macro_number(year: i64) -> Option<Self> {
+        if year < Self::MIN_INT as i64 || year > Self::MAX_INT as i64 {
+            None
+        } else {
+            Some(Self(year as u16))
+        }
+    }
 
-        if year_bytes.len() != 4 {
+    /// Returns the year, given its display format as ASCII bytes of any length.
+    ///
+    /// Like [`try_from_str()`](Self::try_from_str()), but without the need for valid UTF-8
+    /// (e.g. for a slice of a network buffer). Returns `None` unless the input has exactly
+    /// four bytes. See [`from_ascii()`](Self::from_ascii()) for arrays.
+    ///
+    /// ```
+    /// use sac13::{Year, year};
+    ///
+    /// assert_eq!(Year::try_from_ascii(&b"M020-05-21"[..4]), Some(year!(M020)));
+    /// assert_eq!(Year::try_from_ascii(b"M02"), None);
+    /// assert_eq!(Year::try_from_ascii(b"M02\xff"), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_ascii(year: &[u8]) -> Option<Self> {
+        let [d0, d1, d2, d3] = *year else {
+            return None;
+        };
+
+        Self::parse_year_digits(d0, d1, d2, d3)
+    }
+
+    /// The year in its display format (e.g. `M020`) as ASCII bytes.
+    ///
+    /// Useful for fixed-layout binary formats or display drivers without the `fmt` machinery.
+    ///
+    /// ```
+    /// use sac13::{Year, year};
+    ///
+    /// assert_eq!(&year!(M020).to_ascii(), b"M020");
+    /// assert_eq!(Year::from_ascii(*b"M020"), Some(year!(M020)));
+    /// ```
+    #[must_use]
+    pub const fn to_ascii(&self) -> [u8; 4] {
+        const fn digit(value: u16) -> u8 {
+            b'0' + (value % 10) as u8
+        }
+
+        [
+            b'A' + (self.0 / 1000) as u8,
+            digit(self.0 / 100),
+            digit(self.0 / 10),
+            digit(self.0),
+        ]
+    }
+
+    /// Returns the year, given its display format as ASCII bytes (e.g. `*b"M020"`).
+    ///
+    /// Inverse of [`to_ascii()`](Self::to_ascii()).
+    #[must_use]
+    pub const fn from_ascii(bytes: [u8; 4]) -> Option<Self> {
+        let [d0, d1, d2, d3] = bytes;
+        Self::parse_year_digits(d0, d1, d2, d3)
+    }
+
+    /// Returns the year, given its plain five digit number (e.g. `12020` for M020).
+    ///
+    /// The year must be written with exactly five digits (zero-padded, e.g. `00020` for A020).
+    /// This is the opt-in format for datasets that store the numeric year without the
+    /// millennium letter. Prefer [`try_from_str()`](Self::try_from_str()) otherwise.
+    ///
+    /// ```
+    /// use sac13::{Year, year};
+    ///
+    /// assert_eq!(Year::try_from_plain_str("12020"), Some(year!(M020)));
+    /// assert_eq!(Year::try_from_plain_str("2020"), None);
+    /// assert_eq!(Year::try_from_plain_str("72693"), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_plain_str(year: &str) -> Option<Self> {
+        let b = year.as_bytes();
+
+        if b.len() != 5 {
             return None;
         }
 
-        Self::parse_year_digits(year_bytes[0], year_bytes[1], year_bytes[2], year_bytes[3])
+        let mut value: u16 = 0;
+        let mut i = 0;
+
+        while i < b.len() {
+            if !b[i].is_ascii_digit() {
+                return None;
+            }
+
+            // five digits might not fit into an u16
+            value = ok!(value.checked_mul(10));
+            value = ok!(value.checked_add((b[i] - b'0') as u16));
+            i += 1;
+        }
+
+        Self::new(value)
+    }
+
+    /// Displays the year as plain, zero-padded five digit number without millennium letter.
+    ///
+    /// ```
+    /// use sac13::year;
+    ///
+    /// assert_eq!(format!("{}", year!(M020).display_plain()), "12020");
+    /// assert_eq!(format!("{}", year!(A020).display_plain()), "00020");
+    /// ```
+    #[must_use]
+    pub const fn display_plain(&self) -> impl core::fmt::Display + use<> {
+        struct Plain(u16);
+
+        impl core::fmt::Display for Plain {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:05}", self.0)
+            }
+        }
+
+        Plain(self.0)
+    }
+
+    /// The year as plain, zero-padded five digit number without millennium letter (e.g. `12020`).
+    ///
+    /// See [`display_plain()`](Self::display_plain()) for a non-allocating alternative.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_plain_number_string(&self) -> alloc::string::String {
+        use alloc::string::ToString;
+
+        self.display_plain().to_string()
     }
 
     /// Returns the type of the year (leap year or common year).
@@ -329,31 +677,87 @@ impl Year {
         matches!(self.year_type(), YearType::Common)
     }
 
+    /// All leap years of the range in order.
+    ///
+    /// Requires the `alloc` feature.
+    ///
+    /// ```
+    /// use sac13::{Year, year};
+    ///
+    /// assert_eq!(
+    ///     Year::leap_years_vec(year!(M020)..=year!(M030)),
+    ///     [year!(M021), year!(M025), year!(M029)]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn leap_years_vec(range: core::ops::RangeInclusive<Self>) -> alloc::vec::Vec<Self> {
+        (range.start().0..=range.end().0)
+            .map(Self)
+            .filter(Self::is_leap)
+            .collect()
+    }
+
     /// Returns the number of days the year has.
     ///
     /// Can only be 365 or 366.
     #[must_use]
     pub const fn days(&self) -> u16 {
-        match self.year_type() {
-            YearType::Common => 365,
-            YearType::Leap => 366,
+        self.year_type().days()
+    }
+
+    /// Day of the year (1 - 366) of the first day of every month, starting with March.
+    ///
+    /// See [`Month::cumulative_days_before()`].
+    ///
+    /// ```
+    /// use sac13::{Month, year};
+    ///
+    /// let ordinals = year!(M021).month_start_ordinals();
+    ///
+    /// assert_eq!(ordinals[0], 1);
+    /// assert_eq!(ordinals[usize::from(Month::September.ord() - 1)], 170);
+    /// assert_eq!(ordinals[12], 338);
+    /// ```
+    #[must_use]
+    pub const fn month_start_ordinals(&self) -> [u16; 13] {
+        let mut ordinals = [0; 13];
+        let mut month = Month::March;
+
+        loop {
+            ordinals[month.ord() as usize - 1] = month.cumulative_days_before(self.year_type()) + 1;
+
+            if matches!(month, Month::Addenduary) {
+                break;
+            }
+
+            month = month.next();
         }
+
+        ordinals
     }
-}
 
-impl JulianDay {
-    pub fn weekday(self) -> Weekday {
-        use crate::weekday::Weekday::*;
-
-        match self.0.rem_euclid(7) {
-            0 => Monday,
-            1 => Tuesday,
-            2 => Wednesday,
-            3 => Thursday,
-            4 => Friday,
-            5 => Saturday,
-            6 => Sunday,
-            _ => unreachable!(),
+    /// The first year after `after` (exclusive) that is of the given type.
+    ///
+    /// Returns `None` if there is no such year within the SAC13 range.
+    ///
+    /// ```
+    /// use sac13::{Year, YearType, year};
+    ///
+    /// assert_eq!(Year::of_type(YearType::Leap, year!(M021)), Some(year!(M025)));
+    /// assert_eq!(Year::of_type(YearType::Common, year!(M020)), Some(year!(M022)));
+    /// assert_eq!(Year::of_type(YearType::Common, year!(Z999)), None);
+    /// ```
+    #[must_use]
+    pub const fn of_type(year_type: YearType, after: Self) -> Option<Self> {
+        let mut year = after;
+
+        loop {
+            year = ok!(year.next());
+
+            if year.year_type() as u8 == year_type as u8 {
+                return Some(year);
+            }
         }
     }
 }
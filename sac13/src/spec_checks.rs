@@ -0,0 +1,68 @@
+//! Compile-time checks of the relationships between the constants of the SAC13 specification.
+//!
+//! The day count types are generated by a macro from their minimum value only, and the
+//! conversions rely on all of them covering exactly the same days. If a refactoring skews an
+//! epoch or a range, the crate stops compiling instead of silently producing shifted dates.
+
+use crate::{
+    Date, Year,
+    scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay},
+    traits::CalendarDate,
+};
+
+/// Compares two dates in const contexts (`PartialEq` isn't const).
+const fn same_date(a: Date, b: Date) -> bool {
+    a.year().value() == b.year().value() && a.month().ord() == b.month().ord() && a.day() == b.day()
+}
+
+/// Number of days from A000-01-01 to Z999-13-29 (inclusive), counted year by year.
+const DAYS_IN_RANGE: i64 = {
+    let mut days = 0;
+    let mut year = Year::MIN.value();
+
+    while year <= Year::MAX.value() {
+        let Some(y) = Year::new(year) else {
+            unreachable!()
+        };
+
+        days += y.days() as i64;
+        year += 1;
+    }
+
+    days
+};
+
+// the range limits of the specification
+const _: () = assert!(Year::MIN.value() == 0 && Year::MAX.value() == 25_999);
+const _: () = assert!(same_date(Date::MIN, Date::SAC13_EPOCH));
+
+// all day counts cover exactly the days of the SAC13 range
+const _: () = assert!(JulianDay::MAX_INT as i64 - JulianDay::MIN_INT as i64 + 1 == DAYS_IN_RANGE);
+const _: () = assert!(UnixDay::MAX_INT as i64 - UnixDay::MIN_INT as i64 + 1 == DAYS_IN_RANGE);
+const _: () =
+    assert!(CycleEpochDay::MAX_INT as i64 - CycleEpochDay::MIN_INT as i64 + 1 == DAYS_IN_RANGE);
+const _: () = assert!(Sac13Day::MAX_INT as i64 - Sac13Day::MIN_INT as i64 + 1 == DAYS_IN_RANGE);
+const _: () = assert!(ExcelDay::MAX_INT as i64 - ExcelDay::MIN_INT as i64 + 1 == DAYS_IN_RANGE);
+
+// the epochs of the day counts
+const _: () = assert!(Sac13Day::MIN_INT == 0);
+const _: () = assert!(
+    CycleEpochDay::MIN_INT.cast_signed() == JulianDay::MIN_INT + CycleEpochDay::JULIAN_DAY_OFFSET
+);
+const _: () = assert!(
+    CycleEpochDay::MIN_INT.cast_signed() == UnixDay::MIN_INT + CycleEpochDay::UNIX_DAY_OFFSET
+);
+const _: () = assert!(ExcelDay::MIN_INT == UnixDay::MIN_INT + ExcelDay::UNIX_DAY_OFFSET);
+const _: () = assert!(CycleEpochDay::YEAR_ZERO.value() == CycleEpochDay::MIN_INT);
+
+// the conversions agree with the limits
+const _: () = assert!(Date::MIN.to_julian_day().value() == JulianDay::MIN_INT);
+const _: () = assert!(Date::MAX.to_julian_day().value() == JulianDay::MAX_INT);
+const _: () = assert!(Date::MIN.to_unix_day().value() == UnixDay::MIN_INT);
+const _: () = assert!(Date::MAX.to_sac13_day().value() == Sac13Day::MAX_INT);
+const _: () = assert!(same_date(JulianDay::MIN.to_date(), Date::MIN));
+const _: () = assert!(same_date(CycleEpochDay::MAX.to_date(), Date::MAX));
+
+// well-known anchors
+const _: () = assert!(Date::UNIX_EPOCH_DATE.to_unix_day().value() == 0);
+const _: () = assert!(Date::SAC13_EPOCH.to_julian_day().value() == JulianDay::MIN_INT);
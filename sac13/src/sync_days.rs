@@ -0,0 +1,33 @@
+/// Whether synchronization days count when looking for a day of the week.
+///
+/// Synchronization days (the 29th of a month: the leap day and the year day) are outside
+/// the regular four-week layout of SAC13. Depending on the application they are ordinary
+/// days (e.g. a Gregorian Friday is still a Friday) or days that are never scheduled
+/// (e.g. public holidays).
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{SyncDays, Weekday, date};
+///
+/// // M020-13-29 (the year day) is a Friday
+/// let date = date!(M020 - 13 - 25);
+///
+/// assert_eq!(
+///     date.next_gregorian_weekday(Weekday::Friday, SyncDays::Include),
+///     Some(date!(M020 - 13 - 29))
+/// );
+/// assert_eq!(
+///     date.next_gregorian_weekday(Weekday::Friday, SyncDays::Skip),
+///     Some(date!(M021 - 01 - 07))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SyncDays {
+    /// Synchronization days are treated like any other day.
+    #[default]
+    Include,
+
+    /// Synchronization days never match.
+    Skip,
+}
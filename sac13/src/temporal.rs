@@ -0,0 +1,405 @@
+//! Calendar methods for the JavaScript [Temporal](https://tc39.es/proposal-temporal/docs/calendars.html) API.
+//!
+//! The functions are exported with the names of the Temporal calendar methods (`dateFromFields()`,
+//! `daysInMonth()`, `inLeapYear()`, ...), so a thin JS wrapper can provide a SAC13 calendar whose
+//! results are computed by this crate. Temporal objects carry ISO 8601 (Gregorian) fields
+//! internally, so every method takes `isoYear`, `isoMonth` and `isoDay` of the date and methods
+//! returning a date return them as an array `[isoYear, isoMonth, isoDay]`.
+//!
+//! The `overflow` option is `"constrain"` or `"reject"`, like in Temporal. Errors are returned
+//! as strings (which become JS exceptions).
+//!
+//! Requires the `wasm` feature.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{Date, GregorianDate, LeapPolicy, Month, Year, traits::CalendarDate};
+
+/// Calendar identifier of SAC13.
+pub const CALENDAR_ID: &str = "sac13";
+
+/// The calendar identifier (`"sac13"`).
+#[must_use]
+#[wasm_bindgen]
+pub fn id() -> String {
+    CALENDAR_ID.to_string()
+}
+
+/// ISO fields of the SAC13 date with the given year (e.g. `12020` for M020), month (1 - 13) and day.
+///
+/// # Errors
+///
+/// Fails for dates outside the SAC13 range, invalid `overflow` values and, with
+/// `overflow = "reject"`, for months and days that don't exist.
+#[wasm_bindgen(js_name = dateFromFields)]
+pub fn date_from_fields(
+    year: i32,
+    month: i32,
+    day: i32,
+    overflow: &str,
+) -> Result<Vec<i32>, String> {
+    let year = u16::try_from(year)
+        .ok()
+        .and_then(Year::new)
+        .ok_or_else(|| format!("year {year} is outside the SAC13 range"))?;
+
+    let (month, day) = if policy(overflow)? == LeapPolicy::Reject {
+        (month, day)
+    } else {
+        let month = month.clamp(1, 13);
+        (month, day.clamp(1, month_len(year, month).into()))
+    };
+
+    let date = u8::try_from(month)
+        .ok()
+        .and_then(Month::new)
+        .zip(u8::try_from(day).ok())
+        .and_then(|(month, day)| Date::from_ymd(year, month, day))
+        .ok_or_else(|| format!("{year}-{month}-{day} is not a valid SAC13 date"))?;
+
+    Ok(iso_fields(date))
+}
+
+/// The ISO fields of the date after adding the duration.
+///
+/// All components must have the same sign. Years and months are added first (with
+/// `overflow` handling of the 29th), then weeks and days.
+///
+/// # Errors
+///
+/// Fails for invalid input, components of different signs, results outside the SAC13
+/// range and, with `overflow = "reject"`, if the 29th doesn't exist in the target month.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = dateAdd)]
+pub fn date_add(
+    iso_year: i32,
+    iso_month: i32,
+    iso_day: i32,
+    years: i32,
+    months: i32,
+    weeks: i32,
+    days: i32,
+    overflow: &str,
+) -> Result<Vec<i32>, String> {
+    let date = to_date(iso_year, iso_month, iso_day)?;
+    let policy = policy(overflow)?;
+
+    let components = [years, months, weeks, days];
+
+    if components.iter().any(|c| *c > 0) && components.iter().any(|c| *c < 0) {
+        return Err("all duration components must have the same sign".to_string());
+    }
+
+    let days = weeks
+        .checked_mul(7)
+        .and_then(|weeks| weeks.checked_add(days));
+
+    let result = date
+        .add_years_with(years, policy)
+        .and_then(|date| date.add_months_with(months, policy))
+        .zip(days)
+        .and_then(|(date, days)| date.add_days(days))
+        .ok_or_else(|| "the result is outside the SAC13 range or doesn't exist".to_string())?;
+
+    Ok(iso_fields(result))
+}
+
+/// The difference between two dates as `[years, months, weeks, days]`.
+///
+/// `largestUnit` is `"year"`, `"month"`, `"week"` or `"day"` (the default, if empty).
+/// The result is negative if the second date is before the first one.
+///
+/// # Errors
+///
+/// Fails for invalid dates and units.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = dateUntil)]
+pub fn date_until(
+    iso_year: i32,
+    iso_month: i32,
+    iso_day: i32,
+    other_iso_year: i32,
+    other_iso_month: i32,
+    other_iso_day: i32,
+    largest_unit: &str,
+) -> Result<Vec<i32>, String> {
+    let a = to_date(iso_year, iso_month, iso_day)?;
+    let b = to_date(other_iso_year, other_iso_month, other_iso_day)?;
+
+    let (earlier, later, sign) = if a <= b { (a, b, 1) } else { (b, a, -1) };
+    let total_days = later.as_julian() - earlier.as_julian();
+
+    let [years, months, weeks, days] = match largest_unit {
+        "" | "day" | "days" => [0, 0, 0, total_days],
+        "week" | "weeks" => [0, 0, total_days / 7, total_days % 7],
+        "month" | "months" | "year" | "years" => {
+            let age = later.age_on(earlier);
+            let (years, months) = (age.years().cast_signed(), age.months().cast_signed());
+            let days = age.days().cast_signed();
+
+            if largest_unit.starts_with("month") {
+                [0, years * 13 + months, 0, days]
+            } else {
+                [years, months, 0, days]
+            }
+        }
+        unit => return Err(format!("unsupported largest unit: {unit}")),
+    };
+
+    Ok(vec![sign * years, sign * months, sign * weeks, sign * days])
+}
+
+/// The SAC13 year number (e.g. `12020` for M020).
+///
+/// # Errors
+///
+/// Fails for invalid dates and dates outside the SAC13 range (this applies to all methods).
+#[wasm_bindgen]
+pub fn year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<i32, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.year().value().into())
+}
+
+/// The month (1 - 13, March is 1).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen]
+pub fn month(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.month().ord())
+}
+
+/// The month code (`"M01"` - `"M13"`).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = monthCode)]
+pub fn month_code(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<String, String> {
+    Ok(format!("M{:02}", month(iso_year, iso_month, iso_day)?))
+}
+
+/// The day of the month (1 - 29).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen]
+pub fn day(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.day())
+}
+
+/// The day of the SAC13 week (1 - 7, or 8 on synchronization days).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = dayOfWeek)]
+pub fn day_of_week(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.weekday_ordinal())
+}
+
+/// The day of the year (1 - 366).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = dayOfYear)]
+pub fn day_of_year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u16, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.day_of_year())
+}
+
+/// The week of the year (1 - 52).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = weekOfYear)]
+pub fn week_of_year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.week_of_year())
+}
+
+/// Number of days in the week of the date: 8 if the week ends with a synchronization day, otherwise 7.
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = daysInWeek)]
+pub fn days_in_week(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    let date = to_date(iso_year, iso_month, iso_day)?;
+
+    Ok(if date.week_of_month() == 4 {
+        Date::month_len(date.year(), date.month()) - 21
+    } else {
+        7
+    })
+}
+
+/// Number of days in the month of the date (28 or 29).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = daysInMonth)]
+pub fn days_in_month(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    let date = to_date(iso_year, iso_month, iso_day)?;
+    Ok(Date::month_len(date.year(), date.month()))
+}
+
+/// Number of days in the year of the date (365 or 366).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = daysInYear)]
+pub fn days_in_year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u16, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.year().days())
+}
+
+/// Number of months in the year of the date (always 13).
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = monthsInYear)]
+pub fn months_in_year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<u8, String> {
+    to_date(iso_year, iso_month, iso_day).map(|_| 13)
+}
+
+/// Returns `true` if the year of the date is a leap year.
+///
+/// # Errors
+///
+/// See [`year()`].
+#[wasm_bindgen(js_name = inLeapYear)]
+pub fn in_leap_year(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<bool, String> {
+    Ok(to_date(iso_year, iso_month, iso_day)?.year().is_leap())
+}
+
+/// The fields the calendar needs, given the fields of a Temporal operation.
+///
+/// SAC13 has no eras, so the list is returned unchanged.
+#[must_use]
+#[allow(clippy::missing_const_for_fn)] // not supported by `wasm_bindgen`
+#[wasm_bindgen]
+pub fn fields(fields: Vec<String>) -> Vec<String> {
+    fields
+}
+
+fn to_date(iso_year: i32, iso_month: i32, iso_day: i32) -> Result<Date, String> {
+    i16::try_from(iso_year)
+        .ok()
+        .zip(u8::try_from(iso_month).ok())
+        .zip(u8::try_from(iso_day).ok())
+        .and_then(|((year, month), day)| GregorianDate::from_ymd(year, month, day))
+        .map(GregorianDate::convert)
+        .ok_or_else(|| {
+            format!("{iso_year}-{iso_month}-{iso_day} is not a valid date in the SAC13 range")
+        })
+}
+
+fn iso_fields(date: Date) -> Vec<i32> {
+    let date: GregorianDate = date.convert();
+    vec![date.year().into(), date.month().into(), date.day().into()]
+}
+
+fn policy(overflow: &str) -> Result<LeapPolicy, String> {
+    match overflow {
+        "" | "constrain" => Ok(LeapPolicy::ClampToPrevious),
+        "reject" => Ok(LeapPolicy::Reject),
+        other => Err(format!("invalid overflow option: {other}")),
+    }
+}
+
+fn month_len(year: Year, month: i32) -> u8 {
+    u8::try_from(month)
+        .ok()
+        .and_then(Month::new)
+        .map_or(28, |month| Date::month_len(year, month))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_fields() {
+        // 2021-03-20 is M021-01-01, M021 is a leap year
+        assert_eq!(year(2021, 3, 20), Ok(12021));
+        assert_eq!(month_code(2021, 3, 20).as_deref(), Ok("M01"));
+        assert_eq!(in_leap_year(2021, 3, 20), Ok(true));
+        assert_eq!(days_in_year(2021, 3, 20), Ok(366));
+        assert_eq!(days_in_month(2021, 3, 20), Ok(28));
+        assert_eq!(days_in_week(2021, 3, 20), Ok(7));
+        assert_eq!(months_in_year(2021, 3, 20), Ok(13));
+
+        let leap_day = iso_fields(date!(M021 - 06 - 29));
+        let [y, m, d] = leap_day[..] else { panic!() };
+
+        assert_eq!(day_of_week(y, m, d), Ok(8));
+        assert_eq!(days_in_week(y, m, d), Ok(8));
+        assert_eq!(day_of_year(y, m, d), Ok(169));
+        assert_eq!(week_of_year(y, m, d), Ok(24));
+
+        assert!(year(2021, 2, 29).is_err());
+        assert!(year(-20000, 1, 1).is_err());
+    }
+
+    #[test]
+    fn date_from_fields_overflow() {
+        assert_eq!(
+            date_from_fields(12022, 6, 29, "constrain"),
+            Ok(iso_fields(date!(M022 - 06 - 28)))
+        );
+        assert_eq!(
+            date_from_fields(12022, 14, 40, "constrain"),
+            Ok(iso_fields(date!(M022 - 13 - 29)))
+        );
+        assert!(date_from_fields(12022, 6, 29, "reject").is_err());
+        assert!(date_from_fields(12022, 6, 1, "wrap").is_err());
+        assert!(date_from_fields(26000, 1, 1, "constrain").is_err());
+    }
+
+    #[test]
+    fn date_add_and_until() {
+        let [y, m, d] = iso_fields(date!(M021 - 06 - 29))[..] else {
+            panic!()
+        };
+
+        assert_eq!(
+            date_add(y, m, d, 1, 0, 0, 0, "constrain"),
+            Ok(iso_fields(date!(M022 - 06 - 28)))
+        );
+        assert!(date_add(y, m, d, 1, 0, 0, 0, "reject").is_err());
+        assert!(date_add(y, m, d, 1, -1, 0, 0, "reject").is_err());
+        assert_eq!(
+            date_add(y, m, d, 0, 0, -1, -1, "reject"),
+            Ok(iso_fields(date!(M021 - 06 - 21)))
+        );
+
+        let [oy, om, od] = iso_fields(date!(M022 - 08 - 01))[..] else {
+            panic!()
+        };
+
+        assert_eq!(
+            date_until(y, m, d, oy, om, od, "year"),
+            Ok(vec![1, 1, 0, 1])
+        );
+        assert_eq!(
+            date_until(y, m, d, oy, om, od, "month"),
+            Ok(vec![0, 14, 0, 1])
+        );
+        assert_eq!(
+            date_until(oy, om, od, y, m, d, "week"),
+            Ok(vec![0, 0, -56, -2])
+        );
+        assert!(date_until(y, m, d, oy, om, od, "hour").is_err());
+    }
+}
@@ -3,7 +3,7 @@ use std::prelude::rust_2024::*;
 use crate::prelude::*;
 
 use crate::{
-    scalars::{JulianDay, UnixDay},
+    scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay},
     traits::CalendarDate,
 };
 
@@ -12,53 +12,123 @@ fn automatic_max_calculation_for_scalars_works_as_expected() {
     assert_eq!(UnixDay::MAX.value(), 5124428);
 }
 
+#[test]
+fn scalar_from_julian_does_not_overflow() {
+    for julian in [i32::MIN, i32::MAX] {
+        assert_eq!(JulianDay::from_julian(julian), None);
+        assert_eq!(UnixDay::from_julian(julian), None);
+        assert_eq!(CycleEpochDay::from_julian(julian), None);
+        assert_eq!(Sac13Day::from_julian(julian), None);
+        assert_eq!(ExcelDay::from_julian(julian), None);
+    }
+}
+
+#[test]
+fn cycle_epoch_day_offsets() {
+    for (julian, unix, epoch) in [
+        (JulianDay::MIN, UnixDay::MIN, CycleEpochDay::MIN),
+        (JulianDay::MAX, UnixDay::MAX, CycleEpochDay::MAX),
+    ] {
+        let epoch = i32::try_from(epoch.value()).unwrap();
+
+        assert_eq!(julian.value() + CycleEpochDay::JULIAN_DAY_OFFSET, epoch);
+        assert_eq!(unix.value() + CycleEpochDay::UNIX_DAY_OFFSET, epoch);
+    }
+}
+
 #[test]
 fn exhaustive_day_conversion_check() {
+    let chunk_size = core::num::NonZeroU32::new(1 << 20).unwrap();
+    let mut last_progress = None;
+
+    crate::exhaustive::run(chunk_size, |_| Ok(()), |p| last_progress = Some(p)).unwrap();
+
+    assert_eq!(
+        last_progress.map(|p| (p.checked, p.total)),
+        Some((crate::exhaustive::TOTAL_DAYS, crate::exhaustive::TOTAL_DAYS))
+    );
+}
+
+#[test]
+fn exhaustive_display_parse_round_trip() {
+    use core::fmt::Write;
+
+    let mut buffer = String::new();
+
     for j in JulianDay::MIN_INT..=JulianDay::MAX_INT {
-        let has_yesterday = j != JulianDay::MIN_INT;
-        let has_tomorrow = j != JulianDay::MAX_INT;
-
-        let jd = JulianDay::new(j).unwrap();
-        let sac13 = Date::from_julian(j).unwrap();
-        let greg = GregorianDate::from_julian(j).unwrap();
-
-        if has_yesterday {
-            let j_y = j - 1;
-
-            let jd_y = JulianDay::new(j_y).unwrap();
-            let sac13_y = Date::from_julian(j_y).unwrap();
-            let greg_y = GregorianDate::from_julian(j_y).unwrap();
-
-            assert_eq!(jd_y.tomorrow().unwrap(), jd, "JD: {}", j);
-            assert_eq!(sac13_y.tomorrow().unwrap(), sac13, "JD: {}", j);
-            assert_eq!(greg_y.tomorrow().unwrap(), greg, "JD: {}", j);
-        } else {
-            assert_eq!(jd, JulianDay::MIN, "JD: {}", j);
-            assert_eq!(sac13, Date::MIN, "JD: {}", j);
-            assert_eq!(greg, GregorianDate::MIN, "JD: {}", j);
-        }
+        let date = Date::from_julian(j).unwrap();
 
-        if has_tomorrow {
-            let j_t = j + 1;
+        buffer.clear();
+        write!(buffer, "{date}").unwrap();
 
-            let jd_t = JulianDay::new(j_t).unwrap();
-            let sac13_t = Date::from_julian(j_t).unwrap();
-            let greg_t = GregorianDate::from_julian(j_t).unwrap();
+        assert_eq!(buffer.parse(), Ok(date), "JD: {j}");
 
-            assert_eq!(jd_t.yesterday().unwrap(), jd, "JD: {}", j);
-            assert_eq!(sac13_t.yesterday().unwrap(), sac13, "JD: {}", j);
-            assert_eq!(greg_t.yesterday().unwrap(), greg, "JD: {}", j);
-        } else {
-            assert_eq!(jd, JulianDay::MAX, "JD: {}", j);
-            assert_eq!(sac13, Date::MAX, "JD: {}", j);
-            assert_eq!(greg, GregorianDate::MAX, "JD: {}", j);
-        }
+        let ordinal = YearOrdinal::from_date(date);
+
+        buffer.clear();
+        write!(buffer, "{ordinal}").unwrap();
+
+        assert_eq!(buffer.parse(), Ok(ordinal), "JD: {j}");
+
+        let gregorian = GregorianDate::from(date);
+
+        buffer.clear();
+        write!(buffer, "{}", gregorian.to_iso8601()).unwrap();
+
+        assert_eq!(
+            GregorianDate::from_iso8601(&buffer),
+            Ok(gregorian),
+            "JD: {j}"
+        );
     }
 }
 
+#[test]
+fn year_ascii_round_trip() {
+    for value in Year::MIN.value()..=Year::MAX.value() {
+        let year = Year::new(value).unwrap();
+        let ascii = year.to_ascii();
+
+        assert_eq!(ascii, format!("{year}").as_bytes());
+        assert_eq!(Year::from_ascii(ascii), Some(year));
+    }
+
+    assert_eq!(Year::from_ascii(*b"m020"), None);
+    assert_eq!(Year::from_ascii(*b"2020"), None);
+}
+
 #[test]
 pub fn const_year_num_is_same_as_during_construction() {
     assert_eq!(year!(B000).value(), 1000);
+    assert_eq!(year!(b000), year!(B000));
+    assert_eq!(year!(z999), Year::MAX);
+    assert_eq!(year!(12020), year!(M020));
+    assert_eq!(year!(00000), Year::MIN);
+    assert_eq!(year!(25999), Year::MAX);
+    assert_eq!(date!(m020 - 05 - 21), date!(M020 - 05 - 21));
+}
+
+#[test]
+pub fn const_date_with_month_name_or_ordinal() {
+    const HOLIDAYS: [Date; 3] = [
+        date!(M020 - March - 01),
+        date!(M021 - August - 29),
+        date!(M020 - Addenduary - 29),
+    ];
+
+    assert_eq!(
+        HOLIDAYS,
+        [
+            date!(M020 - 01 - 01),
+            date!(M021 - 06 - 29),
+            date!(M020 - 13 - 29),
+        ]
+    );
+
+    assert_eq!(date!(M020 ~ 1), date!(M020 - 01 - 01));
+    assert_eq!(date!(M020 ~ 200), date!(M020 - 08 - 04));
+    assert_eq!(date!(M021 ~ 366), date!(M021 - 13 - 29));
+    assert_eq!(date!(m021 ~ 169), date!(M021 - 06 - 29));
 }
 
 #[test]
@@ -96,6 +166,62 @@ fn snapshot_all_leap_years() {
     insta::assert_yaml_snapshot!(leap_years);
 }
 
+#[test]
+fn iterators_walk_all_calendar_types_alike() {
+    fn last_days<T: CalendarDate + Copy>(n: usize) -> Vec<i32> {
+        T::MAX
+            .iter_backward()
+            .take(n)
+            .map(|d| d.as_julian())
+            .collect()
+    }
+
+    fn first_days<T: CalendarDate + Copy>(n: usize) -> Vec<i32> {
+        T::MIN
+            .iter_forward()
+            .take(n)
+            .map(|d| d.as_julian())
+            .collect()
+    }
+
+    let last = last_days::<Date>(3);
+    let first = first_days::<Date>(3);
+
+    assert_eq!(last_days::<GregorianDate>(3), last);
+    assert_eq!(last_days::<UnixDay>(3), last);
+    assert_eq!(last_days::<CycleEpochDay>(3), last);
+    assert_eq!(first_days::<GregorianDate>(3), first);
+    assert_eq!(first_days::<JulianDay>(3), first);
+
+    assert_eq!(Date::MIN.iter_backward().count(), 1);
+    assert_eq!(Date::MAX.iter_backward().nth(1), Date::MAX.yesterday());
+}
+
+#[test]
+fn date_iterators_are_double_ended_and_exact() {
+    let mut days = Date::MIN.iter_forward();
+    assert_eq!(days.len(), 9_496_301);
+
+    assert_eq!(days.nth_back(1), Date::MAX.yesterday());
+    assert_eq!(days.nth(usize::MAX), None);
+    assert_eq!(days.len(), 0);
+    assert_eq!(days.next_back(), None);
+
+    let mut backward = date!(A000 - 01 - 03).iter_backward();
+    assert_eq!(backward.len(), 3);
+    assert_eq!(backward.next_back(), Some(Date::MIN));
+    assert_eq!(backward.next(), Some(date!(A000 - 01 - 03)));
+    assert_eq!(backward.last(), Some(date!(A000 - 01 - 02)));
+
+    let range = crate::DateRange::new(date!(M020 - 13 - 27), date!(M021 - 01 - 02)).unwrap();
+    let reversed: Vec<_> = range.iter().rev().collect();
+    let mut forward: Vec<_> = range.into_iter().collect();
+
+    forward.reverse();
+    assert_eq!(reversed, forward);
+    assert_eq!(range.iter().len(), range.duration_days() as usize);
+}
+
 // #[test]
 // fn demo() {
 //     let j = -1931284;
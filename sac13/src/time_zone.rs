@@ -0,0 +1,89 @@
+//! Civil dates and times of instants, with the time zone rules of the IANA database.
+
+use tz::TimeZoneRef;
+
+use crate::{
+    DateTime, UtcOffset,
+    compat::{UNIX_EPOCH_JULIAN, from_units},
+};
+
+impl UtcOffset {
+    /// Offset of the time zone at the given Unix timestamp (in seconds), including
+    /// daylight saving time.
+    ///
+    /// Returns `None` if the zone has no rule for the timestamp or the offset isn't
+    /// less than 24 hours in either direction.
+    ///
+    /// Requires the `tzdb` feature.
+    ///
+    /// ```
+    /// use sac13::{UtcOffset, tzdb::time_zone::europe::BERLIN};
+    ///
+    /// // 2024-01-15 and 2024-07-15 at noon UTC
+    /// assert_eq!(UtcOffset::at_timestamp_in(1_705_320_000, BERLIN), UtcOffset::from_hm(1, 0));
+    /// assert_eq!(UtcOffset::at_timestamp_in(1_721_044_800, BERLIN), UtcOffset::from_hm(2, 0));
+    /// ```
+    #[must_use]
+    pub fn at_timestamp_in(timestamp: i64, zone: TimeZoneRef<'_>) -> Option<Self> {
+        let local_time_type = zone.find_local_time_type(timestamp).ok()?;
+        Self::from_seconds(local_time_type.ut_offset())
+    }
+}
+
+impl DateTime {
+    /// Civil date and time of a Unix timestamp (in seconds) in the given time zone.
+    ///
+    /// Unlike a fixed [`UtcOffset`], the time zone rules account for daylight saving time
+    /// and historical changes of the offset.
+    ///
+    /// Returns `None` if the offset is unknown (see [`UtcOffset::at_timestamp_in()`])
+    /// or the local date is outside the SAC13 range.
+    ///
+    /// Requires the `tzdb` feature.
+    ///
+    /// ```
+    /// use sac13::{DateTime, date, tzdb::tz_by_name};
+    ///
+    /// let new_york = tz_by_name("America/New_York").unwrap();
+    ///
+    /// // 2024-03-10 06:59:59 UTC and one second later, when DST starts
+    /// let before = DateTime::from_timestamp_in(1_710_053_999, new_york).unwrap();
+    /// let after = DateTime::from_timestamp_in(1_710_054_000, new_york).unwrap();
+    ///
+    /// assert_eq!(before.date(), date!(M023 - 13 - 20));
+    /// assert_eq!((before.hour(), before.minute(), before.second()), (1, 59, 59));
+    /// assert_eq!((after.hour(), after.minute(), after.second()), (3, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn from_timestamp_in(timestamp: i64, zone: TimeZoneRef<'_>) -> Option<Self> {
+        let offset = UtcOffset::at_timestamp_in(timestamp, zone)?;
+        let local = timestamp.checked_add(offset.seconds().into())?;
+
+        from_units(local, 1, UNIX_EPOCH_JULIAN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tzdb::time_zone::{asia::KOLKATA, australia::LORD_HOWE};
+
+    #[test]
+    fn half_hour_zones() {
+        // 2024-01-01 00:00:00 UTC
+        let timestamp = 1_704_067_200;
+
+        let kolkata = DateTime::from_timestamp_in(timestamp, KOLKATA).unwrap();
+        assert_eq!((kolkata.hour(), kolkata.minute()), (5, 30));
+
+        // summer time with a 30 minute shift
+        let lord_howe = DateTime::from_timestamp_in(timestamp, LORD_HOWE).unwrap();
+        assert_eq!((lord_howe.hour(), lord_howe.minute()), (11, 0));
+    }
+
+    #[test]
+    fn outside_the_sac13_range() {
+        assert_eq!(DateTime::from_timestamp_in(i64::MIN, KOLKATA), None);
+        assert_eq!(DateTime::from_timestamp_in(i64::MAX, KOLKATA), None);
+    }
+}
@@ -1,4 +1,12 @@
-use core::fmt::Display;
+use core::{
+    cmp::Ordering, fmt::Display, iter::FusedIterator, marker::PhantomData, ops::RangeInclusive,
+};
+
+use crate::{
+    Date, GregorianDate,
+    date_sac13::raw_date::YearOrdinal,
+    scalars::{CycleEpochDay, ExcelDay, JulianDay, Sac13Day, UnixDay},
+};
 
 /// A minimum set of functionality a typical calendar should provide.
 pub trait CalendarDate: Sized + Display {
@@ -26,6 +34,139 @@ pub trait CalendarDate: Sized + Display {
         Self::from_julian(self.as_julian() - 1)
     }
 
+    /// Chronological ordering of two dates, even if they are from different calendars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use sac13::prelude::*;
+    ///
+    /// assert_eq!(date!(M000 - 01 - 01).cmp_by_day(&date_greg!(2000 - 03 - 20)), Ordering::Equal);
+    /// assert_eq!(date!(M000 - 01 - 01).cmp_by_day(&date_greg!(2000 - 03 - 21)), Ordering::Less);
+    ///
+    /// // Dates of different calendars (e.g. results of `parse_date_str`) can be sorted directly:
+    /// let mut dates = ["2000-03-25", "M000-01-03"].map(|s| sac13::parse_date_str(s).unwrap().date);
+    /// dates.sort_by(|a, b| a.cmp_by_day(b));
+    ///
+    /// assert_eq!(dates[0].to_string(), "M000-01-03");
+    /// ```
+    #[must_use]
+    fn cmp_by_day(&self, other: &impl CalendarDate) -> Ordering {
+        self.as_julian().cmp(&other.as_julian())
+    }
+
+    /// The earlier of the two dates. Returns `self` if both are the same day.
+    #[must_use]
+    fn min_date(self, other: Self) -> Self {
+        if other.as_julian() < self.as_julian() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The later of the two dates. Returns `self` if both are the same day.
+    #[must_use]
+    fn max_date(self, other: Self) -> Self {
+        if other.as_julian() > self.as_julian() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if the date is within the (inclusive) range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    ///
+    /// let spring = date_greg!(2024 - 03 - 20)..=date_greg!(2024 - 06 - 20);
+    ///
+    /// assert!(date_greg!(2024 - 05 - 01).is_within(&spring));
+    /// assert!(!date_greg!(2024 - 07 - 01).is_within(&spring));
+    /// ```
+    #[must_use]
+    fn is_within(&self, range: &RangeInclusive<Self>) -> bool {
+        let day = self.as_julian();
+        range.start().as_julian() <= day && day <= range.end().as_julian()
+    }
+
+    /// Restricts the date to the (inclusive) range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty (the start is after the end).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    ///
+    /// let quarter = date!(M024 - 01 - 01)..=date!(M024 - 04 - 07);
+    ///
+    /// assert_eq!(date!(M023 - 13 - 29).clamp_to(quarter.clone()), date!(M024 - 01 - 01));
+    /// assert_eq!(date!(M024 - 02 - 14).clamp_to(quarter), date!(M024 - 02 - 14));
+    /// ```
+    #[must_use]
+    fn clamp_to(self, range: RangeInclusive<Self>) -> Self {
+        let (start, end) = range.into_inner();
+
+        assert!(
+            start.as_julian() <= end.as_julian(),
+            "range start must not be after its end"
+        );
+
+        self.max_date(start).min_date(end)
+    }
+
+    /// Successive days, starting with this date and ending with [`MAX`](Self::MAX).
+    ///
+    /// Works the same for every calendar type, so generic code can walk any of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    ///
+    /// let mut days = date!(M020 - 13 - 28).iter_forward();
+    ///
+    /// assert_eq!(days.next(), Some(date!(M020 - 13 - 28)));
+    /// assert_eq!(days.next(), Some(date!(M020 - 13 - 29)));
+    /// assert_eq!(days.next(), Some(date!(M021 - 01 - 01)));
+    ///
+    /// assert_eq!(Date::MAX.iter_forward().count(), 1);
+    ///
+    /// // the last days, newest first
+    /// let mut last = Date::MIN.iter_forward().rev();
+    /// assert_eq!(last.next(), Some(Date::MAX));
+    /// ```
+    #[must_use]
+    fn iter_forward(self) -> DateIter<Self> {
+        DateIter::new(self.as_julian(), Self::MAX.as_julian(), true)
+    }
+
+    /// Preceding days, starting with this date and ending with [`MIN`](Self::MIN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    ///
+    /// let days: Vec<_> = date_greg!(2024 - 03 - 01).iter_backward().take(3).collect();
+    ///
+    /// assert_eq!(
+    ///     days,
+    ///     [date_greg!(2024 - 03 - 01), date_greg!(2024 - 02 - 29), date_greg!(2024 - 02 - 28)]
+    /// );
+    /// ```
+    #[must_use]
+    fn iter_backward(self) -> DateIter<Self> {
+        DateIter::new(Self::MIN.as_julian(), self.as_julian(), false)
+    }
+
     /// Converts the calendar date to a different calendar system.
     ///
     /// # Examples
@@ -40,8 +181,190 @@ pub trait CalendarDate: Sized + Display {
     ///
     /// It's basically like the [`From`] trait, but because of the orphan rule I failed
     /// to implement it generically for all types that implement [`CalendarDate`].
+    /// All calendar types of this crate implement [`From`] (and therefore also [`TryFrom`])
+    /// for each other, so `let g: GregorianDate = date.into();` works as well.
     #[must_use]
     fn convert<T: CalendarDate>(self) -> T {
         T::from_julian(self.as_julian()).expect("SAC13 range calendars to be convertible.")
     }
 }
+
+/// Iterator over successive days of a calendar, see [`CalendarDate::iter_forward()`],
+/// [`CalendarDate::iter_backward()`] and [`DateRange::iter()`](crate::DateRange::iter()).
+///
+/// It knows its remaining length and can be walked from both ends, so `len()`, `rev()`
+/// and `nth()` (e.g. for pagination) are cheap.
+///
+/// ```
+/// use sac13::prelude::*;
+///
+/// let mut days = date!(M020 - 01 - 01).iter_forward().take(28);
+///
+/// assert_eq!(days.len(), 28);
+/// assert_eq!(days.nth(7), Some(date!(M020 - 01 - 08)));
+/// assert_eq!(days.next_back(), Some(date!(M020 - 01 - 28)));
+/// assert_eq!(days.len(), 19);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateIter<T> {
+    // Julian Day Numbers of the remaining days (both inclusive), empty if `front > back`
+    front: i32,
+    back: i32,
+    forward: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T: CalendarDate> DateIter<T> {
+    /// The days from `front` to `back` (Julian Day Numbers, both inclusive) in the given direction.
+    pub(crate) const fn new(front: i32, back: i32, forward: bool) -> Self {
+        Self {
+            front,
+            back,
+            forward,
+            marker: PhantomData,
+        }
+    }
+
+    fn take_front(&mut self) -> Option<T> {
+        (self.front <= self.back).then(|| {
+            self.front += 1;
+            T::from_julian(self.front - 1).expect("remaining days to be in range")
+        })
+    }
+
+    fn take_back(&mut self) -> Option<T> {
+        (self.front <= self.back).then(|| {
+            self.back -= 1;
+            T::from_julian(self.back + 1).expect("remaining days to be in range")
+        })
+    }
+
+    /// Skips `n` days at the front (`forward`) or back, or all if there are fewer.
+    fn skip_days(&mut self, n: usize, forward: bool) {
+        let n = i32::try_from(n.min(self.len())).unwrap_or(i32::MAX);
+
+        if forward {
+            self.front += n;
+        } else {
+            self.back -= n;
+        }
+    }
+}
+
+impl<T: CalendarDate> Iterator for DateIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.forward {
+            self.take_front()
+        } else {
+            self.take_back()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::try_from(i64::from(self.back) - i64::from(self.front) + 1).unwrap_or(0);
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.skip_days(n, self.forward);
+        self.next()
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn last(mut self) -> Option<T> {
+        self.next_back()
+    }
+}
+
+impl<T: CalendarDate> DoubleEndedIterator for DateIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.forward {
+            self.take_back()
+        } else {
+            self.take_front()
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.skip_days(n, !self.forward);
+        self.next_back()
+    }
+}
+
+impl<T: CalendarDate> ExactSizeIterator for DateIter<T> {}
+
+impl<T: CalendarDate> FusedIterator for DateIter<T> {}
+
+/// Uniform access to the components of a calendar date.
+///
+/// Implemented by [`Date`](crate::Date) and [`GregorianDate`](crate::GregorianDate),
+/// so code like report generators or validators can be written once for both calendars.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::prelude::*;
+///
+/// fn describe(date: &impl Datelike) -> (i32, u8, u8, u16) {
+///     (date.year_value(), date.month_ordinal(), date.day(), date.ordinal())
+/// }
+///
+/// assert_eq!(describe(&date!(M000 - 01 - 01)), (12000, 1, 1, 1));
+/// assert_eq!(describe(&date_greg!(2000 - 03 - 20)), (2000, 3, 20, 80));
+/// ```
+pub trait Datelike {
+    /// Numeric value of the year.
+    #[must_use]
+    fn year_value(&self) -> i32;
+
+    /// Ordinal of the month within the year, starting with 1.
+    ///
+    /// Note that for SAC13 the year starts with March, so March is 1.
+    #[must_use]
+    fn month_ordinal(&self) -> u8;
+
+    /// Day of the month, starting with 1.
+    #[must_use]
+    fn day(&self) -> u8;
+
+    /// Day of the year, starting with 1.
+    #[must_use]
+    fn ordinal(&self) -> u16;
+
+    /// Day of the week as a number in the week system of the calendar.
+    ///
+    /// SAC13 dates return 1-7 and 8 on synchronization days (see [`Date::weekday_ordinal()`](crate::Date::weekday_ordinal())).
+    /// Gregorian dates return the ISO 8601 weekday number (Monday = 1, ..., Sunday = 7).
+    #[must_use]
+    fn weekday_ordinal(&self) -> u8;
+}
+
+/// Implements [`From`] between calendar types.
+///
+/// All types share the exact same range (A000-01-01 to Z999-13-29),
+/// so the conversion can't fail.
+macro_rules! from_calendar_date {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> Self {
+                    value.convert()
+                }
+            }
+        )+
+    };
+}
+
+from_calendar_date!(Date => GregorianDate, YearOrdinal, JulianDay, UnixDay, CycleEpochDay, Sac13Day, ExcelDay);
+from_calendar_date!(GregorianDate => Date, YearOrdinal, JulianDay, UnixDay, CycleEpochDay, Sac13Day, ExcelDay);
+from_calendar_date!(YearOrdinal => Date, GregorianDate, JulianDay, UnixDay, CycleEpochDay, Sac13Day, ExcelDay);
+from_calendar_date!(JulianDay => Date, GregorianDate, YearOrdinal, UnixDay, CycleEpochDay, Sac13Day, ExcelDay);
+from_calendar_date!(UnixDay => Date, GregorianDate, YearOrdinal, JulianDay, CycleEpochDay, Sac13Day, ExcelDay);
+from_calendar_date!(CycleEpochDay => Date, GregorianDate, YearOrdinal, JulianDay, UnixDay, Sac13Day, ExcelDay);
+from_calendar_date!(Sac13Day => Date, GregorianDate, YearOrdinal, JulianDay, UnixDay, CycleEpochDay, ExcelDay);
+from_calendar_date!(ExcelDay => Date, GregorianDate, YearOrdinal, JulianDay, UnixDay, CycleEpochDay, Sac13Day);
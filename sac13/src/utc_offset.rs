@@ -0,0 +1,107 @@
+use core::fmt::Display;
+
+/// Offset of a local time from UTC, in seconds (positive east of Greenwich).
+///
+/// Only the fixed offset is stored, there are no time zone rules. The offset must be
+/// less than 24 hours in either direction.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::UtcOffset;
+///
+/// let cest = UtcOffset::from_hm(2, 0).unwrap();
+///
+/// assert_eq!(cest.seconds(), 7200);
+/// assert_eq!(format!("{cest}"), "+02:00");
+/// assert_eq!(format!("{}", UtcOffset::from_minutes(-210).unwrap()), "-03:30");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UtcOffset(i32);
+
+impl UtcOffset {
+    /// UTC itself (zero offset).
+    pub const UTC: Self = Self(0);
+
+    /// Offset from the number of seconds east of UTC.
+    ///
+    /// Returns `None` if the offset isn't less than 24 hours in either direction.
+    #[must_use]
+    pub const fn from_seconds(seconds: i32) -> Option<Self> {
+        if seconds.unsigned_abs() < 86_400 {
+            Some(Self(seconds))
+        } else {
+            None
+        }
+    }
+
+    /// Offset from the number of minutes east of UTC.
+    ///
+    /// Returns `None` if the offset isn't less than 24 hours in either direction.
+    #[must_use]
+    pub const fn from_minutes(minutes: i16) -> Option<Self> {
+        Self::from_seconds(minutes as i32 * 60)
+    }
+
+    /// Offset from hours and minutes. Both must have the same sign (e.g. `-3, -30` for `-03:30`).
+    ///
+    /// Returns `None` if the minutes are outside ±59, the signs differ,
+    /// or the offset isn't less than 24 hours in either direction.
+    #[must_use]
+    pub const fn from_hm(hours: i8, minutes: i8) -> Option<Self> {
+        if minutes <= -60
+            || minutes >= 60
+            || (hours < 0 && minutes > 0)
+            || (hours > 0 && minutes < 0)
+        {
+            return None;
+        }
+
+        Self::from_seconds(hours as i32 * 3600 + minutes as i32 * 60)
+    }
+
+    /// Seconds east of UTC.
+    #[must_use]
+    pub const fn seconds(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Display for UtcOffset {
+    /// Displays the offset like `+02:00`, or `+05:30:15` if it has seconds.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let abs = self.0.unsigned_abs();
+
+        write!(f, "{sign}{:02}:{:02}", abs / 3600, abs / 60 % 60)?;
+
+        if !abs.is_multiple_of(60) {
+            write!(f, ":{:02}", abs % 60)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_and_display() {
+        assert_eq!(
+            UtcOffset::from_seconds(86_399).map(|x| x.seconds()),
+            Some(86_399)
+        );
+        assert_eq!(UtcOffset::from_seconds(-86_400), None);
+        assert_eq!(UtcOffset::from_hm(-3, 30), None);
+        assert_eq!(UtcOffset::from_hm(0, -30), UtcOffset::from_minutes(-30));
+        assert_eq!(UtcOffset::from_hm(24, 0), None);
+
+        assert_eq!(format!("{}", UtcOffset::UTC), "+00:00");
+        assert_eq!(
+            format!("{}", UtcOffset::from_seconds(-19_815).unwrap()),
+            "-05:30:15"
+        );
+    }
+}
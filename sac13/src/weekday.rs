@@ -1,3 +1,4 @@
+#[cfg(feature = "names")]
 use core::fmt::Display;
 
 /// Represents the Gregorian weekday.
@@ -17,6 +18,7 @@ impl Weekday {
     /// The international (english) name for the day of the week.
     ///
     /// The [`Display`](core::fmt::Display) implementation also calls this method.
+    #[cfg(feature = "names")]
     #[must_use]
     pub const fn name(self) -> &'static str {
         use Weekday::*;
@@ -35,6 +37,7 @@ impl Weekday {
     /// The international (english) two letter abbreviation for the day of the week.
     ///
     /// Mo, Tu, We, Th, Fr, Sa, Su
+    #[cfg(feature = "names")]
     #[must_use]
     pub const fn name_abr2(self) -> &'static str {
         use Weekday::*;
@@ -53,6 +56,7 @@ impl Weekday {
     /// The international (english) three letter abbreviation for the day of the week.
     ///
     /// Mon, Tue, Wed, Thu, Fri, Sat, Sun
+    #[cfg(feature = "names")]
     #[must_use]
     pub const fn name_abr3(self) -> &'static str {
         use Weekday::*;
@@ -68,6 +72,78 @@ impl Weekday {
         }
     }
 
+    /// All weekdays, starting with Monday.
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Number of days since Monday (Monday = 0, ..., Sunday = 6).
+    ///
+    /// This is also the value of `u8::from(weekday)`.
+    #[must_use]
+    pub const fn days_from_monday(self) -> u8 {
+        self as u8
+    }
+
+    /// ISO 8601 weekday number (Monday = 1, ..., Sunday = 7).
+    #[must_use]
+    pub const fn iso_number(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Weekday from the number of days since Monday (Monday = 0, ..., Sunday = 6).
+    ///
+    /// Returns `None` for values larger than 6.
+    #[must_use]
+    pub const fn from_days_from_monday(value: u8) -> Option<Self> {
+        if value < 7 {
+            Some(Self::ALL[value as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Weekday from its ISO 8601 number (Monday = 1, ..., Sunday = 7).
+    ///
+    /// Returns `None` for zero and values larger than 7.
+    ///
+    /// ```
+    /// use sac13::Weekday;
+    ///
+    /// assert_eq!(Weekday::from_iso_number(7), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_days_from_monday(6), Some(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_iso_number(0), None);
+    /// ```
+    #[must_use]
+    pub const fn from_iso_number(value: u8) -> Option<Self> {
+        if value == 0 {
+            None
+        } else {
+            Self::from_days_from_monday(value - 1)
+        }
+    }
+
+    /// Weekday of the given Julian Day Number (day zero is a Monday).
+    pub(crate) const fn from_julian_day_number(jdn: i32) -> Self {
+        use Weekday::*;
+
+        match jdn.rem_euclid(7) {
+            0 => Monday,
+            1 => Tuesday,
+            2 => Wednesday,
+            3 => Thursday,
+            4 => Friday,
+            5 => Saturday,
+            _ => Sunday,
+        }
+    }
+
     /// Next weekday.
     #[must_use]
     pub const fn next(self) -> Self {
@@ -99,10 +175,179 @@ impl Weekday {
             Sunday => Saturday,
         }
     }
+
+    /// The weekday `n` days later.
+    ///
+    /// ```
+    /// use sac13::Weekday;
+    ///
+    /// assert_eq!(Weekday::Friday.nth_next(3), Weekday::Monday);
+    /// assert_eq!(Weekday::Friday.nth_next(7), Weekday::Friday);
+    /// ```
+    #[must_use]
+    pub const fn nth_next(self, n: u8) -> Self {
+        Self::ALL[(self as usize + n as usize) % 7]
+    }
+
+    /// The weekday `n` days earlier.
+    #[must_use]
+    pub const fn nth_previous(self, n: u8) -> Self {
+        Self::ALL[(self as usize + 7 - n as usize % 7) % 7]
+    }
+
+    /// Number of days (0 - 6) from this weekday until the next `other`.
+    ///
+    /// Returns zero if both are the same weekday.
+    ///
+    /// ```
+    /// use sac13::prelude::*;
+    /// use sac13::{Weekday, day_counts::JulianDay};
+    ///
+    /// // next Friday after (or on) a given day
+    /// let day = JulianDay::new(2_460_650).unwrap(); // a Thursday
+    /// let days = day.weekday().days_until(Weekday::Friday);
+    /// let friday = JulianDay::new(day.value() + i32::from(days)).unwrap();
+    ///
+    /// assert_eq!(friday.weekday(), Weekday::Friday);
+    /// ```
+    #[must_use]
+    pub const fn days_until(self, other: Self) -> u8 {
+        (other as u8 + 7 - self as u8) % 7
+    }
 }
 
+impl Default for Weekday {
+    /// [`Weekday::Monday`], the first day of the week according to ISO 8601.
+    fn default() -> Self {
+        Self::Monday
+    }
+}
+
+#[cfg(feature = "names")]
 impl Display for Weekday {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.name())
     }
 }
+
+impl From<Weekday> for u8 {
+    /// Same as [`Weekday::days_from_monday()`] (Monday = 0).
+    fn from(value: Weekday) -> Self {
+        value.days_from_monday()
+    }
+}
+
+impl TryFrom<u8> for Weekday {
+    type Error = ();
+
+    /// Same as [`Weekday::from_days_from_monday()`] (Monday = 0).
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_days_from_monday(value).ok_or(())
+    }
+}
+
+/// Error of parsing a [`Weekday`] from a string.
+#[cfg(feature = "names")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseWeekdayError;
+
+#[cfg(feature = "names")]
+impl Display for ParseWeekdayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "not a weekday name or abbreviation")
+    }
+}
+
+#[cfg(feature = "names")]
+impl core::error::Error for ParseWeekdayError {}
+
+#[cfg(feature = "names")]
+impl core::str::FromStr for Weekday {
+    type Err = ParseWeekdayError;
+
+    /// Parses the english [name](Weekday::name()) or its [three](Weekday::name_abr3())
+    /// and [two](Weekday::name_abr2()) letter abbreviation, ignoring ASCII case.
+    ///
+    /// ```
+    /// use sac13::Weekday;
+    ///
+    /// assert_eq!("Mon".parse(), Ok(Weekday::Monday));
+    /// assert_eq!("monday".parse(), Ok(Weekday::Monday));
+    /// assert_eq!("Mo".parse(), Ok(Weekday::Monday));
+    ///
+    /// let weekend: Result<Vec<Weekday>, _> = "Sat,Sun".split(',').map(str::parse).collect();
+    /// assert_eq!(weekend, Ok(vec![Weekday::Saturday, Weekday::Sunday]));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|weekday| {
+                [weekday.name(), weekday.name_abr3(), weekday.name_abr2()]
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(s))
+            })
+            .ok_or(ParseWeekdayError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_conventions() {
+        for (i, weekday) in Weekday::ALL.into_iter().enumerate() {
+            let i = u8::try_from(i).unwrap();
+
+            assert_eq!(u8::from(weekday), i);
+            assert_eq!(Weekday::try_from(i), Ok(weekday));
+            assert_eq!(
+                Weekday::from_iso_number(weekday.iso_number()),
+                Some(weekday)
+            );
+        }
+
+        assert_eq!(Weekday::try_from(7), Err(()));
+        assert_eq!(Weekday::from_iso_number(8), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        for weekday in Weekday::ALL {
+            for other in Weekday::ALL {
+                let days = weekday.days_until(other);
+
+                assert!(days < 7);
+                assert_eq!(weekday.nth_next(days), other);
+                assert_eq!(other.nth_previous(days), weekday);
+            }
+
+            assert_eq!(weekday.nth_next(1), weekday.next());
+            assert_eq!(weekday.nth_previous(1), weekday.previous());
+            // 255 = 36 * 7 + 3
+            assert_eq!(weekday.nth_next(u8::MAX), weekday.nth_next(3));
+            assert_eq!(weekday.nth_previous(u8::MAX), weekday.nth_next(4));
+        }
+    }
+
+    #[cfg(feature = "names")]
+    #[test]
+    fn from_str() {
+        for weekday in Weekday::ALL {
+            assert_eq!(weekday.name().parse(), Ok(weekday));
+            assert_eq!(
+                weekday.name_abr3().to_ascii_uppercase().parse(),
+                Ok(weekday)
+            );
+            assert_eq!(weekday.name_abr2().parse(), Ok(weekday));
+        }
+
+        for invalid in ["", "M", "Mond", "Monday ", "Sonntag"] {
+            assert_eq!(
+                invalid.parse::<Weekday>(),
+                Err(ParseWeekdayError),
+                "{invalid}"
+            );
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Date, Weekday, traits::CalendarDate};
+
+/// Definition of the rest days (the "weekend") of an organization, e.g. for business-day arithmetic.
+///
+/// Rest days can be defined by SAC13 [weekday ordinals](Date::weekday_ordinal()),
+/// by Gregorian [weekdays](Weekday), or both. A date is a rest day if it matches any of them.
+/// The weekday ordinal 8 stands for synchronization days, so they can be made rest days
+/// on their own, independent of the Gregorian weekday they fall on.
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{WeekendDef, date};
+///
+/// // SAC13 weekdays 6 and 7, and synchronization days off
+/// let weekend = WeekendDef::new()
+///     .with_weekday_ordinal(6)
+///     .with_weekday_ordinal(7)
+///     .with_weekday_ordinal(8);
+///
+/// assert!(weekend.is_rest_day(date!(M020 - 13 - 29)));
+/// assert!(weekend.is_business_day(date!(M020 - 13 - 22)));
+///
+/// // the 5th business day after M020-13-26 (skipping the weekend and the year day)
+/// assert_eq!(
+///     weekend.add_business_days(date!(M020 - 13 - 26), 5),
+///     Some(date!(M021 - 01 - 05))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WeekendDef {
+    /// Bit `n - 1` is set if the weekday ordinal `n` (1 - 8) is a rest day.
+    weekday_ordinals: u8,
+
+    /// Bit `n` is set if the Gregorian weekday with [`days_from_monday()`](Weekday::days_from_monday()) `n` is a rest day.
+    gregorian_weekdays: u8,
+}
+
+impl WeekendDef {
+    /// Saturday and Sunday (Gregorian), the most common weekend.
+    pub const SATURDAY_SUNDAY: Self = Self::new()
+        .with_gregorian_weekday(Weekday::Saturday)
+        .with_gregorian_weekday(Weekday::Sunday);
+
+    /// No rest days at all.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            weekday_ordinals: 0,
+            gregorian_weekdays: 0,
+        }
+    }
+
+    /// Adds a SAC13 weekday ordinal (1 - 7, or 8 for synchronization days) as rest day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ordinal is not between 1 and 8.
+    #[must_use]
+    pub const fn with_weekday_ordinal(mut self, ordinal: u8) -> Self {
+        assert!(
+            ordinal >= 1 && ordinal <= 8,
+            "weekday ordinal must be between 1 and 8"
+        );
+
+        self.weekday_ordinals |= 1 << (ordinal - 1);
+        self
+    }
+
+    /// Adds a Gregorian weekday as rest day.
+    #[must_use]
+    pub const fn with_gregorian_weekday(mut self, weekday: Weekday) -> Self {
+        self.gregorian_weekdays |= 1 << weekday.days_from_monday();
+        self
+    }
+
+    /// Returns `true` if the date is a rest day.
+    #[must_use]
+    pub const fn is_rest_day(&self, date: Date) -> bool {
+        self.weekday_ordinals & (1 << (date.weekday_ordinal() - 1)) != 0
+            || self.gregorian_weekdays & (1 << date.weekday().days_from_monday()) != 0
+    }
+
+    /// Returns `true` if the date is not a rest day.
+    #[must_use]
+    pub const fn is_business_day(&self, date: Date) -> bool {
+        !self.is_rest_day(date)
+    }
+
+    /// Moves the date by the given number of business days (backwards if negative).
+    ///
+    /// The start date doesn't have to be a business day, but the result always is
+    /// (unless `days` is zero, then the date is returned as is).
+    ///
+    /// Returns `None` if the result is outside the SAC13 range.
+    #[must_use]
+    pub fn add_business_days(&self, date: Date, days: i32) -> Option<Date> {
+        let step = if days < 0 {
+            Date::yesterday
+        } else {
+            Date::tomorrow
+        };
+
+        let mut date = date;
+
+        for _ in 0..days.unsigned_abs() {
+            date = step(date)?;
+
+            // also terminates if every day is a rest day, at the end of the range
+            while self.is_rest_day(date) {
+                date = step(date)?;
+            }
+        }
+
+        Some(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gregorian_weekend() {
+        let weekend = WeekendDef::SATURDAY_SUNDAY;
+
+        // M021-01-01 is a Saturday
+        let saturday = date!(M021 - 01 - 01);
+        assert_eq!(saturday.weekday(), Weekday::Saturday);
+
+        assert!(weekend.is_rest_day(saturday));
+        assert!(weekend.is_rest_day(saturday.tomorrow().unwrap()));
+        assert!(weekend.is_business_day(saturday.yesterday().unwrap()));
+
+        assert_eq!(
+            weekend.add_business_days(saturday, 1),
+            Some(date!(M021 - 01 - 03))
+        );
+        assert_eq!(
+            weekend.add_business_days(saturday, -1),
+            Some(date!(M020 - 13 - 29))
+        );
+        assert_eq!(
+            weekend.add_business_days(saturday, 5),
+            Some(date!(M021 - 01 - 07))
+        );
+        assert_eq!(weekend.add_business_days(saturday, 0), Some(saturday));
+    }
+
+    #[test]
+    fn no_business_days() {
+        let mut weekend = WeekendDef::new();
+
+        for ordinal in 1..=8 {
+            weekend = weekend.with_weekday_ordinal(ordinal);
+        }
+
+        assert!(weekend.is_rest_day(Date::MIN));
+        assert_eq!(weekend.add_business_days(Date::MIN, 1), None);
+        assert_eq!(weekend.add_business_days(Date::MAX, -1), None);
+    }
+
+    #[test]
+    #[should_panic = "weekday ordinal must be between 1 and 8"]
+    fn invalid_ordinal_panics() {
+        let _ = WeekendDef::new().with_weekday_ordinal(9);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    Date, Year,
+    date_sac13::raw_date::{YearOrdinal, date_to_yo, yo_to_date},
+    day_counts::CycleEpochDay,
+};
+
+/// Generates the first days of `N` consecutive years, starting with `first`.
+///
+/// The table is meant to be computed at compile time and embedded, e.g. into firmware,
+/// where [`YearStartTable`] then converts dates of these years with a subtraction and
+/// a division instead of the cycle arithmetic of the general conversion.
+///
+/// Returns `None` if the last year would be after [`Year::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// use sac13::{YearStartTable, date, day_counts::CycleEpochDay, year, year_start_table};
+///
+/// const STARTS: [CycleEpochDay; 50] = year_start_table(year!(M000)).unwrap();
+/// const TABLE: YearStartTable = YearStartTable::new(year!(M000), &STARTS).unwrap();
+///
+/// let date = date!(M024 - 03 - 07);
+/// let day = TABLE.to_cycle_epoch_day(date).unwrap();
+///
+/// assert_eq!(day, date.to_cycle_epoch_day());
+/// assert_eq!(TABLE.to_date(day), Some(date));
+/// assert_eq!(TABLE.to_date(date!(M050 - 01 - 01).to_cycle_epoch_day()), None);
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation)] // N is at most the number of years
+pub const fn year_start_table<const N: usize>(first: Year) -> Option<[CycleEpochDay; N]> {
+    if first.value() as usize + N > Year::MAX_INT as usize + 1 {
+        return None;
+    }
+
+    let mut starts = [CycleEpochDay::MIN; N];
+    let mut i = 0;
+
+    while i < N {
+        starts[i] = year_start(first.value() + i as u16);
+        i += 1;
+    }
+
+    Some(starts)
+}
+
+/// Converts dates of consecutive years with a table of their first days,
+/// see [`year_start_table()`].
+///
+/// The table can live anywhere (e.g. in flash memory), it's only borrowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearStartTable<'a> {
+    first: Year,
+    starts: &'a [CycleEpochDay],
+}
+
+impl<'a> YearStartTable<'a> {
+    /// Table of the years starting with `first`.
+    ///
+    /// Returns `None` if the table is empty, goes beyond [`Year::MAX`] or an entry
+    /// isn't the first day of its year, so a corrupted table is rejected.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // the length is checked first
+    pub const fn new(first: Year, starts: &'a [CycleEpochDay]) -> Option<Self> {
+        if starts.is_empty() || first.value() as usize + starts.len() > Year::MAX_INT as usize + 1 {
+            return None;
+        }
+
+        if starts[0].value() != year_start(first.value()).value() {
+            return None;
+        }
+
+        let mut i = 1;
+
+        while i < starts.len() {
+            let Some(previous) = Year::new(first.value() + i as u16 - 1) else {
+                unreachable!()
+            };
+
+            if starts[i].value() != starts[i - 1].value() + previous.days() as u32 {
+                return None;
+            }
+
+            i += 1;
+        }
+
+        Some(Self { first, starts })
+    }
+
+    /// The first year of the table.
+    #[must_use]
+    pub const fn first_year(&self) -> Year {
+        self.first
+    }
+
+    /// The last year of the table.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // checked in `new()`
+    pub const fn last_year(&self) -> Year {
+        let Some(year) = Year::new(self.first.value() + self.starts.len() as u16 - 1) else {
+            unreachable!()
+        };
+
+        year
+    }
+
+    /// The first days of the years.
+    #[must_use]
+    pub const fn starts(&self) -> &'a [CycleEpochDay] {
+        self.starts
+    }
+
+    /// The date of the day.
+    ///
+    /// Returns `None` if the day isn't in one of the years of the table.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // indices and days of the year are small
+    pub const fn to_date(&self, day: CycleEpochDay) -> Option<Date> {
+        let Some(offset) = day.value().checked_sub(self.starts[0].value()) else {
+            return None;
+        };
+
+        // a lower bound of the index (years have at most 366 days), that is
+        // at most one year too early for the first centuries of the table
+        let mut i = (offset / 366) as usize;
+
+        while i + 1 < self.starts.len() && self.starts[i + 1].value() <= day.value() {
+            i += 1;
+        }
+
+        if i >= self.starts.len() {
+            return None;
+        }
+
+        let Some(year) = Year::new(self.first.value() + i as u16) else {
+            unreachable!()
+        };
+
+        let day_of_year = day.value() - self.starts[i].value();
+
+        if day_of_year >= year.days() as u32 {
+            return None;
+        }
+
+        Some(yo_to_date(ok!(YearOrdinal::new(year, day_of_year as u16))))
+    }
+
+    /// The day number of the date.
+    ///
+    /// Returns `None` if the year of the date isn't in the table.
+    #[must_use]
+    pub const fn to_cycle_epoch_day(&self, date: Date) -> Option<CycleEpochDay> {
+        let ordinal = date_to_yo(date);
+
+        let Some(index) = ordinal.year().value().checked_sub(self.first.value()) else {
+            return None;
+        };
+
+        if index as usize >= self.starts.len() {
+            return None;
+        }
+
+        CycleEpochDay::new(self.starts[index as usize].value() + ordinal.day() as u32)
+    }
+}
+
+const fn year_start(year: u16) -> CycleEpochDay {
+    let Some(year) = Year::new(year) else {
+        unreachable!()
+    };
+
+    let Some(first_day) = YearOrdinal::new(year, 0) else {
+        unreachable!()
+    };
+
+    first_day.epoch_day()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::CalendarDate;
+
+    #[test]
+    fn matches_the_general_conversion() {
+        const STARTS: [CycleEpochDay; 400] = year_start_table(year!(L800)).unwrap();
+        let table = YearStartTable::new(year!(L800), &STARTS).unwrap();
+
+        assert_eq!(table.last_year(), year!(M199));
+
+        let mut date = date!(L800 - 01 - 01);
+
+        while date.year() <= table.last_year() {
+            let day = date.to_cycle_epoch_day();
+
+            assert_eq!(table.to_cycle_epoch_day(date), Some(day));
+            assert_eq!(table.to_date(day), Some(date));
+
+            date = date.tomorrow().unwrap();
+        }
+
+        assert_eq!(table.to_date(date.to_cycle_epoch_day()), None);
+        assert_eq!(table.to_cycle_epoch_day(date), None);
+        assert_eq!(table.to_cycle_epoch_day(date!(L799 - 13 - 29)), None);
+        assert_eq!(
+            table.to_date(date!(L799 - 13 - 29).to_cycle_epoch_day()),
+            None
+        );
+    }
+
+    #[test]
+    fn limits() {
+        let last: [CycleEpochDay; 2] = year_start_table(Year::MAX.previous().unwrap()).unwrap();
+        let table = YearStartTable::new(Year::MAX.previous().unwrap(), &last).unwrap();
+
+        assert_eq!(
+            table.to_date(Date::MAX.to_cycle_epoch_day()),
+            Some(Date::MAX)
+        );
+        assert_eq!(year_start_table::<2>(Year::MAX), None);
+        assert_eq!(
+            year_start_table::<1>(Year::MIN),
+            Some([Date::MIN.to_cycle_epoch_day()])
+        );
+
+        assert_eq!(YearStartTable::new(Year::MIN, &[]), None);
+        assert_eq!(YearStartTable::new(Year::MAX, &last), None);
+
+        let mut corrupted = last;
+        corrupted[1] = corrupted[1].tomorrow().unwrap();
+        assert_eq!(
+            YearStartTable::new(Year::MAX.previous().unwrap(), &corrupted),
+            None
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Invalid `date!()` and `year!()` invocations must fail to compile with a helpful message.
+//!
+//! The expected messages are in `tests/ui/*.stderr`, update them with
+//! `TRYBUILD=overwrite cargo test --test compile_fail`.
+
+#[test]
+fn macros() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = sac13::date!(M020 - Addendary - 01);
+}
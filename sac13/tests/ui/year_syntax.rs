@@ -0,0 +1,3 @@
+fn main() {
+    let _ = sac13::year!(M 020);
+}